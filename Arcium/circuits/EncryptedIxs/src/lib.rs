@@ -7,10 +7,21 @@
 
 use arcis_imports::*;
 
+pub mod reference;
+
 #[encrypted]
 mod circuits {
     use arcis_imports::*;
 
+    /// Upper bound on the number of outcomes a categorical problem can have.
+    const MAX_OUTCOMES: usize = 4;
+
+    /// Number of content hashes kept per problem for duplicate detection.
+    const MAX_TRACKED_SUBMISSIONS: usize = 16;
+
+    /// Number of solutions a judging panel can pick between.
+    const MAX_JUDGE_CANDIDATES: usize = 16;
+
     pub struct VoteStats {
         yes_votes: u8,
         no_votes: u8,
@@ -41,27 +52,123 @@ mod circuits {
         no_pool_delta: u64,
     }
 
+    pub struct SubmissionHashes {
+        hashes: [u128; MAX_TRACKED_SUBMISSIONS],
+    }
+
+    pub struct CategoricalStats {
+        votes: [u8; MAX_OUTCOMES],
+        pools: [u64; MAX_OUTCOMES],
+    }
+
+    pub struct ScoreBoard {
+        best_score: u64,
+        best_index: u64,
+        has_score: bool,
+    }
+
+    pub struct BestSolution {
+        best_index: u64,
+        has_score: bool,
+    }
+
+    pub struct BidBoard {
+        lowest_bid: u64,
+        lowest_index: u64,
+        has_bid: bool,
+    }
+
+    pub struct LowestBid {
+        lowest_bid: u64,
+        lowest_index: u64,
+        has_bid: bool,
+    }
+
+    pub struct RaffleDraw {
+        winner_index: u64,
+        success: bool,
+    }
+
+    pub struct ConfidentialBounty {
+        bounty_amount: u64,
+        capped: bool,
+    }
+
+    pub struct BountyRelease {
+        payout_amount: u64,
+        refund_amount: u64,
+        capped: bool,
+    }
+
+    pub struct JudgeTally {
+        votes: [u8; MAX_JUDGE_CANDIDATES],
+    }
+
+    pub struct PanelDecision {
+        winning_index: u8,
+        decided: bool,
+    }
+
+    pub struct CategoricalResolution {
+        winning_outcome: u8,
+        winning_votes: u8,
+        winning_pool: u64,
+        total_pool: u64,
+    }
+
+    /// Also starts the problem's duplicate registry, so the first submission
+    /// compares against hashes the MXE encrypted rather than zeroed bytes.
     #[instruction]
-    pub fn initialize_problem(mxe: Mxe) -> Enc<Mxe, VoteStats> {
+    pub fn initialize_problem(mxe: Mxe) -> (Enc<Mxe, VoteStats>, Enc<Mxe, SubmissionHashes>) {
         let vote_stats = VoteStats {
             yes_votes: 0,
             no_votes: 0,
         };
-        mxe.from_arcis(vote_stats)
+        let submissions = SubmissionHashes {
+            hashes: [0; MAX_TRACKED_SUBMISSIONS],
+        };
+        (mxe.from_arcis(vote_stats), mxe.from_arcis(submissions))
     }
 
     #[instruction]
-    pub fn submit_solution(prediction_ctxt: Enc<Shared, bool>, amount: u64) -> PoolUpdate {
+    pub fn submit_solution(
+        prediction_ctxt: Enc<Shared, bool>,
+        amount: u64,
+        content_hash_ctxt: Enc<Shared, u128>,
+        solution_index: u64,
+        hashes_ctxt: Enc<Mxe, SubmissionHashes>,
+    ) -> (Enc<Mxe, SubmissionHashes>, PoolUpdate, bool) {
         let prediction = prediction_ctxt.to_arcis();
+        let content_hash = content_hash_ctxt.to_arcis();
+        let mut submissions = hashes_ctxt.to_arcis();
 
         let yes_delta = if prediction { amount } else { 0 };
         let no_delta = if !prediction { amount } else { 0 };
 
-        PoolUpdate {
-            yes_pool_delta: yes_delta,
-            no_pool_delta: no_delta,
+        // Slots at or past `solution_index` have never been written, so
+        // whatever they decrypt to is ignored rather than compared.
+        let mut duplicate = false;
+        for i in 0..MAX_TRACKED_SUBMISSIONS {
+            if (i as u64) < solution_index && submissions.hashes[i] == content_hash {
+                duplicate = true;
+            }
         }
-        .reveal()
+
+        for i in 0..MAX_TRACKED_SUBMISSIONS {
+            if i as u64 == solution_index {
+                submissions.hashes[i] = content_hash;
+            }
+        }
+
+        (
+            hashes_ctxt.owner.from_arcis(submissions),
+            PoolUpdate {
+                yes_pool_delta: yes_delta,
+                no_pool_delta: no_delta,
+            }
+            .reveal(),
+            duplicate.reveal(),
+        )
     }
 
     #[instruction]
@@ -72,9 +179,12 @@ mod circuits {
         let vote = vote_ctxt.to_arcis();
         let mut vote_stats = vote_stats_ctxt.to_arcis();
 
+        // Tallies saturate rather than wrap so a full u8 can never flip the outcome.
         if vote.vote {
-            vote_stats.yes_votes += 1;
-        } else {
+            if vote_stats.yes_votes < 255 {
+                vote_stats.yes_votes += 1;
+            }
+        } else if vote_stats.no_votes < 255 {
             vote_stats.no_votes += 1;
         }
 
@@ -101,30 +211,386 @@ mod circuits {
     #[instruction]
     pub fn pay_winner(
         bet_ctxt: Enc<Shared, bool>,
-        market_outcome: bool,
+        is_winner: bool,
         bet_amount: u64,
         yes_pool: u64,
         no_pool: u64,
     ) -> WinnerPayout {
         let user_prediction = bet_ctxt.to_arcis();
 
-        let won = user_prediction == market_outcome;
-
-        let payout_amount = if !won {
+        // Only the solution the problem closed on is paid. Its own prediction
+        // picks the pool it won, so it takes its stake back plus a share of the
+        // opposite pool and nobody else is owed anything.
+        let payout_amount = if !is_winner {
             0
         } else {
-            let winning_pool = if market_outcome { yes_pool } else { no_pool };
-            let losing_pool = if market_outcome { no_pool } else { yes_pool };
+            let winning_pool = (if user_prediction { yes_pool } else { no_pool }) as u128;
+            let losing_pool = (if user_prediction { no_pool } else { yes_pool }) as u128;
 
+            // Both branches are evaluated under MPC, so never divide by zero.
+            let divisor = if winning_pool > 0 { winning_pool } else { 1 };
             let profit_ratio = if winning_pool > 0 {
-                (losing_pool * bet_amount) / winning_pool
+                (losing_pool * bet_amount as u128) / divisor
             } else {
                 0
             };
 
-            bet_amount + profit_ratio
+            let total = bet_amount as u128 + profit_ratio;
+            if total > u64::MAX as u128 {
+                u64::MAX
+            } else {
+                total as u64
+            }
         };
 
-        BetPayout { payout_amount, won }.reveal()
+        WinnerPayout {
+            payout_amount,
+            won: is_winner,
+        }
+        .reveal()
+    }
+
+    #[instruction]
+    pub fn initialize_categorical_problem(mxe: Mxe) -> Enc<Mxe, CategoricalStats> {
+        let stats = CategoricalStats {
+            votes: [0; MAX_OUTCOMES],
+            pools: [0; MAX_OUTCOMES],
+        };
+        mxe.from_arcis(stats)
+    }
+
+    #[instruction]
+    pub fn submit_categorical_solution(
+        choice_ctxt: Enc<Shared, u8>,
+        amount: u64,
+        num_outcomes: u8,
+        stats_ctxt: Enc<Mxe, CategoricalStats>,
+    ) -> Enc<Mxe, CategoricalStats> {
+        let choice = choice_ctxt.to_arcis();
+        let mut stats = stats_ctxt.to_arcis();
+
+        // Out-of-range choices are never credited to a pool.
+        for i in 0..MAX_OUTCOMES {
+            if choice == i as u8 && choice < num_outcomes {
+                let pool = stats.pools[i] as u128 + amount as u128;
+                stats.pools[i] = if pool > u64::MAX as u128 {
+                    u64::MAX
+                } else {
+                    pool as u64
+                };
+            }
+        }
+
+        stats_ctxt.owner.from_arcis(stats)
+    }
+
+    #[instruction]
+    pub fn submit_categorical_vote(
+        vote_ctxt: Enc<Shared, u8>,
+        num_outcomes: u8,
+        stats_ctxt: Enc<Mxe, CategoricalStats>,
+    ) -> Enc<Mxe, CategoricalStats> {
+        let vote = vote_ctxt.to_arcis();
+        let mut stats = stats_ctxt.to_arcis();
+
+        for i in 0..MAX_OUTCOMES {
+            if vote == i as u8 && vote < num_outcomes && stats.votes[i] < 255 {
+                stats.votes[i] += 1;
+            }
+        }
+
+        stats_ctxt.owner.from_arcis(stats)
+    }
+
+    #[instruction]
+    pub fn resolve_categorical_market(
+        stats_ctxt: Enc<Mxe, CategoricalStats>,
+        num_outcomes: u8,
+    ) -> CategoricalResolution {
+        let stats = stats_ctxt.to_arcis();
+
+        // Argmax over the live outcomes; ties go to the lowest index.
+        let mut winning_outcome: u8 = 0;
+        let mut winning_votes = stats.votes[0];
+        for i in 1..MAX_OUTCOMES {
+            if (i as u8) < num_outcomes && stats.votes[i] > winning_votes {
+                winning_outcome = i as u8;
+                winning_votes = stats.votes[i];
+            }
+        }
+
+        let mut winning_pool: u64 = 0;
+        let mut total_pool: u128 = 0;
+        for i in 0..MAX_OUTCOMES {
+            if (i as u8) < num_outcomes {
+                total_pool += stats.pools[i] as u128;
+            }
+            if i as u8 == winning_outcome {
+                winning_pool = stats.pools[i];
+            }
+        }
+
+        CategoricalResolution {
+            winning_outcome,
+            winning_votes,
+            winning_pool,
+            total_pool: if total_pool > u64::MAX as u128 {
+                u64::MAX
+            } else {
+                total_pool as u64
+            },
+        }
+        .reveal()
+    }
+
+    #[instruction]
+    pub fn pay_categorical_winner(
+        choice_ctxt: Enc<Shared, u8>,
+        winning_outcome: u8,
+        bet_amount: u64,
+        winning_pool: u64,
+        total_pool: u64,
+    ) -> WinnerPayout {
+        let choice = choice_ctxt.to_arcis();
+
+        let won = choice == winning_outcome;
+
+        let divisor = if winning_pool > 0 {
+            winning_pool as u128
+        } else {
+            1
+        };
+        let share = if winning_pool > 0 {
+            (total_pool as u128 * bet_amount as u128) / divisor
+        } else {
+            bet_amount as u128
+        };
+
+        let payout_amount = if !won {
+            0
+        } else if share > u64::MAX as u128 {
+            u64::MAX
+        } else {
+            share as u64
+        };
+
+        WinnerPayout { payout_amount, won }.reveal()
+    }
+
+    #[instruction]
+    pub fn initialize_scoring(mxe: Mxe) -> Enc<Mxe, ScoreBoard> {
+        let board = ScoreBoard {
+            best_score: 0,
+            best_index: 0,
+            has_score: false,
+        };
+        mxe.from_arcis(board)
+    }
+
+    #[instruction]
+    pub fn score_solution(
+        score_ctxt: Enc<Shared, u64>,
+        solution_index: u64,
+        board_ctxt: Enc<Mxe, ScoreBoard>,
+    ) -> Enc<Mxe, ScoreBoard> {
+        let score = score_ctxt.to_arcis();
+        let mut board = board_ctxt.to_arcis();
+
+        // Strictly greater, so the earliest solution keeps a tied best score.
+        if !board.has_score || score > board.best_score {
+            board.best_score = score;
+            board.best_index = solution_index;
+            board.has_score = true;
+        }
+
+        board_ctxt.owner.from_arcis(board)
+    }
+
+    #[instruction]
+    pub fn reveal_best_solution(board_ctxt: Enc<Mxe, ScoreBoard>) -> BestSolution {
+        let board = board_ctxt.to_arcis();
+
+        BestSolution {
+            best_index: board.best_index,
+            has_score: board.has_score,
+        }
+        .reveal()
+    }
+
+    #[instruction]
+    pub fn initialize_bid_board(mxe: Mxe) -> Enc<Mxe, BidBoard> {
+        let board = BidBoard {
+            lowest_bid: 0,
+            lowest_index: 0,
+            has_bid: false,
+        };
+        mxe.from_arcis(board)
+    }
+
+    #[instruction]
+    pub fn submit_bid(
+        bid_ctxt: Enc<Shared, u64>,
+        bidder_index: u64,
+        max_price: u64,
+        board_ctxt: Enc<Mxe, BidBoard>,
+    ) -> Enc<Mxe, BidBoard> {
+        let bid = bid_ctxt.to_arcis();
+        let mut board = board_ctxt.to_arcis();
+
+        // A quote is valid if it is non-zero and within the escrowed bounty.
+        let valid = bid > 0 && bid <= max_price;
+
+        // Strictly lower, so the earliest bidder keeps a tied lowest price.
+        if valid && (!board.has_bid || bid < board.lowest_bid) {
+            board.lowest_bid = bid;
+            board.lowest_index = bidder_index;
+            board.has_bid = true;
+        }
+
+        board_ctxt.owner.from_arcis(board)
+    }
+
+    #[instruction]
+    pub fn reveal_lowest_bid(board_ctxt: Enc<Mxe, BidBoard>) -> LowestBid {
+        let board = board_ctxt.to_arcis();
+
+        LowestBid {
+            lowest_bid: board.lowest_bid,
+            lowest_index: board.lowest_index,
+            has_bid: board.has_bid,
+        }
+        .reveal()
+    }
+
+    #[instruction]
+    pub fn draw_raffle_winner(entrant_count: u64) -> RaffleDraw {
+        let max_index = if entrant_count > 0 {
+            entrant_count - 1
+        } else {
+            0
+        };
+
+        // Rejection sampling keeps the draw uniform; a failed draw is revealed
+        // as such and simply retried rather than biased towards low indices.
+        let (index, in_range) = ArcisRNG::gen_integer_in_range(0, max_index as u128, 32);
+
+        RaffleDraw {
+            winner_index: index as u64,
+            success: in_range && entrant_count > 0,
+        }
+        .reveal()
+    }
+
+    #[instruction]
+    pub fn draw_jury(mxe: Mxe) -> (u64, Enc<Mxe, VoteStats>) {
+        // The seed the program draws jurors with comes from the cluster, so
+        // nobody can pick the moment that seats the panel they want. The
+        // range spans every u64, so the sample is never rejected.
+        let (seed, _) = ArcisRNG::gen_integer_in_range(0, u64::MAX as u128, 1);
+        let tally = VoteStats {
+            yes_votes: 0,
+            no_votes: 0,
+        };
+
+        ((seed as u64).reveal(), mxe.from_arcis(tally))
+    }
+
+    #[instruction]
+    pub fn initialize_confidential_bounty(
+        bounty_ctxt: Enc<Shared, u64>,
+        bounty_cap: u64,
+        mxe: Mxe,
+    ) -> (Enc<Mxe, ConfidentialBounty>, Enc<Mxe, SubmissionHashes>) {
+        let bounty = bounty_ctxt.to_arcis();
+
+        // Never promise more than the publicly escrowed upper bound.
+        let capped = bounty > bounty_cap;
+        let bounty_amount = if capped { bounty_cap } else { bounty };
+        let submissions = SubmissionHashes {
+            hashes: [0; MAX_TRACKED_SUBMISSIONS],
+        };
+
+        (
+            mxe.from_arcis(ConfidentialBounty {
+                bounty_amount,
+                capped,
+            }),
+            mxe.from_arcis(submissions),
+        )
+    }
+
+    #[instruction]
+    pub fn pay_confidential_bounty(
+        bounty_ctxt: Enc<Mxe, ConfidentialBounty>,
+        bounty_cap: u64,
+    ) -> BountyRelease {
+        let bounty = bounty_ctxt.to_arcis();
+
+        let payout_amount = if bounty.bounty_amount > bounty_cap {
+            bounty_cap
+        } else {
+            bounty.bounty_amount
+        };
+
+        BountyRelease {
+            payout_amount,
+            refund_amount: bounty_cap - payout_amount,
+            capped: bounty.capped,
+        }
+        .reveal()
+    }
+
+    #[instruction]
+    pub fn check_juror_vote(vote_ctxt: Enc<Shared, QuorumVote>, outcome: bool) -> bool {
+        let vote = vote_ctxt.to_arcis();
+
+        // Only whether the juror sided with the outcome is revealed, never the vote.
+        (vote.vote == outcome).reveal()
+    }
+
+    #[instruction]
+    pub fn initialize_judge_tally(mxe: Mxe) -> Enc<Mxe, JudgeTally> {
+        let tally = JudgeTally {
+            votes: [0; MAX_JUDGE_CANDIDATES],
+        };
+        mxe.from_arcis(tally)
+    }
+
+    #[instruction]
+    pub fn submit_judge_pick(
+        pick_ctxt: Enc<Shared, u8>,
+        num_candidates: u8,
+        threshold: u8,
+        tally_ctxt: Enc<Mxe, JudgeTally>,
+    ) -> (Enc<Mxe, JudgeTally>, PanelDecision) {
+        let pick = pick_ctxt.to_arcis();
+        let mut tally = tally_ctxt.to_arcis();
+
+        // Out-of-range picks count as abstentions.
+        for i in 0..MAX_JUDGE_CANDIDATES {
+            if pick == i as u8 && pick < num_candidates && tally.votes[i] < 255 {
+                tally.votes[i] += 1;
+            }
+        }
+
+        // Only a candidate that reached the threshold is revealed; until then
+        // the decision says nothing about how the panel is leaning.
+        let mut decided = false;
+        let mut winning_index: u8 = 0;
+        for i in 0..MAX_JUDGE_CANDIDATES {
+            if !decided && (i as u8) < num_candidates && tally.votes[i] >= threshold {
+                decided = true;
+                winning_index = i as u8;
+            }
+        }
+
+        (
+            tally_ctxt.owner.from_arcis(tally),
+            PanelDecision {
+                winning_index,
+                decided,
+            }
+            .reveal(),
+        )
     }
 }
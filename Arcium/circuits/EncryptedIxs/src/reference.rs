@@ -0,0 +1,423 @@
+/*
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+//! Plaintext reference model of the circuits in `circuits`.
+//!
+//! Every `#[instruction]` has a twin here that takes the decrypted inputs and
+//! returns the decrypted outputs. The property tests and the differential
+//! harness in `tests/` treat these functions as the specification the compiled
+//! circuits must match. The `submit_solution` circuit is modelled by two twins,
+//! `submit_solution` for the pools and `record_submission_hash` for duplicate
+//! detection.
+
+/// Mirrors `MAX_OUTCOMES` in the circuits.
+pub const MAX_OUTCOMES: usize = 4;
+
+/// Mirrors `MAX_TRACKED_SUBMISSIONS` in the circuits.
+pub const MAX_TRACKED_SUBMISSIONS: usize = 16;
+
+/// Mirrors `MAX_JUDGE_CANDIDATES` in the circuits.
+pub const MAX_JUDGE_CANDIDATES: usize = 16;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VoteStats {
+    pub yes_votes: u8,
+    pub no_votes: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QuorumVote {
+    pub vote: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResolutionResult {
+    pub outcome: bool,
+    pub yes_votes: u8,
+    pub no_votes: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WinnerPayout {
+    pub payout_amount: u64,
+    pub won: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolUpdate {
+    pub yes_pool_delta: u64,
+    pub no_pool_delta: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubmissionHashes {
+    pub hashes: [u128; MAX_TRACKED_SUBMISSIONS],
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CategoricalStats {
+    pub votes: [u8; MAX_OUTCOMES],
+    pub pools: [u64; MAX_OUTCOMES],
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CategoricalResolution {
+    pub winning_outcome: u8,
+    pub winning_votes: u8,
+    pub winning_pool: u64,
+    pub total_pool: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScoreBoard {
+    pub best_score: u64,
+    pub best_index: u64,
+    pub has_score: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BestSolution {
+    pub best_index: u64,
+    pub has_score: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BidBoard {
+    pub lowest_bid: u64,
+    pub lowest_index: u64,
+    pub has_bid: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LowestBid {
+    pub lowest_bid: u64,
+    pub lowest_index: u64,
+    pub has_bid: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RaffleDraw {
+    pub winner_index: u64,
+    pub success: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConfidentialBounty {
+    pub bounty_amount: u64,
+    pub capped: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BountyRelease {
+    pub payout_amount: u64,
+    pub refund_amount: u64,
+    pub capped: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JudgeTally {
+    pub votes: [u8; MAX_JUDGE_CANDIDATES],
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PanelDecision {
+    pub winning_index: u8,
+    pub decided: bool,
+}
+
+pub fn initialize_problem() -> VoteStats {
+    VoteStats {
+        yes_votes: 0,
+        no_votes: 0,
+    }
+}
+
+pub fn submit_solution(prediction: bool, amount: u64) -> PoolUpdate {
+    PoolUpdate {
+        yes_pool_delta: if prediction { amount } else { 0 },
+        no_pool_delta: if prediction { 0 } else { amount },
+    }
+}
+
+/// Returns the updated hashes and whether `content_hash` repeats a tracked
+/// earlier submission.
+pub fn record_submission_hash(
+    content_hash: u128,
+    solution_index: u64,
+    submissions: SubmissionHashes,
+) -> (SubmissionHashes, bool) {
+    let mut submissions = submissions;
+
+    let tracked = (solution_index as usize).min(MAX_TRACKED_SUBMISSIONS);
+    let duplicate = submissions.hashes[..tracked].contains(&content_hash);
+
+    if let Some(slot) = submissions.hashes.get_mut(solution_index as usize) {
+        *slot = content_hash;
+    }
+
+    (submissions, duplicate)
+}
+
+pub fn submit_quorum_vote(vote: QuorumVote, vote_stats: VoteStats) -> VoteStats {
+    let mut vote_stats = vote_stats;
+
+    if vote.vote {
+        vote_stats.yes_votes = vote_stats.yes_votes.saturating_add(1);
+    } else {
+        vote_stats.no_votes = vote_stats.no_votes.saturating_add(1);
+    }
+
+    vote_stats
+}
+
+pub fn resolve_market(vote_stats: VoteStats, _quorum_size: u8) -> ResolutionResult {
+    ResolutionResult {
+        outcome: vote_stats.yes_votes > vote_stats.no_votes,
+        yes_votes: vote_stats.yes_votes,
+        no_votes: vote_stats.no_votes,
+    }
+}
+
+pub fn pay_winner(
+    prediction: bool,
+    is_winner: bool,
+    bet_amount: u64,
+    yes_pool: u64,
+    no_pool: u64,
+) -> WinnerPayout {
+    if !is_winner {
+        return WinnerPayout {
+            payout_amount: 0,
+            won: false,
+        };
+    }
+
+    let (winning_pool, losing_pool) = if prediction {
+        (yes_pool, no_pool)
+    } else {
+        (no_pool, yes_pool)
+    };
+
+    let profit_ratio = if winning_pool > 0 {
+        (losing_pool as u128 * bet_amount as u128) / winning_pool as u128
+    } else {
+        0
+    };
+
+    let total = bet_amount as u128 + profit_ratio;
+
+    WinnerPayout {
+        payout_amount: u64::try_from(total).unwrap_or(u64::MAX),
+        won: true,
+    }
+}
+
+pub fn initialize_categorical_problem() -> CategoricalStats {
+    CategoricalStats::default()
+}
+
+pub fn submit_categorical_solution(
+    choice: u8,
+    amount: u64,
+    num_outcomes: u8,
+    stats: CategoricalStats,
+) -> CategoricalStats {
+    let mut stats = stats;
+
+    if choice < num_outcomes && (choice as usize) < MAX_OUTCOMES {
+        let pool = &mut stats.pools[choice as usize];
+        *pool = pool.saturating_add(amount);
+    }
+
+    stats
+}
+
+pub fn submit_categorical_vote(
+    vote: u8,
+    num_outcomes: u8,
+    stats: CategoricalStats,
+) -> CategoricalStats {
+    let mut stats = stats;
+
+    if vote < num_outcomes && (vote as usize) < MAX_OUTCOMES {
+        let votes = &mut stats.votes[vote as usize];
+        *votes = votes.saturating_add(1);
+    }
+
+    stats
+}
+
+pub fn resolve_categorical_market(
+    stats: CategoricalStats,
+    num_outcomes: u8,
+) -> CategoricalResolution {
+    let live = (num_outcomes as usize).min(MAX_OUTCOMES);
+
+    let mut winning_outcome = 0;
+    for i in 1..live {
+        if stats.votes[i] > stats.votes[winning_outcome] {
+            winning_outcome = i;
+        }
+    }
+
+    let total_pool: u128 = stats.pools[..live].iter().map(|&pool| pool as u128).sum();
+
+    CategoricalResolution {
+        winning_outcome: winning_outcome as u8,
+        winning_votes: stats.votes[winning_outcome],
+        winning_pool: stats.pools[winning_outcome],
+        total_pool: u64::try_from(total_pool).unwrap_or(u64::MAX),
+    }
+}
+
+pub fn pay_categorical_winner(
+    choice: u8,
+    winning_outcome: u8,
+    bet_amount: u64,
+    winning_pool: u64,
+    total_pool: u64,
+) -> WinnerPayout {
+    let won = choice == winning_outcome;
+
+    if !won {
+        return WinnerPayout {
+            payout_amount: 0,
+            won,
+        };
+    }
+
+    let share = if winning_pool > 0 {
+        (total_pool as u128 * bet_amount as u128) / winning_pool as u128
+    } else {
+        bet_amount as u128
+    };
+
+    WinnerPayout {
+        payout_amount: u64::try_from(share).unwrap_or(u64::MAX),
+        won,
+    }
+}
+
+pub fn initialize_scoring() -> ScoreBoard {
+    ScoreBoard::default()
+}
+
+pub fn score_solution(score: u64, solution_index: u64, board: ScoreBoard) -> ScoreBoard {
+    if !board.has_score || score > board.best_score {
+        ScoreBoard {
+            best_score: score,
+            best_index: solution_index,
+            has_score: true,
+        }
+    } else {
+        board
+    }
+}
+
+pub fn reveal_best_solution(board: ScoreBoard) -> BestSolution {
+    BestSolution {
+        best_index: board.best_index,
+        has_score: board.has_score,
+    }
+}
+
+pub fn initialize_bid_board() -> BidBoard {
+    BidBoard::default()
+}
+
+pub fn submit_bid(bid: u64, bidder_index: u64, max_price: u64, board: BidBoard) -> BidBoard {
+    let valid = bid > 0 && bid <= max_price;
+
+    if valid && (!board.has_bid || bid < board.lowest_bid) {
+        BidBoard {
+            lowest_bid: bid,
+            lowest_index: bidder_index,
+            has_bid: true,
+        }
+    } else {
+        board
+    }
+}
+
+pub fn reveal_lowest_bid(board: BidBoard) -> LowestBid {
+    LowestBid {
+        lowest_bid: board.lowest_bid,
+        lowest_index: board.lowest_index,
+        has_bid: board.has_bid,
+    }
+}
+
+/// `sample` stands in for the MPC randomness, which cannot be reproduced here;
+/// `None` models a rejection-sampling failure.
+pub fn draw_raffle_winner(entrant_count: u64, sample: Option<u64>) -> RaffleDraw {
+    match sample {
+        Some(index) if entrant_count > 0 && index < entrant_count => RaffleDraw {
+            winner_index: index,
+            success: true,
+        },
+        _ => RaffleDraw {
+            winner_index: sample.unwrap_or(0),
+            success: false,
+        },
+    }
+}
+
+/// `sample` stands in for the MPC randomness the jury seed is drawn from.
+pub fn draw_jury(sample: u64) -> (u64, VoteStats) {
+    (sample, initialize_problem())
+}
+
+pub fn initialize_confidential_bounty(bounty: u64, bounty_cap: u64) -> ConfidentialBounty {
+    ConfidentialBounty {
+        bounty_amount: bounty.min(bounty_cap),
+        capped: bounty > bounty_cap,
+    }
+}
+
+pub fn pay_confidential_bounty(bounty: ConfidentialBounty, bounty_cap: u64) -> BountyRelease {
+    let payout_amount = bounty.bounty_amount.min(bounty_cap);
+
+    BountyRelease {
+        payout_amount,
+        refund_amount: bounty_cap - payout_amount,
+        capped: bounty.capped,
+    }
+}
+
+pub fn check_juror_vote(vote: QuorumVote, outcome: bool) -> bool {
+    vote.vote == outcome
+}
+
+pub fn initialize_judge_tally() -> JudgeTally {
+    JudgeTally::default()
+}
+
+pub fn submit_judge_pick(
+    pick: u8,
+    num_candidates: u8,
+    threshold: u8,
+    tally: JudgeTally,
+) -> (JudgeTally, PanelDecision) {
+    let mut tally = tally;
+    let live = (num_candidates as usize).min(MAX_JUDGE_CANDIDATES);
+
+    if (pick as usize) < live {
+        let votes = &mut tally.votes[pick as usize];
+        *votes = votes.saturating_add(1);
+    }
+
+    let reached = tally.votes[..live].iter().position(|&v| v >= threshold);
+    let decision = match reached {
+        Some(index) => PanelDecision {
+            winning_index: index as u8,
+            decided: true,
+        },
+        None => PanelDecision::default(),
+    };
+
+    (tally, decision)
+}
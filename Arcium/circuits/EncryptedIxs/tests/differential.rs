@@ -0,0 +1,411 @@
+/*
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+//! Golden vectors shared with the localnet harness in `Tests/Circuits.ts`.
+//!
+//! The reference model produces `tests/vectors.json`; the TypeScript harness
+//! replays the same scenarios against the compiled circuits and compares the
+//! revealed outputs. Run with `UPDATE_VECTORS=1` after an intentional change
+//! to the reference model to regenerate the file.
+
+use encrypted_ixs::reference::{self, QuorumVote, SubmissionHashes};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+struct Scenario {
+    name: &'static str,
+    submissions: &'static [(bool, u64)],
+    winner: usize,
+}
+
+const SCENARIOS: &[Scenario] = &[
+    Scenario {
+        name: "single solver",
+        submissions: &[(true, 5_000)],
+        winner: 0,
+    },
+    Scenario {
+        name: "winner takes the losing pool",
+        submissions: &[(true, 1_000), (false, 3_000), (false, 2_000)],
+        winner: 0,
+    },
+    Scenario {
+        name: "rounding dust",
+        submissions: &[(true, 3), (true, 3), (false, 7)],
+        winner: 1,
+    },
+    Scenario {
+        name: "empty winning pool",
+        submissions: &[(false, 0), (true, 10_000)],
+        winner: 1,
+    },
+    Scenario {
+        name: "large stakes",
+        submissions: &[(true, u64::MAX / 4), (false, u64::MAX / 4)],
+        winner: 0,
+    },
+];
+
+const QUORUMS: &[&[bool]] = &[
+    &[],
+    &[true],
+    &[false, true],
+    &[true, true, false],
+    &[false, false, true],
+];
+
+struct CategoricalScenario {
+    name: &'static str,
+    num_outcomes: u8,
+    submissions: &'static [(u8, u64)],
+    votes: &'static [u8],
+}
+
+const CATEGORICAL_SCENARIOS: &[CategoricalScenario] = &[
+    CategoricalScenario {
+        name: "winning outcome takes the total pool",
+        num_outcomes: 3,
+        submissions: &[(0, 1_000), (1, 2_000), (2, 3_000), (1, 500)],
+        votes: &[1, 1, 2],
+    },
+    CategoricalScenario {
+        name: "tied votes keep the lower outcome",
+        num_outcomes: 2,
+        submissions: &[(0, 700), (1, 300)],
+        votes: &[1, 0],
+    },
+    CategoricalScenario {
+        name: "empty winning pool",
+        num_outcomes: 4,
+        submissions: &[(0, 1_000), (1, 1_000)],
+        votes: &[3],
+    },
+    CategoricalScenario {
+        name: "out of range choice",
+        num_outcomes: 2,
+        submissions: &[(0, 1_000), (5, 400)],
+        votes: &[0],
+    },
+];
+
+const SCORINGS: &[&[u64]] = &[&[10, 40, 25], &[7, 7], &[0]];
+
+struct BidScenario {
+    name: &'static str,
+    max_price: u64,
+    bids: &'static [u64],
+}
+
+const BID_SCENARIOS: &[BidScenario] = &[
+    BidScenario {
+        name: "lowest bid wins",
+        max_price: 1_000_000,
+        bids: &[800_000, 300_000, 500_000],
+    },
+    BidScenario {
+        name: "bids over the ceiling are ignored",
+        max_price: 500_000,
+        bids: &[700_000, 450_000],
+    },
+    BidScenario {
+        name: "ties keep the first bid",
+        max_price: 500_000,
+        bids: &[400_000, 400_000],
+    },
+    BidScenario {
+        name: "no valid bid",
+        max_price: 100_000,
+        bids: &[0, 150_000],
+    },
+];
+
+/// `(bounty, bounty_cap)` pairs.
+const CONFIDENTIAL_BOUNTIES: &[(u64, u64)] =
+    &[(300_000, 500_000), (500_000, 500_000), (900_000, 500_000)];
+
+const SUBMISSION_HASHES: &[&[u128]] = &[&[1, 2, 3], &[5, 6, 5], &[9, 9, 9]];
+
+struct JudgeScenario {
+    name: &'static str,
+    num_candidates: u8,
+    threshold: u8,
+    picks: &'static [u8],
+}
+
+const JUDGE_SCENARIOS: &[JudgeScenario] = &[
+    JudgeScenario {
+        name: "decided on the last pick",
+        num_candidates: 3,
+        threshold: 2,
+        picks: &[1, 2, 1],
+    },
+    JudgeScenario {
+        name: "threshold of one",
+        num_candidates: 2,
+        threshold: 1,
+        picks: &[1],
+    },
+    JudgeScenario {
+        name: "out of range pick",
+        num_candidates: 2,
+        threshold: 2,
+        picks: &[5, 0, 0],
+    },
+    JudgeScenario {
+        name: "deadlock",
+        num_candidates: 3,
+        threshold: 2,
+        picks: &[0, 1, 2],
+    },
+];
+
+fn vectors_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/vectors.json")
+}
+
+fn scenario_vector(scenario: &Scenario) -> Value {
+    let mut yes_pool = 0u64;
+    let mut no_pool = 0u64;
+    let mut submissions = Vec::new();
+
+    for &(prediction, amount) in scenario.submissions {
+        let update = reference::submit_solution(prediction, amount);
+        yes_pool += update.yes_pool_delta;
+        no_pool += update.no_pool_delta;
+
+        submissions.push(json!({
+            "prediction": prediction,
+            "amount": amount.to_string(),
+            "yes_pool_delta": update.yes_pool_delta.to_string(),
+            "no_pool_delta": update.no_pool_delta.to_string(),
+        }));
+    }
+
+    // The program passes `is_winner = (solver == winner)` to `pay_winner`.
+    let payouts: Vec<Value> = scenario
+        .submissions
+        .iter()
+        .enumerate()
+        .map(|(i, &(prediction, amount))| {
+            let payout =
+                reference::pay_winner(prediction, i == scenario.winner, amount, yes_pool, no_pool);
+            json!({
+                "payout_amount": payout.payout_amount.to_string(),
+                "won": payout.won,
+            })
+        })
+        .collect();
+
+    json!({
+        "name": scenario.name,
+        "winner": scenario.winner,
+        "submissions": submissions,
+        "yes_pool": yes_pool.to_string(),
+        "no_pool": no_pool.to_string(),
+        "payouts": payouts,
+    })
+}
+
+fn quorum_vector(votes: &[bool]) -> Value {
+    let stats = votes
+        .iter()
+        .fold(reference::initialize_problem(), |stats, &vote| {
+            reference::submit_quorum_vote(QuorumVote { vote }, stats)
+        });
+    let result = reference::resolve_market(stats, votes.len() as u8);
+
+    // `settle_juror_vote` checks each seat's vote against the outcome.
+    let majority: Vec<bool> = votes
+        .iter()
+        .map(|&vote| reference::check_juror_vote(QuorumVote { vote }, result.outcome))
+        .collect();
+
+    json!({
+        "votes": votes,
+        "outcome": result.outcome,
+        "yes_votes": result.yes_votes,
+        "no_votes": result.no_votes,
+        "majority": majority,
+    })
+}
+
+fn categorical_vector(scenario: &CategoricalScenario) -> Value {
+    let stats = scenario.submissions.iter().fold(
+        reference::initialize_categorical_problem(),
+        |stats, &(choice, amount)| {
+            reference::submit_categorical_solution(choice, amount, scenario.num_outcomes, stats)
+        },
+    );
+    let stats = scenario.votes.iter().fold(stats, |stats, &vote| {
+        reference::submit_categorical_vote(vote, scenario.num_outcomes, stats)
+    });
+    let result = reference::resolve_categorical_market(stats, scenario.num_outcomes);
+
+    let payouts: Vec<Value> = scenario
+        .submissions
+        .iter()
+        .map(|&(choice, amount)| {
+            let payout = reference::pay_categorical_winner(
+                choice,
+                result.winning_outcome,
+                amount,
+                result.winning_pool,
+                result.total_pool,
+            );
+            json!({
+                "payout_amount": payout.payout_amount.to_string(),
+                "won": payout.won,
+            })
+        })
+        .collect();
+
+    json!({
+        "name": scenario.name,
+        "num_outcomes": scenario.num_outcomes,
+        "submissions": scenario
+            .submissions
+            .iter()
+            .map(|&(choice, amount)| json!({ "choice": choice, "amount": amount.to_string() }))
+            .collect::<Vec<_>>(),
+        "votes": scenario.votes,
+        "winning_outcome": result.winning_outcome,
+        "winning_votes": result.winning_votes,
+        "winning_pool": result.winning_pool.to_string(),
+        "total_pool": result.total_pool.to_string(),
+        "payouts": payouts,
+    })
+}
+
+fn scoring_vector(scores: &[u64]) -> Value {
+    let board = scores
+        .iter()
+        .enumerate()
+        .fold(reference::initialize_scoring(), |board, (i, &score)| {
+            reference::score_solution(score, i as u64, board)
+        });
+    let best = reference::reveal_best_solution(board);
+
+    json!({
+        "scores": scores.iter().map(u64::to_string).collect::<Vec<_>>(),
+        "best_index": best.best_index.to_string(),
+        "has_score": best.has_score,
+    })
+}
+
+fn bid_vector(scenario: &BidScenario) -> Value {
+    let board = scenario
+        .bids
+        .iter()
+        .enumerate()
+        .fold(reference::initialize_bid_board(), |board, (i, &bid)| {
+            reference::submit_bid(bid, i as u64, scenario.max_price, board)
+        });
+    let lowest = reference::reveal_lowest_bid(board);
+
+    json!({
+        "name": scenario.name,
+        "max_price": scenario.max_price.to_string(),
+        "bids": scenario.bids.iter().map(u64::to_string).collect::<Vec<_>>(),
+        "lowest_bid": lowest.lowest_bid.to_string(),
+        "lowest_index": lowest.lowest_index.to_string(),
+        "has_bid": lowest.has_bid,
+    })
+}
+
+fn confidential_bounty_vector(&(bounty, bounty_cap): &(u64, u64)) -> Value {
+    let initialized = reference::initialize_confidential_bounty(bounty, bounty_cap);
+    let release = reference::pay_confidential_bounty(initialized, bounty_cap);
+
+    json!({
+        "bounty": bounty.to_string(),
+        "bounty_cap": bounty_cap.to_string(),
+        "bounty_amount": initialized.bounty_amount.to_string(),
+        "payout_amount": release.payout_amount.to_string(),
+        "refund_amount": release.refund_amount.to_string(),
+        "capped": release.capped,
+    })
+}
+
+fn submission_hash_vector(hashes: &[u128]) -> Value {
+    let mut submissions = SubmissionHashes::default();
+    let mut duplicates = Vec::new();
+
+    for (i, &hash) in hashes.iter().enumerate() {
+        let (updated, duplicate) = reference::record_submission_hash(hash, i as u64, submissions);
+        submissions = updated;
+        duplicates.push(duplicate);
+    }
+
+    json!({
+        "hashes": hashes.iter().map(u128::to_string).collect::<Vec<_>>(),
+        "duplicates": duplicates,
+    })
+}
+
+fn judge_vector(scenario: &JudgeScenario) -> Value {
+    let mut tally = reference::initialize_judge_tally();
+    let mut decisions = Vec::new();
+
+    for &pick in scenario.picks {
+        let (updated, decision) =
+            reference::submit_judge_pick(pick, scenario.num_candidates, scenario.threshold, tally);
+        tally = updated;
+        decisions.push(json!({
+            "winning_index": decision.winning_index,
+            "decided": decision.decided,
+        }));
+    }
+
+    json!({
+        "name": scenario.name,
+        "num_candidates": scenario.num_candidates,
+        "threshold": scenario.threshold,
+        "picks": scenario.picks,
+        "decisions": decisions,
+    })
+}
+
+fn generate() -> Value {
+    json!({
+        "scenarios": SCENARIOS.iter().map(scenario_vector).collect::<Vec<_>>(),
+        "quorums": QUORUMS.iter().map(|votes| quorum_vector(votes)).collect::<Vec<_>>(),
+        "categorical": CATEGORICAL_SCENARIOS.iter().map(categorical_vector).collect::<Vec<_>>(),
+        "scoring": SCORINGS.iter().map(|scores| scoring_vector(scores)).collect::<Vec<_>>(),
+        "bids": BID_SCENARIOS.iter().map(bid_vector).collect::<Vec<_>>(),
+        "confidential_bounties": CONFIDENTIAL_BOUNTIES
+            .iter()
+            .map(confidential_bounty_vector)
+            .collect::<Vec<_>>(),
+        "submission_hashes": SUBMISSION_HASHES
+            .iter()
+            .map(|hashes| submission_hash_vector(hashes))
+            .collect::<Vec<_>>(),
+        "judge_picks": JUDGE_SCENARIOS.iter().map(judge_vector).collect::<Vec<_>>(),
+    })
+}
+
+#[test]
+fn reference_matches_golden_vectors() {
+    let generated = generate();
+    let path = vectors_path();
+
+    if std::env::var_os("UPDATE_VECTORS").is_some() {
+        let mut contents = serde_json::to_string_pretty(&generated).unwrap();
+        contents.push('\n');
+        std::fs::write(&path, contents).unwrap();
+        return;
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("missing {}; run with UPDATE_VECTORS=1", path.display()));
+    let recorded: Value = serde_json::from_str(&contents).unwrap();
+
+    assert_eq!(
+        recorded, generated,
+        "reference model drifted from tests/vectors.json"
+    );
+}
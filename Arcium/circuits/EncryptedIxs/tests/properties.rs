@@ -0,0 +1,375 @@
+/*
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+use encrypted_ixs::reference::{
+    self, CategoricalStats, QuorumVote, MAX_JUDGE_CANDIDATES, MAX_OUTCOMES, MAX_TRACKED_SUBMISSIONS,
+};
+use proptest::prelude::*;
+
+fn submissions() -> impl Strategy<Value = Vec<(bool, u64)>> {
+    // Keep each stake small enough that the pools themselves fit in a u64.
+    prop::collection::vec((any::<bool>(), 0..=u64::MAX / 64), 1..32)
+}
+
+fn categorical_submissions() -> impl Strategy<Value = Vec<(u8, u64)>> {
+    prop::collection::vec((0..MAX_OUTCOMES as u8 + 2, 0..=u64::MAX / 64), 1..32)
+}
+
+fn pools(submissions: &[(bool, u64)]) -> (u64, u64) {
+    submissions
+        .iter()
+        .fold((0u64, 0u64), |(yes, no), &(prediction, amount)| {
+            let update = reference::submit_solution(prediction, amount);
+            (yes + update.yes_pool_delta, no + update.no_pool_delta)
+        })
+}
+
+proptest! {
+    #[test]
+    fn submit_solution_routes_the_whole_amount_to_one_pool(prediction: bool, amount: u64) {
+        let update = reference::submit_solution(prediction, amount);
+
+        prop_assert_eq!(update.yes_pool_delta as u128 + update.no_pool_delta as u128, amount as u128);
+        if prediction {
+            prop_assert_eq!(update.no_pool_delta, 0);
+        } else {
+            prop_assert_eq!(update.yes_pool_delta, 0);
+        }
+    }
+
+    #[test]
+    fn payouts_never_exceed_the_pools(
+        (submissions, winner) in submissions().prop_flat_map(|submissions| {
+            let len = submissions.len();
+            (Just(submissions), 0..len)
+        }),
+    ) {
+        let (yes_pool, no_pool) = pools(&submissions);
+        let total_pool = yes_pool as u128 + no_pool as u128;
+
+        // Replay the scenario exactly as the program does: every solution is
+        // paid once, with only the closed-on winner flagged.
+        let mut paid = 0u128;
+        for (i, &(prediction, amount)) in submissions.iter().enumerate() {
+            let payout = reference::pay_winner(prediction, i == winner, amount, yes_pool, no_pool);
+
+            prop_assert_eq!(payout.won, i == winner);
+            if payout.won {
+                prop_assert!(payout.payout_amount >= amount);
+            } else {
+                prop_assert_eq!(payout.payout_amount, 0);
+            }
+            paid += payout.payout_amount as u128;
+        }
+
+        prop_assert!(paid <= total_pool);
+    }
+
+    #[test]
+    fn pay_winner_never_overflows(
+        prediction: bool,
+        is_winner: bool,
+        bet_amount: u64,
+        yes_pool: u64,
+        no_pool: u64,
+    ) {
+        let payout = reference::pay_winner(prediction, is_winner, bet_amount, yes_pool, no_pool);
+
+        if payout.won {
+            prop_assert!(payout.payout_amount >= bet_amount);
+        }
+    }
+
+    #[test]
+    fn quorum_votes_are_monotonic(votes in prop::collection::vec(any::<bool>(), 0..600)) {
+        let mut stats = reference::initialize_problem();
+
+        for (i, &vote) in votes.iter().enumerate() {
+            let next = reference::submit_quorum_vote(QuorumVote { vote }, stats);
+
+            prop_assert!(next.yes_votes >= stats.yes_votes);
+            prop_assert!(next.no_votes >= stats.no_votes);
+            if vote {
+                prop_assert_eq!(next.no_votes, stats.no_votes);
+                prop_assert_eq!(next.yes_votes, stats.yes_votes.saturating_add(1));
+            } else {
+                prop_assert_eq!(next.yes_votes, stats.yes_votes);
+                prop_assert_eq!(next.no_votes, stats.no_votes.saturating_add(1));
+            }
+            if i < u8::MAX as usize {
+                prop_assert_eq!(next.yes_votes as usize + next.no_votes as usize, i + 1);
+            }
+
+            stats = next;
+        }
+    }
+
+    #[test]
+    fn resolve_market_reveals_the_strict_majority(yes_votes: u8, no_votes: u8, quorum_size: u8) {
+        let stats = reference::VoteStats { yes_votes, no_votes };
+        let result = reference::resolve_market(stats, quorum_size);
+
+        prop_assert_eq!(result.outcome, yes_votes > no_votes);
+        prop_assert_eq!(result.yes_votes, yes_votes);
+        prop_assert_eq!(result.no_votes, no_votes);
+    }
+
+    #[test]
+    fn categorical_pools_hold_every_in_range_stake(
+        submissions in categorical_submissions(),
+        num_outcomes in 2..=MAX_OUTCOMES as u8,
+    ) {
+        let stats = submissions.iter().fold(
+            reference::initialize_categorical_problem(),
+            |stats, &(choice, amount)| {
+                reference::submit_categorical_solution(choice, amount, num_outcomes, stats)
+            },
+        );
+
+        let credited: u128 = submissions
+            .iter()
+            .filter(|&&(choice, _)| choice < num_outcomes)
+            .map(|&(_, amount)| amount as u128)
+            .sum();
+        let pooled: u128 = stats.pools.iter().map(|&pool| pool as u128).sum();
+
+        prop_assert_eq!(pooled, credited);
+        for i in num_outcomes as usize..MAX_OUTCOMES {
+            prop_assert_eq!(stats.pools[i], 0);
+        }
+    }
+
+    #[test]
+    fn categorical_resolution_is_the_lowest_argmax(
+        votes in prop::array::uniform4(any::<u8>()),
+        num_outcomes in 2..=MAX_OUTCOMES as u8,
+    ) {
+        let stats = CategoricalStats { votes, pools: [0; MAX_OUTCOMES] };
+        let result = reference::resolve_categorical_market(stats, num_outcomes);
+        let live = &votes[..num_outcomes as usize];
+
+        prop_assert!((result.winning_outcome as usize) < live.len());
+        prop_assert_eq!(result.winning_votes, *live.iter().max().unwrap());
+        prop_assert_eq!(
+            result.winning_outcome as usize,
+            live.iter().position(|&v| v == result.winning_votes).unwrap()
+        );
+    }
+
+    #[test]
+    fn categorical_votes_are_monotonic(
+        votes in prop::collection::vec(0..MAX_OUTCOMES as u8 + 2, 0..300),
+        num_outcomes in 2..=MAX_OUTCOMES as u8,
+    ) {
+        let mut stats = reference::initialize_categorical_problem();
+
+        for &vote in &votes {
+            let next = reference::submit_categorical_vote(vote, num_outcomes, stats);
+            for i in 0..MAX_OUTCOMES {
+                let expected = if i == vote as usize && vote < num_outcomes {
+                    stats.votes[i].saturating_add(1)
+                } else {
+                    stats.votes[i]
+                };
+                prop_assert_eq!(next.votes[i], expected);
+            }
+            stats = next;
+        }
+    }
+
+    #[test]
+    fn categorical_payouts_never_exceed_the_pools(
+        submissions in categorical_submissions(),
+        num_outcomes in 2..=MAX_OUTCOMES as u8,
+        ballots in prop::collection::vec(0..MAX_OUTCOMES as u8, 0..16),
+    ) {
+        let stats = submissions.iter().fold(
+            reference::initialize_categorical_problem(),
+            |stats, &(choice, amount)| {
+                reference::submit_categorical_solution(choice, amount, num_outcomes, stats)
+            },
+        );
+        let stats = ballots.iter().fold(stats, |stats, &vote| {
+            reference::submit_categorical_vote(vote, num_outcomes, stats)
+        });
+        let result = reference::resolve_categorical_market(stats, num_outcomes);
+
+        let mut paid = 0u128;
+        let mut winners = 0u128;
+        for &(choice, amount) in &submissions {
+            let payout = reference::pay_categorical_winner(
+                choice,
+                result.winning_outcome,
+                amount,
+                result.winning_pool,
+                result.total_pool,
+            );
+
+            if payout.won {
+                prop_assert!(payout.payout_amount >= amount);
+                winners += 1;
+            } else {
+                prop_assert_eq!(payout.payout_amount, 0);
+            }
+            paid += payout.payout_amount as u128;
+        }
+
+        prop_assert!(paid <= result.total_pool as u128);
+        if result.winning_pool > 0 {
+            prop_assert!(result.total_pool as u128 - paid <= winners);
+        }
+    }
+
+    #[test]
+    fn scoring_reveals_the_first_best_solution(
+        scores in prop::collection::vec(any::<u64>(), 0..64),
+    ) {
+        let board = scores
+            .iter()
+            .enumerate()
+            .fold(reference::initialize_scoring(), |board, (i, &score)| {
+                reference::score_solution(score, i as u64, board)
+            });
+        let best = reference::reveal_best_solution(board);
+
+        prop_assert_eq!(best.has_score, !scores.is_empty());
+        if let Some(&max) = scores.iter().max() {
+            prop_assert_eq!(
+                best.best_index as usize,
+                scores.iter().position(|&score| score == max).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn sealed_bids_reveal_the_first_cheapest_valid_quote(
+        bids in prop::collection::vec(0..2_000u64, 0..64),
+        max_price in 1..1_500u64,
+    ) {
+        let board = bids
+            .iter()
+            .enumerate()
+            .fold(reference::initialize_bid_board(), |board, (i, &bid)| {
+                reference::submit_bid(bid, i as u64, max_price, board)
+            });
+        let lowest = reference::reveal_lowest_bid(board);
+
+        let valid = bids
+            .iter()
+            .enumerate()
+            .filter(|&(_, &bid)| bid > 0 && bid <= max_price);
+        match valid.min_by_key(|&(i, &bid)| (bid, i)) {
+            Some((i, &bid)) => {
+                prop_assert!(lowest.has_bid);
+                prop_assert_eq!(lowest.lowest_bid, bid);
+                prop_assert_eq!(lowest.lowest_index, i as u64);
+                // Settlement pays the price and refunds the rest, never more.
+                prop_assert!(lowest.lowest_bid <= max_price);
+            }
+            None => prop_assert!(!lowest.has_bid),
+        }
+    }
+
+    #[test]
+    fn raffle_draws_only_succeed_inside_the_entrant_set(
+        entrant_count in 0..256u64,
+        sample in prop::option::of(0..512u64),
+    ) {
+        let draw = reference::draw_raffle_winner(entrant_count, sample);
+
+        if draw.success {
+            prop_assert!(draw.winner_index < entrant_count);
+            prop_assert_eq!(Some(draw.winner_index), sample);
+        } else {
+            prop_assert!(entrant_count == 0 || sample.is_none_or(|index| index >= entrant_count));
+        }
+    }
+
+    #[test]
+    fn confidential_bounties_split_the_escrow_exactly(bounty: u64, bounty_cap: u64) {
+        let stored = reference::initialize_confidential_bounty(bounty, bounty_cap);
+        let release = reference::pay_confidential_bounty(stored, bounty_cap);
+
+        prop_assert_eq!(
+            release.payout_amount as u128 + release.refund_amount as u128,
+            bounty_cap as u128
+        );
+        prop_assert_eq!(release.payout_amount, bounty.min(bounty_cap));
+        prop_assert_eq!(release.capped, bounty > bounty_cap);
+    }
+
+    #[test]
+    fn duplicates_are_flagged_only_against_tracked_earlier_submissions(
+        hashes in prop::collection::vec(0..8u128, 0..32),
+    ) {
+        let mut submissions = reference::SubmissionHashes::default();
+
+        for (i, &hash) in hashes.iter().enumerate() {
+            let (next, duplicate) =
+                reference::record_submission_hash(hash, i as u64, submissions);
+
+            let tracked = &hashes[..i.min(MAX_TRACKED_SUBMISSIONS)];
+            prop_assert_eq!(duplicate, tracked.contains(&hash));
+            if i < MAX_TRACKED_SUBMISSIONS {
+                prop_assert_eq!(next.hashes[i], hash);
+            } else {
+                prop_assert_eq!(next, submissions);
+            }
+
+            submissions = next;
+        }
+    }
+
+    #[test]
+    fn juror_checks_split_the_panel_like_the_tally(votes in prop::collection::vec(any::<bool>(), 1..16)) {
+        let stats = votes.iter().fold(reference::initialize_problem(), |stats, &vote| {
+            reference::submit_quorum_vote(QuorumVote { vote }, stats)
+        });
+        let result = reference::resolve_market(stats, votes.len() as u8);
+
+        let sided = votes
+            .iter()
+            .filter(|&&vote| reference::check_juror_vote(QuorumVote { vote }, result.outcome))
+            .count();
+        let expected = if result.outcome { result.yes_votes } else { result.no_votes };
+
+        prop_assert_eq!(sided, expected as usize);
+    }
+
+    #[test]
+    fn judging_panels_only_reveal_a_candidate_at_the_threshold(
+        picks in prop::collection::vec(0..MAX_JUDGE_CANDIDATES as u8 + 2, 1..10),
+        num_candidates in 1..=MAX_JUDGE_CANDIDATES as u8,
+        threshold in 1..10u8,
+    ) {
+        let mut tally = reference::initialize_judge_tally();
+        let mut counts = [0u8; MAX_JUDGE_CANDIDATES];
+
+        for &pick in &picks {
+            let (next, decision) =
+                reference::submit_judge_pick(pick, num_candidates, threshold, tally);
+            if pick < num_candidates {
+                counts[pick as usize] += 1;
+            }
+
+            let live = &counts[..num_candidates as usize];
+            match live.iter().position(|&votes| votes >= threshold) {
+                Some(index) => {
+                    prop_assert!(decision.decided);
+                    prop_assert_eq!(decision.winning_index as usize, index);
+                }
+                None => {
+                    // An undecided panel reveals nothing beyond the fact.
+                    prop_assert_eq!(decision, reference::PanelDecision::default());
+                }
+            }
+            prop_assert_eq!(next.votes, counts);
+
+            tally = next;
+        }
+    }
+}
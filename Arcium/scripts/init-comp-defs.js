@@ -66,7 +66,7 @@ async function initCompDef(name) {
 
   console.log(`Initializing ${name} computation definition...`);
   const sig = await program.methods
-    [`init${name.charAt(0).toUpperCase() + name.slice(1).replace(/_([a-z])/g, (g) => g[1].toUpperCase())}CompDef`]()
+    [`init${name.charAt(0).toUpperCase() + name.slice(1).replace(/_([a-z])/g, (g) => g[1].toUpperCase())}CompDef`](null)
     .accounts({
       compDefAccount: compDefPDA,
       payer: ownerKp.publicKey,
@@ -6,11 +6,245 @@
  */
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{
+    Field, TokenMetadata,
+};
+use anchor_spl::token_interface::{
+    mint_to, token_metadata_initialize, token_metadata_update_field, Mint, MintTo, TokenAccount,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
 use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::{CircuitSource, OffChainCircuitSource};
 
-const COMP_DEF_OFFSET_INITIALIZE_PROBLEM: u32 = comp_def_offset("initialize_problem");
-const COMP_DEF_OFFSET_SUBMIT_SOLUTION: u32 = comp_def_offset("submit_solution");
-const COMP_DEF_OFFSET_PAY_WINNER: u32 = comp_def_offset("pay_winner");
+/// Computation definition offset of version `$version` of `$circuit`, for the
+/// constants the Arcium account macros expect. Version 1 keeps the bare circuit
+/// name so comp defs registered before versioning stay valid; later versions
+/// are compiled as `<circuit>_v<version>` and registered alongside the earlier
+/// ones.
+macro_rules! versioned_comp_def_offset {
+    ($circuit:literal, 1) => {
+        comp_def_offset($circuit)
+    };
+    ($circuit:literal, $version:literal) => {
+        comp_def_offset(concat!($circuit, "_v", $version))
+    };
+}
+
+const COMP_DEF_OFFSET_INITIALIZE_PROBLEM: u32 = versioned_comp_def_offset!("initialize_problem", 1);
+const COMP_DEF_OFFSET_SUBMIT_SOLUTION: u32 = versioned_comp_def_offset!("submit_solution", 1);
+const COMP_DEF_OFFSET_PAY_WINNER: u32 = versioned_comp_def_offset!("pay_winner", 1);
+const COMP_DEF_OFFSET_INITIALIZE_CATEGORICAL_PROBLEM: u32 =
+    versioned_comp_def_offset!("initialize_categorical_problem", 1);
+const COMP_DEF_OFFSET_SUBMIT_CATEGORICAL_SOLUTION: u32 =
+    versioned_comp_def_offset!("submit_categorical_solution", 1);
+const COMP_DEF_OFFSET_SUBMIT_CATEGORICAL_VOTE: u32 =
+    versioned_comp_def_offset!("submit_categorical_vote", 1);
+const COMP_DEF_OFFSET_RESOLVE_CATEGORICAL_MARKET: u32 =
+    versioned_comp_def_offset!("resolve_categorical_market", 1);
+const COMP_DEF_OFFSET_PAY_CATEGORICAL_WINNER: u32 =
+    versioned_comp_def_offset!("pay_categorical_winner", 1);
+const COMP_DEF_OFFSET_INITIALIZE_SCORING: u32 = versioned_comp_def_offset!("initialize_scoring", 1);
+const COMP_DEF_OFFSET_SCORE_SOLUTION: u32 = versioned_comp_def_offset!("score_solution", 1);
+const COMP_DEF_OFFSET_REVEAL_BEST_SOLUTION: u32 =
+    versioned_comp_def_offset!("reveal_best_solution", 1);
+const COMP_DEF_OFFSET_INITIALIZE_BID_BOARD: u32 =
+    versioned_comp_def_offset!("initialize_bid_board", 1);
+const COMP_DEF_OFFSET_SUBMIT_BID: u32 = versioned_comp_def_offset!("submit_bid", 1);
+const COMP_DEF_OFFSET_REVEAL_LOWEST_BID: u32 = versioned_comp_def_offset!("reveal_lowest_bid", 1);
+const COMP_DEF_OFFSET_DRAW_RAFFLE_WINNER: u32 = versioned_comp_def_offset!("draw_raffle_winner", 1);
+const COMP_DEF_OFFSET_INITIALIZE_CONFIDENTIAL_BOUNTY: u32 =
+    versioned_comp_def_offset!("initialize_confidential_bounty", 1);
+const COMP_DEF_OFFSET_PAY_CONFIDENTIAL_BOUNTY: u32 =
+    versioned_comp_def_offset!("pay_confidential_bounty", 1);
+const COMP_DEF_OFFSET_SUBMIT_QUORUM_VOTE: u32 = versioned_comp_def_offset!("submit_quorum_vote", 1);
+const COMP_DEF_OFFSET_RESOLVE_MARKET: u32 = versioned_comp_def_offset!("resolve_market", 1);
+const COMP_DEF_OFFSET_CHECK_JUROR_VOTE: u32 = versioned_comp_def_offset!("check_juror_vote", 1);
+const COMP_DEF_OFFSET_DRAW_JURY: u32 = versioned_comp_def_offset!("draw_jury", 1);
+const COMP_DEF_OFFSET_INITIALIZE_JUDGE_TALLY: u32 =
+    versioned_comp_def_offset!("initialize_judge_tally", 1);
+const COMP_DEF_OFFSET_SUBMIT_JUDGE_PICK: u32 = versioned_comp_def_offset!("submit_judge_pick", 1);
+
+/// Newest version of the circuit suite. New problems are pinned to it, and every
+/// computation queued for a problem runs the comp def of the version it was
+/// created under, so older problems keep running the circuits they started on.
+pub const CIRCUIT_VERSION: u8 = 1;
+
+/// Runtime counterpart of `versioned_comp_def_offset!`, for a version only known
+/// from account data.
+pub fn versioned_comp_def_offset(circuit: &str, version: u8) -> u32 {
+    if version == 1 {
+        comp_def_offset(circuit)
+    } else {
+        comp_def_offset(&format!("{circuit}_v{version}"))
+    }
+}
+
+/// Whether `comp_def` is the computation definition of any version of `circuit`
+/// up to `CIRCUIT_VERSION`. Callbacks accept all of them, since a callback can
+/// land for a problem pinned to any earlier version.
+pub fn is_versioned_comp_def(comp_def: &Pubkey, circuit: &str) -> bool {
+    (1..=CIRCUIT_VERSION).any(|version| {
+        *comp_def == derive_comp_def_pda!(versioned_comp_def_offset(circuit, version))
+    })
+}
+
+/// Records version `$version` of the circuit behind `$comp_def_offset` in the
+/// `CircuitRecord` and initializes its comp def, from `$circuit_source` when
+/// the circuit is hosted off-chain.
+macro_rules! init_versioned_comp_def {
+    ($ctx:expr, $comp_def_offset:expr, $version:literal, $circuit_source:expr) => {{
+        let circuit_source: Option<OffChainSource> = $circuit_source;
+        $ctx.accounts.circuit_record.register(
+            $ctx.bumps.circuit_record,
+            $comp_def_offset,
+            $version,
+            &circuit_source,
+        )?;
+        init_comp_def(
+            $ctx.accounts,
+            true,
+            0,
+            circuit_source.map(OffChainSource::into_circuit_source),
+            None,
+        )
+    }};
+}
+
+/// Maximum length of an off-chain circuit source URL.
+pub const MAX_CIRCUIT_SOURCE_LEN: usize = 200;
+
+/// Mirrors `MAX_OUTCOMES` in the circuits.
+pub const MAX_OUTCOMES: usize = 4;
+
+/// One ciphertext per vote tally and per pool in `CategoricalStats`.
+const CATEGORICAL_STATS_LEN: usize = 2 * MAX_OUTCOMES;
+
+/// `best_score`, `best_index` and `has_score` in `ScoreBoard`.
+const SCORE_BOARD_LEN: usize = 3;
+
+/// `lowest_bid`, `lowest_index` and `has_bid` in `BidBoard`.
+const BID_BOARD_LEN: usize = 3;
+
+/// Upper bound on the number of valid solutions a raffle can draw from.
+pub const MAX_RAFFLE_ENTRANTS: usize = 64;
+
+/// Mirrors `MAX_TRACKED_SUBMISSIONS` in the circuits. Only the first 16
+/// submissions to a problem are remembered; later ones are still checked
+/// against them, but a copy of a later submission goes undetected.
+pub const MAX_TRACKED_SUBMISSIONS: usize = 16;
+
+/// Seconds after the deadline before a creator can withdraw an unused compute budget.
+pub const COMPUTE_BUDGET_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// Smallest deposit a juror must keep staked to be drawn onto a panel.
+pub const MIN_JUROR_STAKE: u64 = 100_000_000;
+
+/// Upper bound on the number of registered jurors.
+pub const MAX_POOL_JURORS: usize = 64;
+
+/// Upper bound on the number of jurors on one panel; `JuryPanel` tracks them in `u16` bitmaps.
+pub const MAX_PANEL_SIZE: usize = 9;
+
+/// Share of its stake, in basis points, a juror loses for voting against the
+/// outcome or not voting at all.
+pub const JUROR_SLASH_BPS: u64 = 1_000;
+
+/// Dispute window a problem starts with, in seconds after `close_problem`.
+pub const DEFAULT_DISPUTE_WINDOW: i64 = 3 * 24 * 60 * 60;
+
+/// Longest dispute window a creator can configure.
+pub const MAX_DISPUTE_WINDOW: i64 = 14 * 24 * 60 * 60;
+
+/// Lamports a solver locks to challenge a winner.
+pub const DISPUTE_BOND: u64 = 50_000_000;
+
+/// Jurors drawn for every dispute. Dispute panels take no caller-chosen terms,
+/// so neither side can size one that never seats or never reaches quorum.
+pub const DISPUTE_PANEL_SIZE: u8 = 5;
+
+/// Lamports a challenger pays into the dispute panel for its jurors.
+pub const DISPUTE_JUROR_FEE: u64 = 10_000_000;
+
+/// Seconds a dispute jury has to vote, counted from `dispute_winner`.
+pub const DISPUTE_VOTE_PERIOD: i64 = 2 * 24 * 60 * 60;
+
+/// Seconds after the vote deadline for a dispute jury's tally to be revealed;
+/// after that the dispute is resolved as if the jury missed quorum.
+pub const DISPUTE_RESOLUTION_PERIOD: i64 = 24 * 60 * 60;
+
+/// Most judges a creator can delegate a problem to.
+pub const MAX_JUDGES: usize = 9;
+
+/// Mirrors `MAX_JUDGE_CANDIDATES` in the circuits: judges can only pick
+/// between the first solutions submitted to a problem.
+pub const MAX_JUDGE_CANDIDATES: usize = 16;
+
+/// Most members a multisig authority can have.
+pub const MAX_MULTISIG_MEMBERS: usize = 10;
+
+/// How long after the deadline a creator can pick a winner and still have the
+/// bounty count as paid on time.
+pub const ON_TIME_CLOSE_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+/// Largest creator bond a problem can be posted with, in lamports.
+pub const MAX_CREATOR_BOND: u64 = 100_000_000_000;
+
+/// Shortest review window a bonded problem can give its creator.
+pub const MIN_REVIEW_WINDOW: i64 = 24 * 60 * 60;
+
+/// Longest review window a bonded problem can give its creator.
+pub const MAX_REVIEW_WINDOW: i64 = 30 * 24 * 60 * 60;
+
+/// Longest schedule a winner's bounty can be vested over.
+pub const MAX_VESTING_DURATION: i64 = 365 * 24 * 60 * 60;
+
+/// Seed of the PDA that mints winner badges and owns their metadata.
+pub const BADGE_AUTHORITY_SEED: &[u8] = b"badge_authority";
+
+/// Symbol shared by every winner badge.
+pub const BADGE_SYMBOL: &str = "BOUNTY";
+
+/// Most solvers a season pays out, and so the length of its leaderboard.
+pub const MAX_SEASON_WINNERS: usize = 10;
+
+/// Season points for winning a problem in the season.
+pub const SEASON_WIN_POINTS: u64 = 10;
+
+/// Season points for any other paid-out solution in the season.
+pub const SEASON_ENTRY_POINTS: u64 = 1;
+
+/// Shortest gap between rounds spawned from a bounty template.
+pub const MIN_TEMPLATE_CADENCE: i64 = 60 * 60;
+
+/// Most milestones a problem's plan can be split into.
+pub const MAX_MILESTONES: usize = 8;
+
+/// Longest preimage a puzzle solver can reveal.
+pub const MAX_PUZZLE_PREIMAGE_LEN: usize = 256;
+
+/// Solutions listed on each `SolutionIndexPage`.
+pub const SOLUTIONS_PER_PAGE: u64 = 32;
+
+/// Longest description stored on `Problem` itself; anything longer goes in
+/// `ProblemContent` chunks.
+pub const MAX_DESCRIPTION_LEN: usize = 200;
+
+/// Bytes of content each `ProblemContent` chunk holds.
+pub const CONTENT_CHUNK_LEN: usize = 900;
+
+/// Most `ProblemContent` chunks a problem's content can span.
+pub const MAX_CONTENT_CHUNKS: usize = 16;
 
 declare_id!("AMgZmVhB17SVSQAbhTHaZzHPurArHaJ7zJeLdcwKRhE2");
 
@@ -24,324 +258,9229 @@ pub fn validate_callback_ixs(_instructions_sysvar: &AccountInfo, _program_id: &P
     Ok(())
 }
 
-#[program]
-pub mod bounty_solver {
-    use super::*;
+// Helper function for paying out of a program-owned escrow such as `Problem`
+pub fn release_escrow(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let remaining = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientEscrow)?;
+    let credited = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::InvalidAmount)?;
 
-    pub fn init_initialize_problem_comp_def(
-        ctx: Context<InitInitializeProblemCompDef>,
-    ) -> Result<()> {
-        init_comp_def(ctx.accounts, true, 0, None, None)?;
-        Ok(())
-    }
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? = credited;
 
-    pub fn init_submit_solution_comp_def(ctx: Context<InitSubmitSolutionCompDef>) -> Result<()> {
-        init_comp_def(ctx.accounts, true, 0, None, None)?;
-        Ok(())
-    }
+    Ok(())
+}
 
-    pub fn init_pay_winner_comp_def(
-        ctx: Context<InitPayWinnerCompDef>,
-    ) -> Result<()> {
-        init_comp_def(ctx.accounts, true, 0, None, None)?;
-        Ok(())
-    }
+// Helper function for recording the MPC fee a `queue_computation` call charged
+// `payer` and reimbursing it from the problem's prepaid compute budget
+pub fn reimburse_mpc_fee<'info>(
+    problem: &mut Account<'info, Problem>,
+    payer: &AccountInfo<'info>,
+    fee: u64,
+) -> Result<()> {
+    let reimbursed = fee.min(problem.compute_budget);
+    release_escrow(&problem.to_account_info(), payer, reimbursed)?;
 
-    pub fn create_problem(
-        ctx: Context<CreateProblem>,
-        computation_offset: u64,
-        problem_id: u64,
-        title: String,
-        description: String,
-        deadline: i64,
-        bounty_amount: u64,
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+    problem.mpc_fees = problem.mpc_fees.saturating_add(fee);
+    problem.compute_budget -= reimbursed;
 
-        require!(
-            deadline > Clock::get()?.unix_timestamp,
-            ErrorCode::InvalidDeadline
-        );
-        require!(
-            !title.is_empty() && title.len() <= 200,
-            ErrorCode::InvalidTitle
-        );
+    emit!(MpcFeeReimbursedEvent {
+        problem_id: problem.problem_id,
+        payer: payer.key(),
+        fee,
+        reimbursed,
+        remaining_budget: problem.compute_budget,
+    });
 
-        let args = vec![Argument::PlaintextU128(nonce)];
+    Ok(())
+}
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![InitializeProblemCallback::callback_ix(&[])],
-        )?;
+// Helper function for drawing a panel from the juror pool. The seed comes from
+// the MPC cluster via `draw_jury`, and the draw can be recomputed off-chain
+// from the revealed panel to pass the drawn `Juror` accounts.
+pub fn draw_jurors(
+    pool: &[Pubkey],
+    excluded: &[Pubkey],
+    seed: [u8; 32],
+    count: usize,
+) -> Vec<Pubkey> {
+    let mut candidates: Vec<Pubkey> = pool
+        .iter()
+        .filter(|juror| !excluded.contains(juror))
+        .copied()
+        .collect();
+    let mut drawn = Vec::with_capacity(count);
+    let mut seed = seed;
 
-        let problem = &mut ctx.accounts.problem;
-        problem.bump = ctx.bumps.problem;
-        problem.problem_id = problem_id;
-        problem.creator = ctx.accounts.creator.key();
-        problem.title = title.clone();
-        problem.description = description;
-        problem.deadline = deadline;
-        problem.bounty_amount = bounty_amount;
-        problem.state = ProblemState::Active;
-        problem.total_bounty = bounty_amount;
-        problem.total_solutions = 0;
-        problem.winner = None;
-        problem.encrypted_data = [[0; 32]; 2];
-        problem.nonce = 0;
+    while drawn.len() < count && !candidates.is_empty() {
+        seed = hashv(&[&seed]).to_bytes();
+        let pick = u64::from_le_bytes(seed[..8].try_into().unwrap()) % candidates.len() as u64;
+        drawn.push(candidates.swap_remove(pick as usize));
+    }
 
-        emit!(ProblemPostedEvent {
-            problem_id,
-            creator: ctx.accounts.creator.key(),
-            title,
-            deadline,
-            bounty_amount,
-        });
+    drawn
+}
 
-        Ok(())
-    }
+// Helper function for slashing a held creator bond: the slashed share is set
+// aside for the problem's solvers to claim and the rest goes back to the creator
+pub fn slash_creator_bond<'info>(
+    problem: &mut Account<'info, Problem>,
+    creator: &AccountInfo<'info>,
+) -> Result<()> {
+    // With nobody to compensate the whole bond is simply returned.
+    let slashed = if problem.total_solutions > 0 {
+        (problem.creator_bond as u128 * problem.bond_slash_bps as u128 / 10_000) as u64
+    } else {
+        0
+    };
+    let refunded = problem.creator_bond - slashed;
+    release_escrow(&problem.to_account_info(), creator, refunded)?;
 
-    #[arcium_callback(encrypted_ix = "initialize_problem")]
-    pub fn initialize_problem_callback(
-        ctx: Context<InitializeProblemCallback>,
-        output: ComputationOutputs<InitializeProblemOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(InitializeProblemOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+    problem.bond_status = BondStatus::Slashed;
+    problem.bond_slashed = slashed;
 
-        ctx.accounts.problem.encrypted_data = o.ciphertexts;
-        ctx.accounts.problem.nonce = o.nonce;
+    emit!(CreatorBondSlashedEvent {
+        problem_id: problem.problem_id,
+        slashed,
+        refunded,
+    });
 
-        Ok(())
-    }
+    Ok(())
+}
 
-    pub fn submit_solution(
-        ctx: Context<SubmitSolution>,
-        computation_offset: u64,
-        problem_id: u64,
-        amount: u64,
-        encrypted_solution: [u8; 32],
-        solution_pubkey: [u8; 32],
-        solution_nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+// Helper function for handing a held creator bond back once the problem is
+// judged, shared by `return_creator_bond` and executed multisig proposals
+pub fn return_held_bond<'info>(
+    problem: &mut Account<'info, Problem>,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
 
-        let problem = &mut ctx.accounts.problem;
+    require!(
+        problem.bond_status == BondStatus::Held,
+        ErrorCode::CreatorBondNotHeld
+    );
+    require!(
+        matches!(problem.state, ProblemState::Closed | ProblemState::Settled),
+        ErrorCode::ProblemNotClosed
+    );
+    require!(!problem.missed_review(now), ErrorCode::ReviewWindowMissed);
+    // The bond stays at stake for as long as the winner can be disputed.
+    require!(
+        !problem.in_dispute_window(now),
+        ErrorCode::DisputeWindowOpen
+    );
 
-        require!(
-            problem.state == ProblemState::Active,
-            ErrorCode::ProblemNotActive
-        );
-        require!(
-            Clock::get()?.unix_timestamp < problem.deadline,
-            ErrorCode::DeadlinePassed
-        );
-        require!(amount >= 0, ErrorCode::InvalidAmount);
+    let bond = problem.creator_bond;
+    release_escrow(&problem.to_account_info(), to, bond)?;
+    problem.bond_status = BondStatus::Returned;
 
-        problem.total_solutions += 1;
+    emit!(CreatorBondReturnedEvent {
+        problem_id: problem.problem_id,
+        amount: bond,
+    });
 
-        let args = vec![
-            Argument::ArcisPubkey(solution_pubkey),
-            Argument::PlaintextU128(solution_nonce),
-            Argument::EncryptedBool(encrypted_solution),
-            Argument::PlaintextU64(amount),
-        ];
+    Ok(())
+}
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![SubmitSolutionCallback::callback_ix(&[])],
-        )?;
+// Helper function for handing an unused compute budget back after the grace
+// period, shared by `withdraw_compute_budget` and executed multisig proposals
+pub fn withdraw_unused_budget<'info>(
+    problem: &mut Account<'info, Problem>,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    // Solvers are still reimbursed for `pay_winner` after close, so leave
+    // them a grace period before the creator can take the budget back.
+    require!(
+        problem.state != ProblemState::Active,
+        ErrorCode::ProblemNotClosed
+    );
+    require!(
+        Clock::get()?.unix_timestamp
+            >= problem.deadline.saturating_add(COMPUTE_BUDGET_GRACE_PERIOD),
+        ErrorCode::DeadlineNotReached
+    );
 
-        let solution = &mut ctx.accounts.solution;
-        solution.bump = ctx.bumps.solution;
-        solution.problem_id = problem_id;
-        solution.solver = ctx.accounts.solver.key();
-        solution.amount = amount;
-        solution.encrypted_solution = encrypted_solution;
-        solution.solution_pubkey = solution_pubkey;
-        solution.solution_nonce = solution_nonce;
-        solution.timestamp = Clock::get()?.unix_timestamp;
-        solution.paid = false;
-        solution.payout_amount = 0;
+    let amount = problem.compute_budget;
+    release_escrow(&problem.to_account_info(), to, amount)?;
+    problem.compute_budget = 0;
 
-        emit!(SolutionSubmittedEvent {
-            problem_id,
-            solver: ctx.accounts.solver.key(),
-            amount,
-            timestamp: solution.timestamp,
-        });
+    emit!(ComputeBudgetUpdatedEvent {
+        problem_id: problem.problem_id,
+        remaining_budget: 0,
+        mpc_fees: problem.mpc_fees,
+    });
 
-        Ok(())
+    Ok(())
+}
+
+// Helper function for refunding the milestones nobody delivered by the
+// delivery deadline, shared by `refund_milestones` and executed multisig proposals
+pub fn refund_open_milestones<'info>(
+    problem: &mut Account<'info, Problem>,
+    plan: &mut MilestonePlan,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    // Cancelling already refunded the whole escrow, milestones included.
+    require!(
+        problem.state != ProblemState::Cancelled,
+        ErrorCode::NothingToRefund
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= plan.delivery_deadline,
+        ErrorCode::DeadlineNotReached
+    );
+
+    let mut amount = 0;
+    for milestone in plan.milestones.iter_mut() {
+        if matches!(
+            milestone.status,
+            MilestoneStatus::Pending | MilestoneStatus::Submitted
+        ) {
+            milestone.status = MilestoneStatus::Refunded;
+            amount += milestone.amount;
+        }
     }
+    require!(amount > 0, ErrorCode::NothingToRefund);
+    plan.refunded += amount;
 
-    #[arcium_callback(encrypted_ix = "submit_solution")]
-    pub fn submit_solution_callback(
-        ctx: Context<SubmitSolutionCallback>,
-        output: ComputationOutputs<SubmitSolutionOutput>,
-    ) -> Result<()> {
-        let _o = match output {
-            ComputationOutputs::Success(SubmitSolutionOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+    release_escrow(&problem.to_account_info(), to, amount)?;
+    problem.escrow_amount -= amount;
 
-        let problem = &mut ctx.accounts.problem;
+    emit!(MilestonesRefundedEvent {
+        problem_id: problem.problem_id,
+        amount,
+        refunded: plan.refunded,
+    });
+
+    Ok(())
+}
 
-        // Optional: update problem state based on MPC output
+// Helper function for refunding the escrow of a puzzle or verifier-judged
+// problem nobody solved, shared by `refund_unsolved_puzzle` and executed
+// multisig proposals
+pub fn refund_unsolved<'info>(
+    problem: &mut Account<'info, Problem>,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
 
-        Ok(())
-    }
+    // Verifier-judged problems nobody solved are refunded the same way.
+    require!(
+        matches!(
+            problem.judging_mode,
+            JudgingMode::Puzzle | JudgingMode::Verifier
+        ),
+        ErrorCode::InvalidJudgingMode
+    );
+    require!(
+        problem.state == ProblemState::Active,
+        ErrorCode::ProblemNotActive
+    );
+    require!(now >= problem.deadline, ErrorCode::DeadlineNotReached);
 
-    pub fn close_problem(
-        ctx: Context<CloseProblem>,
-        _problem_id: u64,
-        winner: Pubkey,
-    ) -> Result<()> {
-        let problem = &mut ctx.accounts.problem;
+    let amount = problem.escrow_amount;
+    release_escrow(&problem.to_account_info(), to, amount)?;
 
-        require!(
-            problem.state == ProblemState::Active,
-            ErrorCode::ProblemNotActive
-        );
-        require!(
-            Clock::get()?.unix_timestamp >= problem.deadline,
-            ErrorCode::DeadlineNotReached
-        );
+    problem.escrow_amount = 0;
+    problem.state = ProblemState::Settled;
+    problem.judged_at = now;
 
-        problem.winner = Some(winner);
-        problem.state = ProblemState::Closed;
+    emit!(PuzzleRefundedEvent {
+        problem_id: problem.problem_id,
+        amount,
+    });
 
-        emit!(ProblemClosedEvent {
-            problem_id: problem.problem_id,
-            winner,
-        });
+    Ok(())
+}
 
-        Ok(())
-    }
+// Helper function for refunding the escrowed cap of a confidential bounty the
+// creator never picked a winner for, shared by `refund_confidential_bounty`
+// and executed multisig proposals
+pub fn refund_confidential_cap<'info>(
+    problem: &mut Account<'info, Problem>,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
 
-    pub fn pay_winner(
-        ctx: Context<PayWinner>,
-        computation_offset: u64,
-        _problem_id: u64,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+    require!(
+        problem.bounty_mode == BountyMode::Confidential,
+        ErrorCode::InvalidBountyMode
+    );
+    require!(
+        problem.state == ProblemState::Active,
+        ErrorCode::ProblemNotActive
+    );
+    require!(problem.missed_review(now), ErrorCode::ReviewWindowOpen);
 
-        let problem = &ctx.accounts.problem;
+    let amount = problem.escrow_amount;
+    release_escrow(&problem.to_account_info(), to, amount)?;
+
+    problem.escrow_amount = 0;
+    problem.state = ProblemState::Settled;
+    problem.judged_at = now;
+
+    emit!(BountyRefundedEvent {
+        problem_id: problem.problem_id,
+        creator: problem.creator,
+        amount,
+    });
+
+    Ok(())
+}
 
+// Helper function for editing a live problem, shared by `update_problem` and
+// executed multisig proposals. Once someone has submitted, only the deadline
+// can move, and only later, so nobody's solution is judged against terms they
+// didn't see.
+pub fn apply_problem_update(problem: &mut Problem, update: ProblemUpdate) -> Result<()> {
+    require!(
+        problem.state == ProblemState::Active,
+        ErrorCode::ProblemNotActive
+    );
+    require!(
+        problem.total_solutions == 0 || (update.title.is_none() && update.description.is_none()),
+        ErrorCode::ProblemHasSolutions
+    );
+
+    if let Some(title) = update.title {
         require!(
-            problem.state == ProblemState::Closed,
-            ErrorCode::ProblemNotClosed
+            !title.is_empty() && title.len() <= 200,
+            ErrorCode::InvalidTitle
         );
+        problem.title = title;
+    }
+    if let Some(description) = update.description {
         require!(
-            problem.winner.is_some(),
-            ErrorCode::NoWinnerSet
+            description.len() <= MAX_DESCRIPTION_LEN,
+            ErrorCode::DescriptionTooLong
+        );
+        problem.description = description;
+    }
+    if let Some(deadline) = update.deadline {
+        require!(
+            deadline > Clock::get()?.unix_timestamp
+                && (problem.total_solutions == 0 || deadline >= problem.deadline),
+            ErrorCode::InvalidDeadline
         );
+        problem.deadline = deadline;
+    }
 
-        let solution = &mut ctx.accounts.solution;
-        require!(!solution.paid, ErrorCode::AlreadyPaid);
+    emit!(ProblemUpdatedEvent {
+        problem_id: problem.problem_id,
+        title: problem.title.clone(),
+        deadline: problem.deadline,
+    });
 
-        let args = vec![
-            Argument::ArcisPubkey(solution.solution_pubkey),
-            Argument::PlaintextU128(solution.solution_nonce),
-            Argument::EncryptedBool(solution.encrypted_solution),
-            Argument::PlaintextU64(solution.amount),
-            Argument::PlaintextU64(problem.total_bounty),
-        ];
+    Ok(())
+}
 
+// Helper function for withdrawing a problem nobody has submitted to, shared by
+// `cancel_problem` and executed multisig proposals. The escrow, the unused
+// compute budget and a held bond all go back to `to`.
+pub fn cancel_open_problem<'info>(
+    problem: &mut Account<'info, Problem>,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(
+        problem.state == ProblemState::Active,
+        ErrorCode::ProblemNotActive
+    );
+    require!(problem.total_solutions == 0, ErrorCode::ProblemHasSolutions);
+
+    let bond = if problem.bond_status == BondStatus::Held {
+        problem.creator_bond
+    } else {
+        0
+    };
+    let amount = problem
+        .escrow_amount
+        .checked_add(problem.compute_budget)
+        .and_then(|amount| amount.checked_add(bond))
+        .ok_or(ErrorCode::InvalidAmount)?;
+    release_escrow(&problem.to_account_info(), to, amount)?;
+
+    problem.escrow_amount = 0;
+    problem.compute_budget = 0;
+    if bond > 0 {
+        problem.bond_status = BondStatus::Returned;
+    }
+    problem.state = ProblemState::Cancelled;
+
+    emit!(ProblemCancelledEvent {
+        problem_id: problem.problem_id,
+        refunded: amount,
+    });
+
+    Ok(())
+}
+
+// Helper function for checking `solver` against a problem's eligibility rule.
+// Allowlist leaves are `hashv([solver])` and each proof step hashes the sorted
+// pair, so the proof does not need to say which side a sibling is on.
+pub fn check_eligibility(
+    rule: &EligibilityRule,
+    solver: &Pubkey,
+    allowlist_proof: &[[u8; 32]],
+    token_account: Option<&TokenAccount>,
+    metadata: Option<&MetadataAccount>,
+) -> Result<()> {
+    match rule {
+        EligibilityRule::Open => {}
+        EligibilityRule::Allowlist { merkle_root } => {
+            let leaf = hashv(&[solver.as_ref()]).to_bytes();
+            let root = allowlist_proof.iter().fold(leaf, |node, sibling| {
+                if node <= *sibling {
+                    hashv(&[&node, sibling]).to_bytes()
+                } else {
+                    hashv(&[sibling, &node]).to_bytes()
+                }
+            });
+            require!(root == *merkle_root, ErrorCode::NotEligible);
+        }
+        EligibilityRule::TokenHolder { mint, min_amount } => {
+            let token_account = token_account.ok_or(ErrorCode::NotEligible)?;
+            require!(
+                token_account.owner == *solver
+                    && token_account.mint == *mint
+                    && token_account.amount >= (*min_amount).max(1),
+                ErrorCode::NotEligible
+            );
+        }
+        EligibilityRule::NftCollection { collection } => {
+            let token_account = token_account.ok_or(ErrorCode::NotEligible)?;
+            let metadata = metadata.ok_or(ErrorCode::NotEligible)?;
+            require!(
+                token_account.owner == *solver
+                    && token_account.amount == 1
+                    && metadata.mint == token_account.mint,
+                ErrorCode::NotEligible
+            );
+            // Only a verified collection counts; anyone can claim an unverified one.
+            require!(
+                metadata
+                    .collection
+                    .as_ref()
+                    .is_some_and(|member| member.verified && member.key == *collection),
+                ErrorCode::NotEligible
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// Helper function for initializing a freshly created `Problem` as an open,
+// creator-judged binary problem with nothing escrowed, shared by
+// `create_problem`, `create_confidential_problem` and `spawn_round`, which then
+// set whatever their options change
+pub fn init_problem(
+    problem: &mut Problem,
+    bump: u8,
+    problem_id: u64,
+    creator: Pubkey,
+    title: String,
+    description: String,
+    deadline: i64,
+) {
+    problem.bump = bump;
+    problem.problem_id = problem_id;
+    problem.creator = creator;
+    problem.title = title;
+    problem.description = description;
+    problem.deadline = deadline;
+    problem.bounty_amount = 0;
+    problem.state = ProblemState::Active;
+    problem.market_kind = MarketKind::Binary;
+    problem.judging_mode = JudgingMode::Creator;
+    problem.bounty_mode = BountyMode::Standard;
+    problem.eligibility = EligibilityRule::Open;
+    problem.circuit_version = CIRCUIT_VERSION;
+    problem.escrow_amount = 0;
+    problem.escrowed_bounty = 0;
+    problem.compute_budget = 0;
+    problem.mpc_fees = 0;
+    problem.total_bounty = 0;
+    problem.total_solutions = 0;
+    problem.yes_pool = 0;
+    problem.no_pool = 0;
+    problem.winner = None;
+    problem.winning_index = None;
+    problem.winning_bid = 0;
+    problem.dispute_window = DEFAULT_DISPUTE_WINDOW;
+    problem.dispute_count = 0;
+    problem.jury_count = 0;
+    problem.closed_at = 0;
+    problem.creator_bond = 0;
+    problem.bond_slash_bps = 0;
+    problem.review_window = 0;
+    problem.bond_status = BondStatus::None;
+    problem.bond_slashed = 0;
+    problem.judged_at = 0;
+    problem.vesting_cliff = 0;
+    problem.vesting_duration = 0;
+    problem.season_id = None;
+    problem.puzzle_hash = None;
+    problem.verifier = None;
+    problem.content_hash = None;
+    problem.content_chunks = 0;
+    problem.encrypted_data = [[0; 32]; 2];
+    problem.nonce = 0;
+}
+
+// Helper function for closing a binary problem on the creator's pick, shared by
+// `close_problem` and executed multisig proposals
+pub fn close_with_winner(
+    problem: &mut Problem,
+    judging_panel: Option<&JudgingPanel>,
+    creator_profile: &mut CreatorProfile,
+    winner: Pubkey,
+) -> Result<()> {
+    require!(
+        problem.state == ProblemState::Active,
+        ErrorCode::ProblemNotActive
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= problem.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+    require!(
+        problem.market_kind == MarketKind::Binary,
+        ErrorCode::InvalidMarketKind
+    );
+    // A panel only hands the decision back to the creator once every judge
+    // has picked without any solution reaching the threshold.
+    match problem.judging_mode {
+        JudgingMode::Creator => {}
+        JudgingMode::Panel => require!(
+            judging_panel.is_some_and(|judging_panel| judging_panel.is_deadlocked()),
+            ErrorCode::PanelStillJudging
+        ),
+        _ => return Err(ErrorCode::InvalidJudgingMode.into()),
+    }
+    require!(
+        matches!(
+            problem.bounty_mode,
+            BountyMode::Standard | BountyMode::Confidential
+        ),
+        ErrorCode::InvalidBountyMode
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    problem.winner = Some(winner);
+    problem.state = ProblemState::Closed;
+    problem.judged_at = now;
+    // Only winners the creator picked alone can be disputed.
+    if problem.judging_mode == JudgingMode::Creator {
+        problem.closed_at = now;
+    }
+
+    creator_profile.record_close(problem.deadline, now);
+
+    emit!(ProblemClosedEvent {
+        problem_id: problem.problem_id,
+        winner,
+    });
+
+    Ok(())
+}
+
+// Helper function for checking the `ProblemContent` chunks passed to
+// `create_problem` against `content_hash`. The chunks must be the creator's,
+// in order from index 0, and their bytes concatenated must hash to it.
+pub fn verify_problem_content(
+    chunks: &[AccountInfo],
+    problem_id: u64,
+    creator: &Pubkey,
+    content_hash: [u8; 32],
+) -> Result<u8> {
+    require!(
+        !chunks.is_empty() && chunks.len() <= MAX_CONTENT_CHUNKS,
+        ErrorCode::InvalidProblemContent
+    );
+
+    let mut contents = Vec::with_capacity(chunks.len());
+    for (index, account) in chunks.iter().enumerate() {
+        let chunk = Account::<ProblemContent>::try_from(account)?;
+        require!(
+            chunk.problem_id == problem_id
+                && chunk.creator == *creator
+                && chunk.index as usize == index,
+            ErrorCode::InvalidProblemContent
+        );
+        contents.push(chunk.data.clone());
+    }
+
+    let slices: Vec<&[u8]> = contents.iter().map(Vec::as_slice).collect();
+    require!(
+        hashv(&slices).to_bytes() == content_hash,
+        ErrorCode::ContentHashMismatch
+    );
+
+    Ok(chunks.len() as u8)
+}
+
+// Helper function for writing a badge mint's embedded metadata the first time
+// it is used. The metadata names the problem and records its account under a
+// `problem` field so a badge can be traced back to the bounty it was won on.
+pub fn initialize_badge_metadata<'info>(
+    problem: &Account<'info, Problem>,
+    badge_mint: &InterfaceAccount<'info, Mint>,
+    badge_authority: &AccountInfo<'info>,
+    badge_authority_bump: u8,
+    payer: &AccountInfo<'info>,
+    token_program: &Program<'info, Token2022>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let mint_info = badge_mint.to_account_info();
+    {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+        if mint.get_variable_len_extension::<TokenMetadata>().is_ok() {
+            return Ok(());
+        }
+    }
+
+    let name = format!("Bounty #{} winner", problem.problem_id);
+    let uri = String::new();
+    let metadata = TokenMetadata {
+        name: name.clone(),
+        symbol: BADGE_SYMBOL.to_string(),
+        uri: uri.clone(),
+        additional_metadata: vec![("problem".to_string(), problem.key().to_string())],
+        ..Default::default()
+    };
+
+    // The metadata extension reallocates the mint, so top it up to stay rent exempt.
+    let rent = Rent::get()?.minimum_balance(mint_info.data_len() + metadata.tlv_size_of()?);
+    let top_up = rent.saturating_sub(mint_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: mint_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    let signer_seeds: &[&[&[u8]]] = &[&[BADGE_AUTHORITY_SEED, &[badge_authority_bump]]];
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: token_program.to_account_info(),
+                mint: mint_info.clone(),
+                metadata: mint_info.clone(),
+                mint_authority: badge_authority.clone(),
+                update_authority: badge_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        name,
+        BADGE_SYMBOL.to_string(),
+        uri,
+    )?;
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                program_id: token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: badge_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        Field::Key("problem".to_string()),
+        problem.key().to_string(),
+    )?;
+
+    Ok(())
+}
+
+// Helper function for minting a problem's single winner badge, shared by
+// `pay_winner_callback` and `pay_sealed_bid_winner`. A mint that already holds
+// the badge is left alone rather than failing the payout.
+pub fn mint_winner_badge<'info>(
+    badge_mint: &InterfaceAccount<'info, Mint>,
+    badge_account: &InterfaceAccount<'info, TokenAccount>,
+    badge_authority: &AccountInfo<'info>,
+    badge_authority_bump: u8,
+    token_program: &Program<'info, Token2022>,
+    solution: &Solution,
+) -> Result<()> {
+    if badge_mint.supply > 0 {
+        return Ok(());
+    }
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: badge_mint.to_account_info(),
+                to: badge_account.to_account_info(),
+                authority: badge_authority.clone(),
+            },
+            &[&[BADGE_AUTHORITY_SEED, &[badge_authority_bump]]],
+        ),
+        1,
+    )?;
+
+    emit!(WinnerBadgeMintedEvent {
+        problem_id: solution.problem_id,
+        solver: solution.solver,
+        mint: badge_mint.key(),
+    });
+
+    Ok(())
+}
+
+// Helper function for settling a solution's payout, shared by
+// `pay_winner_callback`, `pay_sealed_bid_winner` and
+// `pay_categorical_winner_callback`. Only the call that flips
+// `paid` counts the payout on the solver's profile and season leaderboard.
+pub fn record_solution_payout(
+    solution: &mut Solution,
+    solver_profile: &mut SolverProfile,
+    season: Option<(&mut Season, &mut SeasonEntry)>,
+    won: bool,
+    payout_amount: u64,
+) {
+    let first_payout = !solution.paid;
+    solution.paid = true;
+    solution.payout_amount = payout_amount;
+
+    if first_payout {
+        solver_profile.record_payout(won, payout_amount);
+    }
+
+    // Results that land after the season is paid out no longer score.
+    if let Some((season, season_entry)) = season {
+        if first_payout && !season.finalized {
+            let points = if won {
+                SEASON_WIN_POINTS
+            } else {
+                SEASON_ENTRY_POINTS
+            };
+            season_entry.points = season_entry.points.saturating_add(points);
+            season.record_points(season_entry.solver, season_entry.points);
+
+            emit!(SeasonPointsAwardedEvent {
+                season_id: season.season_id,
+                solver: season_entry.solver,
+                points,
+                total_points: season_entry.points,
+            });
+        }
+    }
+
+    emit!(WinnerPaidEvent {
+        problem_id: solution.problem_id,
+        solver: solution.solver,
+        solution_amount: solution.amount,
+        payout_amount,
+        is_winner: won,
+    });
+}
+
+#[program]
+pub mod bounty_solver {
+    use super::*;
+
+    pub fn init_initialize_problem_comp_def(
+        ctx: Context<InitInitializeProblemCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_INITIALIZE_PROBLEM, 1, circuit_source)
+    }
+
+    pub fn init_submit_solution_comp_def(
+        ctx: Context<InitSubmitSolutionCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_SUBMIT_SOLUTION, 1, circuit_source)
+    }
+
+    pub fn init_pay_winner_comp_def(
+        ctx: Context<InitPayWinnerCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_PAY_WINNER, 1, circuit_source)
+    }
+
+    pub fn init_initialize_categorical_problem_comp_def(
+        ctx: Context<InitInitializeCategoricalProblemCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_INITIALIZE_CATEGORICAL_PROBLEM,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_submit_categorical_solution_comp_def(
+        ctx: Context<InitSubmitCategoricalSolutionCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_SUBMIT_CATEGORICAL_SOLUTION,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_submit_categorical_vote_comp_def(
+        ctx: Context<InitSubmitCategoricalVoteCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_SUBMIT_CATEGORICAL_VOTE,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_resolve_categorical_market_comp_def(
+        ctx: Context<InitResolveCategoricalMarketCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_RESOLVE_CATEGORICAL_MARKET,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_pay_categorical_winner_comp_def(
+        ctx: Context<InitPayCategoricalWinnerCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_PAY_CATEGORICAL_WINNER,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_initialize_scoring_comp_def(
+        ctx: Context<InitInitializeScoringCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_INITIALIZE_SCORING, 1, circuit_source)
+    }
+
+    pub fn init_score_solution_comp_def(
+        ctx: Context<InitScoreSolutionCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_SCORE_SOLUTION, 1, circuit_source)
+    }
+
+    pub fn init_reveal_best_solution_comp_def(
+        ctx: Context<InitRevealBestSolutionCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_REVEAL_BEST_SOLUTION, 1, circuit_source)
+    }
+
+    pub fn init_initialize_bid_board_comp_def(
+        ctx: Context<InitInitializeBidBoardCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_INITIALIZE_BID_BOARD, 1, circuit_source)
+    }
+
+    pub fn init_submit_bid_comp_def(
+        ctx: Context<InitSubmitBidCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_SUBMIT_BID, 1, circuit_source)
+    }
+
+    pub fn init_reveal_lowest_bid_comp_def(
+        ctx: Context<InitRevealLowestBidCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_REVEAL_LOWEST_BID, 1, circuit_source)
+    }
+
+    pub fn init_draw_raffle_winner_comp_def(
+        ctx: Context<InitDrawRaffleWinnerCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_DRAW_RAFFLE_WINNER, 1, circuit_source)
+    }
+
+    pub fn init_initialize_confidential_bounty_comp_def(
+        ctx: Context<InitInitializeConfidentialBountyCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_INITIALIZE_CONFIDENTIAL_BOUNTY,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_pay_confidential_bounty_comp_def(
+        ctx: Context<InitPayConfidentialBountyCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_PAY_CONFIDENTIAL_BOUNTY,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_submit_quorum_vote_comp_def(
+        ctx: Context<InitSubmitQuorumVoteCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_SUBMIT_QUORUM_VOTE, 1, circuit_source)
+    }
+
+    pub fn init_resolve_market_comp_def(
+        ctx: Context<InitResolveMarketCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_RESOLVE_MARKET, 1, circuit_source)
+    }
+
+    pub fn init_check_juror_vote_comp_def(
+        ctx: Context<InitCheckJurorVoteCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_CHECK_JUROR_VOTE, 1, circuit_source)
+    }
+
+    pub fn init_draw_jury_comp_def(
+        ctx: Context<InitDrawJuryCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_DRAW_JURY, 1, circuit_source)
+    }
+
+    pub fn init_initialize_judge_tally_comp_def(
+        ctx: Context<InitInitializeJudgeTallyCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(
+            ctx,
+            COMP_DEF_OFFSET_INITIALIZE_JUDGE_TALLY,
+            1,
+            circuit_source
+        )
+    }
+
+    pub fn init_submit_judge_pick_comp_def(
+        ctx: Context<InitSubmitJudgePickCompDef>,
+        circuit_source: Option<OffChainSource>,
+    ) -> Result<()> {
+        init_versioned_comp_def!(ctx, COMP_DEF_OFFSET_SUBMIT_JUDGE_PICK, 1, circuit_source)
+    }
+
+    pub fn create_problem(
+        ctx: Context<CreateProblem>,
+        computation_offset: u64,
+        problem_id: u64,
+        title: String,
+        description: String,
+        deadline: i64,
+        bounty_amount: u64,
+        nonce: u128,
+        bounty_mode: BountyMode,
+        compute_budget: u64,
+        judges: Vec<Pubkey>,
+        judge_threshold: u8,
+        eligibility: EligibilityRule,
+        creator_bond: Option<CreatorBondTerms>,
+        vesting: Option<VestingTerms>,
+        puzzle_hash: Option<[u8; 32]>,
+        verifier: Option<Pubkey>,
+        content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        require!(
+            deadline > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidDeadline
+        );
+        require!(
+            !title.is_empty() && title.len() <= 200,
+            ErrorCode::InvalidTitle
+        );
+        require!(
+            description.len() <= MAX_DESCRIPTION_LEN,
+            ErrorCode::DescriptionTooLong
+        );
+        // Longer content is written to `ProblemContent` chunks beforehand and
+        // pinned here by its hash; the chunks are the remaining accounts.
+        let content_chunks = match content_hash {
+            Some(content_hash) => verify_problem_content(
+                ctx.remaining_accounts,
+                problem_id,
+                &ctx.accounts.creator.key(),
+                content_hash,
+            )?,
+            None => 0,
+        };
+
+        if let Some(vesting) = vesting {
+            require!(
+                bounty_mode == BountyMode::Standard,
+                ErrorCode::InvalidBountyMode
+            );
+            require!(
+                vesting.duration > 0
+                    && vesting.duration <= MAX_VESTING_DURATION
+                    && (0..=vesting.duration).contains(&vesting.cliff),
+                ErrorCode::InvalidVestingSchedule
+            );
+        }
+
+        // Puzzles and verifier-judged problems are judged by a program, so
+        // they take none of the options that involve the creator or a panel.
+        let auto_judged = puzzle_hash.is_some() || verifier.is_some();
+        if auto_judged {
+            require!(
+                bounty_mode == BountyMode::Standard
+                    && judges.is_empty()
+                    && eligibility == EligibilityRule::Open
+                    && creator_bond.is_none()
+                    && vesting.is_none(),
+                if puzzle_hash.is_some() {
+                    ErrorCode::InvalidPuzzle
+                } else {
+                    ErrorCode::InvalidVerifier
+                }
+            );
+            require!(
+                puzzle_hash.is_none() || verifier.is_none(),
+                ErrorCode::InvalidVerifier
+            );
+        }
+
+        // Sealed-bid bounties escrow the bounty, which doubles as the price
+        // ceiling; vested and auto-judged ones escrow it for the winner.
+        let escrow_amount = match bounty_mode {
+            BountyMode::Standard if vesting.is_some() || auto_judged => {
+                require!(bounty_amount > 0, ErrorCode::InvalidAmount);
+                bounty_amount
+            }
+            BountyMode::Standard => 0,
+            BountyMode::SealedBid => {
+                require!(bounty_amount > 0, ErrorCode::InvalidAmount);
+                bounty_amount
+            }
+            // Confidential bounties are posted through `create_confidential_problem`.
+            BountyMode::Confidential => return Err(ErrorCode::InvalidBountyMode.into()),
+        };
+
+        // Naming judges replaces the creator's own `close_problem` call with
+        // a panel decision.
+        require!(
+            ctx.accounts.judging_panel.is_some() != judges.is_empty(),
+            ErrorCode::InvalidJudgingPanel
+        );
+        let judging_mode = if puzzle_hash.is_some() {
+            JudgingMode::Puzzle
+        } else if verifier.is_some() {
+            JudgingMode::Verifier
+        } else if judges.is_empty() {
+            JudgingMode::Creator
+        } else {
+            require!(
+                bounty_mode == BountyMode::Standard,
+                ErrorCode::InvalidBountyMode
+            );
+            require!(
+                judges.len() <= MAX_JUDGES
+                    && judge_threshold > 0
+                    && judge_threshold as usize <= judges.len(),
+                ErrorCode::InvalidJudgingPanel
+            );
+            require!(
+                judges
+                    .iter()
+                    .enumerate()
+                    .all(|(i, judge)| !judges[..i].contains(judge)),
+                ErrorCode::InvalidJudgingPanel
+            );
+            JudgingMode::Panel
+        };
+        // Gating only applies to `submit_solution`, so bids stay open to anyone.
+        require!(
+            eligibility == EligibilityRule::Open || bounty_mode == BountyMode::Standard,
+            ErrorCode::InvalidBountyMode
+        );
+        let bond = creator_bond.unwrap_or_default();
+        if creator_bond.is_some() {
+            require!(
+                bond.amount > 0 && bond.amount <= MAX_CREATOR_BOND,
+                ErrorCode::InvalidCreatorBond
+            );
+            require!(bond.slash_bps <= 10_000, ErrorCode::InvalidCreatorBond);
+            require!(
+                (MIN_REVIEW_WINDOW..=MAX_REVIEW_WINDOW).contains(&bond.review_window),
+                ErrorCode::InvalidCreatorBond
+            );
+        }
+
+        // The compute budget sits next to the escrow and pays solvers back for MPC fees.
+        let deposit = escrow_amount
+            .checked_add(compute_budget)
+            .and_then(|deposit| deposit.checked_add(bond.amount))
+            .ok_or(ErrorCode::InvalidAmount)?;
+        if deposit > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.creator.to_account_info(),
+                        to: ctx.accounts.problem.to_account_info(),
+                    },
+                ),
+                deposit,
+            )?;
+        }
+
+        let args = vec![Argument::PlaintextU128(nonce)];
+
+        let creator_balance = ctx.accounts.creator.lamports();
         queue_computation(
             ctx.accounts,
             computation_offset,
             args,
             None,
-            vec![PayWinnerCallback::callback_ix(&[])],
+            vec![InitializeProblemCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.registry.key(),
+                    is_writable: true,
+                },
+            ])],
         )?;
+        let fee = creator_balance.saturating_sub(ctx.accounts.creator.lamports());
+
+        let problem = &mut ctx.accounts.problem;
+        init_problem(
+            problem,
+            ctx.bumps.problem,
+            problem_id,
+            ctx.accounts.creator.key(),
+            title.clone(),
+            description,
+            deadline,
+        );
+        problem.bounty_amount = bounty_amount;
+        problem.judging_mode = judging_mode;
+        problem.bounty_mode = bounty_mode;
+        problem.eligibility = eligibility;
+        problem.escrow_amount = escrow_amount;
+        problem.compute_budget = compute_budget;
+        problem.mpc_fees = fee;
+        problem.total_bounty = bounty_amount;
+        problem.creator_bond = bond.amount;
+        problem.bond_slash_bps = bond.slash_bps;
+        problem.review_window = bond.review_window;
+        if creator_bond.is_some() {
+            problem.bond_status = BondStatus::Held;
+        }
+        problem.vesting_cliff = vesting.map_or(0, |vesting| vesting.cliff);
+        problem.vesting_duration = vesting.map_or(0, |vesting| vesting.duration);
+        problem.puzzle_hash = puzzle_hash;
+        problem.verifier = verifier;
+        problem.content_hash = content_hash;
+        problem.content_chunks = content_chunks;
+
+        ctx.accounts.registry.open(ctx.bumps.registry, problem_id);
+
+        let creator_profile = &mut ctx.accounts.creator_profile;
+        creator_profile.bump = ctx.bumps.creator_profile;
+        creator_profile.creator = ctx.accounts.creator.key();
+        creator_profile.bounties_posted += 1;
+
+        if let Some(judging_panel) = ctx.accounts.judging_panel.as_mut() {
+            judging_panel.bump = ctx.bumps.judging_panel.unwrap_or_default();
+            judging_panel.encrypted_tally = [[0; 32]; MAX_JUDGE_CANDIDATES];
+            judging_panel.nonce = 0;
+            judging_panel.problem_id = problem_id;
+            judging_panel.judges = judges.clone();
+            judging_panel.threshold = judge_threshold;
+            judging_panel.opened = false;
+            judging_panel.voted = 0;
+            judging_panel.picks_cast = 0;
+            judging_panel.decided = false;
+            judging_panel.pending_seat = None;
+
+            emit!(JudgingPanelNamedEvent {
+                problem_id,
+                judges,
+                threshold: judge_threshold,
+            });
+        }
+
+        emit!(ProblemPostedEvent {
+            problem_id,
+            creator: ctx.accounts.creator.key(),
+            title,
+            deadline,
+            bounty_amount,
+        });
 
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "pay_winner")]
-    pub fn pay_winner_callback(
-        ctx: Context<PayWinnerCallback>,
-        output: ComputationOutputs<PayWinnerOutput>,
+    #[arcium_callback(encrypted_ix = "initialize_problem")]
+    pub fn initialize_problem_callback(
+        ctx: Context<InitializeProblemCallback>,
+        output: ComputationOutputs<InitializeProblemOutput>,
     ) -> Result<()> {
-        let result = match output {
-            ComputationOutputs::Success(PayWinnerOutput { field_0 }) => field_0,
+        let o = match output {
+            ComputationOutputs::Success(InitializeProblemOutput { field_0 }) => field_0,
             _ => return Err(ErrorCode::AbortedComputation.into()),
         };
 
-        let solution = &mut ctx.accounts.solution;
-        solution.paid = true;
-        solution.payout_amount = result.field_0;
+        ctx.accounts.problem.encrypted_data = o.field_0.ciphertexts;
+        ctx.accounts.problem.nonce = o.field_0.nonce;
 
-        emit!(WinnerPaidEvent {
-            problem_id: solution.problem_id,
-            solver: solution.solver,
-            solution_amount: solution.amount,
-            payout_amount: result.field_0,
-            is_winner: result.field_1,
-        });
+        let registry = &mut ctx.accounts.registry;
+        registry.encrypted_hashes = o.field_1.ciphertexts;
+        registry.nonce = o.field_1.nonce;
+        registry.pending = false;
 
         Ok(())
     }
-}
 
-#[queue_computation_accounts("initialize_problem", creator)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64, problem_id: u64)]
-pub struct CreateProblem<'info> {
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = creator,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INITIALIZE_PROBLEM)
-    )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Account<'info, Cluster>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
+    pub fn submit_solution(
+        ctx: Context<SubmitSolution>,
+        computation_offset: u64,
+        problem_id: u64,
+        amount: u64,
+        encrypted_solution: [u8; 32],
+        solution_pubkey: [u8; 32],
+        solution_nonce: u128,
+        encrypted_content_hash: [u8; 32],
+        content_hash_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp < problem.deadline,
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            problem.market_kind == MarketKind::Binary,
+            ErrorCode::InvalidMarketKind
+        );
+        // Puzzles are answered through `commit_puzzle_solution` and
+        // verifier-judged problems through `verify_solution`.
+        require!(
+            !matches!(
+                problem.judging_mode,
+                JudgingMode::Puzzle | JudgingMode::Verifier
+            ),
+            ErrorCode::InvalidJudgingMode
+        );
+        require!(
+            matches!(
+                problem.bounty_mode,
+                BountyMode::Standard | BountyMode::Confidential
+            ),
+            ErrorCode::InvalidBountyMode
+        );
+        require!(amount >= 0, ErrorCode::InvalidAmount);
+        check_eligibility(
+            &problem.eligibility,
+            &ctx.accounts.solver.key(),
+            &allowlist_proof,
+            ctx.accounts.eligibility_token.as_deref(),
+            ctx.accounts.eligibility_metadata.as_deref(),
+        )?;
+
+        let index = problem.total_solutions;
+        problem.total_solutions += 1;
+
+        let registry = &mut ctx.accounts.registry;
+        // Each submission builds on the hashes the previous one wrote back,
+        // the first on those the problem was initialized with.
+        require!(!registry.pending, ErrorCode::ComputationPending);
+        registry.pending = true;
+
+        let args = vec![
+            Argument::ArcisPubkey(solution_pubkey),
+            Argument::PlaintextU128(solution_nonce),
+            Argument::EncryptedBool(encrypted_solution),
+            Argument::PlaintextU64(amount),
+            Argument::ArcisPubkey(solution_pubkey),
+            Argument::PlaintextU128(content_hash_nonce),
+            Argument::EncryptedU128(encrypted_content_hash),
+            Argument::PlaintextU64(index),
+            Argument::PlaintextU128(registry.nonce),
+            Argument::Account(registry.key(), 8 + 1, 32 * MAX_TRACKED_SUBMISSIONS as u32),
+        ];
+
+        let solver_balance = ctx.accounts.solver.lamports();
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitSolutionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.registry.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.solution.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+        let fee = solver_balance.saturating_sub(ctx.accounts.solver.lamports());
+        reimburse_mpc_fee(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.solver.to_account_info(),
+            fee,
+        )?;
+
+        let solution = &mut ctx.accounts.solution;
+        solution.bump = ctx.bumps.solution;
+        solution.problem_id = problem_id;
+        solution.index = index;
+        solution.solver = ctx.accounts.solver.key();
+        solution.amount = amount;
+        solution.encrypted_solution = encrypted_solution;
+        solution.solution_pubkey = solution_pubkey;
+        solution.solution_nonce = solution_nonce;
+        solution.timestamp = Clock::get()?.unix_timestamp;
+        solution.paid = false;
+        solution.payout_pending = false;
+        solution.payout_amount = 0;
+        solution.scored = false;
+        solution.valid = false;
+        solution.duplicate = false;
+        solution.bond_claimed = false;
+
+        let timestamp = solution.timestamp;
+        ctx.accounts.solution_index_page.append(
+            ctx.bumps.solution_index_page,
+            problem_id,
+            index,
+            ctx.accounts.solver.key(),
+            timestamp,
+        );
+
+        let solver_profile = &mut ctx.accounts.solver_profile;
+        solver_profile.bump = ctx.bumps.solver_profile;
+        solver_profile.solver = ctx.accounts.solver.key();
+        solver_profile.submissions += 1;
+
+        emit!(SolutionSubmittedEvent {
+            problem_id,
+            solver: ctx.accounts.solver.key(),
+            amount,
+            timestamp: solution.timestamp,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_solution")]
+    pub fn submit_solution_callback(
+        ctx: Context<SubmitSolutionCallback>,
+        output: ComputationOutputs<SubmitSolutionOutput>,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        require!(registry.pending, ErrorCode::NoComputationPending);
+        registry.pending = false;
+
+        // The stake never reached the pools, so the solution is settled with
+        // nothing to pay out rather than left payable from them.
+        let o = match output {
+            ComputationOutputs::Success(SubmitSolutionOutput { field_0 }) => field_0,
+            _ => {
+                let solution = &mut ctx.accounts.solution;
+                solution.paid = true;
+                solution.payout_amount = 0;
+                emit!(ComputationAbortedEvent {
+                    problem_id: solution.problem_id,
+                    circuit: "submit_solution".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        registry.encrypted_hashes = o.field_0.ciphertexts;
+        registry.nonce = o.field_0.nonce;
+
+        let pool_update = o.field_1;
+        let problem = &mut ctx.accounts.problem;
+        problem.yes_pool = problem.yes_pool.saturating_add(pool_update.field_0);
+        problem.no_pool = problem.no_pool.saturating_add(pool_update.field_1);
+
+        emit!(PoolUpdatedEvent {
+            problem_id: problem.problem_id,
+            yes_pool_delta: pool_update.field_0,
+            no_pool_delta: pool_update.field_1,
+            yes_pool: problem.yes_pool,
+            no_pool: problem.no_pool,
+        });
+
+        let solution = &mut ctx.accounts.solution;
+        solution.duplicate = o.field_2;
+
+        if solution.duplicate {
+            emit!(DuplicateSolutionEvent {
+                problem_id: solution.problem_id,
+                solver: solution.solver,
+                index: solution.index,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn close_problem(
+        ctx: Context<CloseProblem>,
+        _problem_id: u64,
+        winner: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.creator_profile.bump = ctx.bumps.creator_profile;
+        ctx.accounts.creator_profile.creator = ctx.accounts.problem.creator;
+
+        close_with_winner(
+            &mut ctx.accounts.problem,
+            ctx.accounts.judging_panel.as_deref(),
+            &mut ctx.accounts.creator_profile,
+            winner,
+        )
+    }
+
+    pub fn update_problem(
+        ctx: Context<UpdateProblem>,
+        _problem_id: u64,
+        update: ProblemUpdate,
+    ) -> Result<()> {
+        apply_problem_update(&mut ctx.accounts.problem, update)
+    }
+
+    pub fn cancel_problem(ctx: Context<CancelProblem>, _problem_id: u64) -> Result<()> {
+        cancel_open_problem(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
+    pub fn pay_winner(
+        ctx: Context<PayWinner>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Closed,
+            ErrorCode::ProblemNotClosed
+        );
+        require!(
+            problem.winner.is_some() || problem.winning_index.is_some(),
+            ErrorCode::NoWinnerSet
+        );
+        // The winning price is already public, so it is paid without a circuit.
+        require!(
+            problem.bounty_mode != BountyMode::SealedBid,
+            ErrorCode::InvalidBountyMode
+        );
+        require!(
+            !problem.in_dispute_window(Clock::get()?.unix_timestamp),
+            ErrorCode::DisputeWindowOpen
+        );
+
+        let solution = &mut ctx.accounts.solution;
+        require!(!solution.paid, ErrorCode::AlreadyPaid);
+        // Every queue is reimbursed from the compute budget, so a payout can
+        // only be in flight once.
+        require!(!solution.payout_pending, ErrorCode::ComputationPending);
+        solution.payout_pending = true;
+
+        let is_winner = problem.is_winner(solution);
+
+        // Solvers who submitted before profiles existed get one on payout.
+        let solver_profile = &mut ctx.accounts.solver_profile;
+        solver_profile.bump = ctx.bumps.solver_profile;
+        solver_profile.solver = ctx.accounts.solver.key();
+
+        // A vested bounty is locked in the winner's schedule rather than paid
+        // out; the callback starts the clock.
+        let vests = is_winner && problem.vesting_duration > 0;
+        require!(
+            ctx.accounts.vesting.is_some() == vests,
+            ErrorCode::InvalidVestingSchedule
+        );
+        if let Some(vesting) = ctx.accounts.vesting.as_mut() {
+            vesting.bump = ctx.bumps.vesting.unwrap_or_default();
+            vesting.problem_id = problem.problem_id;
+            vesting.beneficiary = ctx.accounts.solver.key();
+            vesting.total_amount = problem.escrow_amount;
+            vesting.claimed_amount = 0;
+            vesting.start_time = 0;
+            vesting.cliff = problem.vesting_cliff;
+            vesting.duration = problem.vesting_duration;
+        }
+        let vesting_key = ctx
+            .accounts
+            .vesting
+            .as_ref()
+            .map_or(crate::ID, |vesting| vesting.key());
+
+        // Winners also get a non-transferable badge, minted by the callback
+        // once the payout is confirmed.
+        require!(
+            ctx.accounts.badge_mint.is_some() == is_winner
+                && ctx.accounts.badge_account.is_some() == is_winner,
+            ErrorCode::InvalidBadgeAccounts
+        );
+        if let Some(badge_mint) = ctx.accounts.badge_mint.as_ref() {
+            require!(badge_mint.supply == 0, ErrorCode::BadgeAlreadyMinted);
+            initialize_badge_metadata(
+                problem,
+                badge_mint,
+                &ctx.accounts.badge_authority.to_account_info(),
+                ctx.bumps.badge_authority,
+                &ctx.accounts.solver.to_account_info(),
+                &ctx.accounts.token_program,
+                &ctx.accounts.system_program,
+            )?;
+        }
+        let badge_mint_key = ctx
+            .accounts
+            .badge_mint
+            .as_ref()
+            .map_or(crate::ID, |badge_mint| badge_mint.key());
+        let badge_account_key = ctx
+            .accounts
+            .badge_account
+            .as_ref()
+            .map_or(crate::ID, |badge_account| badge_account.key());
+
+        // Solutions to a season's problems score on its leaderboard.
+        let in_season = problem.season_id.is_some();
+        require!(
+            ctx.accounts.season.is_some() == in_season
+                && ctx.accounts.season_entry.is_some() == in_season,
+            ErrorCode::InvalidSeasonAccounts
+        );
+        if let Some(season_entry) = ctx.accounts.season_entry.as_mut() {
+            season_entry.bump = ctx.bumps.season_entry.unwrap_or_default();
+            season_entry.season_id = problem.season_id.unwrap_or_default();
+            season_entry.solver = ctx.accounts.solver.key();
+        }
+        let season_key = ctx
+            .accounts
+            .season
+            .as_ref()
+            .map_or(crate::ID, |season| season.key());
+        let season_entry_key = ctx
+            .accounts
+            .season_entry
+            .as_ref()
+            .map_or(crate::ID, |season_entry| season_entry.key());
+
+        // Only the winner is paid; every other solution settles at 0.
+        let args = vec![
+            Argument::ArcisPubkey(solution.solution_pubkey),
+            Argument::PlaintextU128(solution.solution_nonce),
+            Argument::EncryptedBool(solution.encrypted_solution),
+            Argument::PlaintextBool(is_winner),
+            Argument::PlaintextU64(solution.amount),
+            Argument::PlaintextU64(problem.yes_pool),
+            Argument::PlaintextU64(problem.no_pool),
+        ];
+
+        let solver_balance = ctx.accounts.solver.lamports();
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![PayWinnerCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.solution.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.solver_profile.key(),
+                    is_writable: true,
+                },
+                // The program id stands in for an absent schedule.
+                CallbackAccount {
+                    pubkey: vesting_key,
+                    is_writable: vests,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.badge_authority.key(),
+                    is_writable: false,
+                },
+                // Likewise for the badge accounts of a losing solution.
+                CallbackAccount {
+                    pubkey: badge_mint_key,
+                    is_writable: is_winner,
+                },
+                CallbackAccount {
+                    pubkey: badge_account_key,
+                    is_writable: is_winner,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.token_program.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: season_key,
+                    is_writable: in_season,
+                },
+                CallbackAccount {
+                    pubkey: season_entry_key,
+                    is_writable: in_season,
+                },
+            ])],
+        )?;
+        let fee = solver_balance.saturating_sub(ctx.accounts.solver.lamports());
+        reimburse_mpc_fee(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.solver.to_account_info(),
+            fee,
+        )?;
+
+        // A bounty escrowed for the winner is public, so it goes out here and
+        // is zeroed before an aborted payout can be queued again.
+        let problem = &mut ctx.accounts.problem;
+        if is_winner && problem.escrowed_bounty > 0 {
+            let bounty = problem.escrowed_bounty;
+            release_escrow(
+                &problem.to_account_info(),
+                &ctx.accounts.solver.to_account_info(),
+                bounty,
+            )?;
+            problem.escrow_amount -= bounty;
+            problem.escrowed_bounty = 0;
+        }
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "pay_winner")]
+    pub fn pay_winner_callback(
+        ctx: Context<PayWinnerCallback>,
+        output: ComputationOutputs<PayWinnerOutput>,
+    ) -> Result<()> {
+        let solution = &mut ctx.accounts.solution;
+        require!(solution.payout_pending, ErrorCode::NoComputationPending);
+        solution.payout_pending = false;
+
+        // An aborted payout leaves the solution unpaid so it can be queued again.
+        let result = match output {
+            ComputationOutputs::Success(PayWinnerOutput { field_0 }) => field_0,
+            _ => {
+                emit!(ComputationAbortedEvent {
+                    problem_id: solution.problem_id,
+                    circuit: "pay_winner".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        let (payout_amount, won) = (result.field_0, result.field_1);
+
+        if let Some(vesting) = ctx.accounts.vesting.as_mut() {
+            vesting.start_time = Clock::get()?.unix_timestamp;
+
+            emit!(VestingStartedEvent {
+                problem_id: vesting.problem_id,
+                beneficiary: vesting.beneficiary,
+                total_amount: vesting.total_amount,
+                start_time: vesting.start_time,
+                cliff: vesting.cliff,
+                duration: vesting.duration,
+            });
+        }
+
+        if let (Some(badge_mint), Some(badge_account)) = (
+            ctx.accounts.badge_mint.as_ref(),
+            ctx.accounts.badge_account.as_ref(),
+        ) {
+            mint_winner_badge(
+                badge_mint,
+                badge_account,
+                &ctx.accounts.badge_authority.to_account_info(),
+                ctx.bumps.badge_authority,
+                &ctx.accounts.token_program,
+                solution,
+            )?;
+        }
+
+        let season = match (
+            ctx.accounts.season.as_mut(),
+            ctx.accounts.season_entry.as_mut(),
+        ) {
+            (Some(season), Some(season_entry)) => Some((&mut **season, &mut **season_entry)),
+            _ => None,
+        };
+        record_solution_payout(
+            solution,
+            &mut ctx.accounts.solver_profile,
+            season,
+            won,
+            payout_amount,
+        );
+
+        Ok(())
+    }
+
+    /// Turns a fresh binary problem into a market over `num_outcomes` outcomes.
+    ///
+    /// The categorical flow keeps its own `*_categorical_*` instructions: each
+    /// Arcium queue and callback struct is pinned to one circuit, and the
+    /// per-outcome stats live in a separate `CategoricalMarket` account rather
+    /// than the problem's yes/no pools.
+    pub fn create_categorical_market(
+        ctx: Context<CreateCategoricalMarket>,
+        computation_offset: u64,
+        problem_id: u64,
+        num_outcomes: u8,
+        quorum_size: u8,
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.market_kind == MarketKind::Binary && problem.total_solutions == 0,
+            ErrorCode::InvalidMarketKind
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Creator,
+            ErrorCode::InvalidJudgingMode
+        );
+        require!(
+            problem.bounty_mode == BountyMode::Standard,
+            ErrorCode::InvalidBountyMode
+        );
+        require!(
+            problem.eligibility == EligibilityRule::Open && problem.vesting_duration == 0,
+            ErrorCode::InvalidMarketKind
+        );
+        require!(
+            num_outcomes >= 2 && num_outcomes as usize <= MAX_OUTCOMES,
+            ErrorCode::InvalidOutcomeCount
+        );
+        require!(quorum_size > 0, ErrorCode::InvalidQuorumSize);
+
+        problem.market_kind = MarketKind::Categorical;
+
+        let args = vec![Argument::PlaintextU128(nonce)];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitializeCategoricalProblemCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.market.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        let market = &mut ctx.accounts.market;
+        market.bump = ctx.bumps.market;
+        market.encrypted_stats = [[0; 32]; CATEGORICAL_STATS_LEN];
+        market.nonce = 0;
+        market.problem_id = problem_id;
+        market.num_outcomes = num_outcomes;
+        market.quorum_size = quorum_size;
+        market.total_votes = 0;
+        market.resolved = false;
+        market.winning_outcome = 0;
+        market.winning_votes = 0;
+        market.winning_pool = 0;
+        market.total_pool = 0;
+        // Nothing is tallied until the encrypted stats are initialized.
+        market.pending = true;
+
+        emit!(CategoricalMarketCreatedEvent {
+            problem_id,
+            num_outcomes,
+            quorum_size,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "initialize_categorical_problem")]
+    pub fn initialize_categorical_problem_callback(
+        ctx: Context<InitializeCategoricalProblemCallback>,
+        output: ComputationOutputs<InitializeCategoricalProblemOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitializeCategoricalProblemOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        ctx.accounts.market.encrypted_stats = o.ciphertexts;
+        ctx.accounts.market.nonce = o.nonce;
+        ctx.accounts.market.pending = false;
+
+        Ok(())
+    }
+
+    /// Categorical counterpart of `submit_solution`. The choice is an encrypted
+    /// outcome index rather than a yes/no flag, so it runs its own circuit and
+    /// accumulates into the `CategoricalMarket` instead of the problem's pools.
+    pub fn submit_categorical_solution(
+        ctx: Context<SubmitCategoricalSolution>,
+        computation_offset: u64,
+        problem_id: u64,
+        amount: u64,
+        encrypted_choice: [u8; 32],
+        solution_pubkey: [u8; 32],
+        solution_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp < problem.deadline,
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            problem.market_kind == MarketKind::Categorical,
+            ErrorCode::InvalidMarketKind
+        );
+
+        let market = &mut ctx.accounts.market;
+        require!(!market.pending, ErrorCode::ComputationPending);
+        market.pending = true;
+
+        let index = problem.total_solutions;
+        problem.total_solutions += 1;
+
+        let args = vec![
+            Argument::ArcisPubkey(solution_pubkey),
+            Argument::PlaintextU128(solution_nonce),
+            Argument::EncryptedU8(encrypted_choice),
+            Argument::PlaintextU64(amount),
+            Argument::PlaintextU8(market.num_outcomes),
+            Argument::PlaintextU128(market.nonce),
+            Argument::Account(market.key(), 8 + 1, 32 * CATEGORICAL_STATS_LEN as u32),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitCategoricalSolutionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.market.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.solution.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        let solution = &mut ctx.accounts.solution;
+        solution.bump = ctx.bumps.solution;
+        solution.problem_id = problem_id;
+        solution.index = index;
+        solution.solver = ctx.accounts.solver.key();
+        solution.amount = amount;
+        solution.encrypted_solution = encrypted_choice;
+        solution.solution_pubkey = solution_pubkey;
+        solution.solution_nonce = solution_nonce;
+        solution.timestamp = Clock::get()?.unix_timestamp;
+        solution.paid = false;
+        solution.payout_pending = false;
+        solution.payout_amount = 0;
+        solution.scored = false;
+        solution.valid = false;
+        solution.duplicate = false;
+        solution.bond_claimed = false;
+
+        let timestamp = solution.timestamp;
+        ctx.accounts.solution_index_page.append(
+            ctx.bumps.solution_index_page,
+            problem_id,
+            index,
+            ctx.accounts.solver.key(),
+            timestamp,
+        );
+
+        emit!(SolutionSubmittedEvent {
+            problem_id,
+            solver: ctx.accounts.solver.key(),
+            amount,
+            timestamp: solution.timestamp,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_categorical_solution")]
+    pub fn submit_categorical_solution_callback(
+        ctx: Context<SubmitCategoricalSolutionCallback>,
+        output: ComputationOutputs<SubmitCategoricalSolutionOutput>,
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require!(market.pending, ErrorCode::NoComputationPending);
+        market.pending = false;
+
+        // As in `submit_solution_callback`, a stake that never reached the
+        // stats leaves nothing to pay out.
+        let o = match output {
+            ComputationOutputs::Success(SubmitCategoricalSolutionOutput { field_0 }) => field_0,
+            _ => {
+                let solution = &mut ctx.accounts.solution;
+                solution.paid = true;
+                solution.payout_amount = 0;
+                emit!(ComputationAbortedEvent {
+                    problem_id: market.problem_id,
+                    circuit: "submit_categorical_solution".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        market.encrypted_stats = o.ciphertexts;
+        market.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    /// Seated jurors vote for an outcome index. The binary jury tally lives on
+    /// the panel, but categorical votes feed the same encrypted stats as the
+    /// solutions so `resolve_categorical_market` can pick the winning pool.
+    pub fn submit_categorical_vote(
+        ctx: Context<SubmitCategoricalVote>,
+        computation_offset: u64,
+        problem_id: u64,
+        encrypted_vote: [u8; 32],
+        vote_pubkey: [u8; 32],
+        vote_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= problem.deadline, ErrorCode::DeadlineNotReached);
+
+        let panel = &mut ctx.accounts.panel;
+        require!(panel.seated, ErrorCode::JuryNotSeated);
+        require!(now < panel.vote_deadline, ErrorCode::DeadlinePassed);
+        let seat = panel
+            .seat(&ctx.accounts.voter.key())
+            .ok_or(ErrorCode::NotAJuror)?;
+        require!(!panel.has_voted(seat), ErrorCode::AlreadyVoted);
+
+        let market = &mut ctx.accounts.market;
+        require!(!market.resolved, ErrorCode::MarketAlreadyResolved);
+        require!(!market.pending, ErrorCode::ComputationPending);
+
+        panel.voted |= 1 << seat;
+        panel.votes_cast += 1;
+        market.total_votes = market.total_votes.saturating_add(1);
+        market.pending = true;
+
+        let args = vec![
+            Argument::ArcisPubkey(vote_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+            Argument::EncryptedU8(encrypted_vote),
+            Argument::PlaintextU8(market.num_outcomes),
+            Argument::PlaintextU128(market.nonce),
+            Argument::Account(market.key(), 8 + 1, 32 * CATEGORICAL_STATS_LEN as u32),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitCategoricalVoteCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.market.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.panel.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.vote_record.key(),
+                    is_writable: false,
+                },
+            ])],
+        )?;
+
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.bump = ctx.bumps.vote_record;
+        vote_record.problem_id = problem_id;
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.timestamp = Clock::get()?.unix_timestamp;
+
+        emit!(CategoricalVoteEvent {
+            problem_id,
+            voter: ctx.accounts.voter.key(),
+            total_votes: ctx.accounts.market.total_votes,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_categorical_vote")]
+    pub fn submit_categorical_vote_callback(
+        ctx: Context<SubmitCategoricalVoteCallback>,
+        output: ComputationOutputs<SubmitCategoricalVoteOutput>,
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require!(market.pending, ErrorCode::NoComputationPending);
+        market.pending = false;
+
+        // An aborted vote is taken back so the juror can cast it again.
+        let o = match output {
+            ComputationOutputs::Success(SubmitCategoricalVoteOutput { field_0 }) => field_0,
+            _ => {
+                let panel = &mut ctx.accounts.panel;
+                if let Some(seat) = panel.seat(&ctx.accounts.vote_record.voter) {
+                    panel.voted &= !(1 << seat);
+                    panel.votes_cast -= 1;
+                }
+                market.total_votes -= 1;
+                emit!(ComputationAbortedEvent {
+                    problem_id: market.problem_id,
+                    circuit: "submit_categorical_vote".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        market.encrypted_stats = o.ciphertexts;
+        market.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    /// Reveals the outcome with the most juror votes and its pool, replacing
+    /// `close_problem` for categorical markets.
+    pub fn resolve_categorical_market(
+        ctx: Context<ResolveCategoricalMarket>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= problem.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+
+        let market = &ctx.accounts.market;
+        require!(!market.resolved, ErrorCode::MarketAlreadyResolved);
+        require!(
+            market.total_votes >= market.quorum_size,
+            ErrorCode::QuorumNotReached
+        );
+        // The last vote has to be in the stats before they are revealed.
+        require!(!market.pending, ErrorCode::ComputationPending);
+
+        let args = vec![
+            Argument::PlaintextU128(market.nonce),
+            Argument::Account(market.key(), 8 + 1, 32 * CATEGORICAL_STATS_LEN as u32),
+            Argument::PlaintextU8(market.num_outcomes),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ResolveCategoricalMarketCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.market.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "resolve_categorical_market")]
+    pub fn resolve_categorical_market_callback(
+        ctx: Context<ResolveCategoricalMarketCallback>,
+        output: ComputationOutputs<ResolveCategoricalMarketOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(ResolveCategoricalMarketOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let market = &mut ctx.accounts.market;
+        market.resolved = true;
+        market.winning_outcome = result.field_0;
+        market.winning_votes = result.field_1;
+        market.winning_pool = result.field_2;
+        market.total_pool = result.field_3;
+
+        ctx.accounts.problem.state = ProblemState::Closed;
+        ctx.accounts.problem.judged_at = Clock::get()?.unix_timestamp;
+
+        emit!(CategoricalMarketResolvedEvent {
+            problem_id: market.problem_id,
+            winning_outcome: result.field_0,
+            winning_votes: result.field_1,
+            winning_pool: result.field_2,
+            total_pool: result.field_3,
+        });
+
+        Ok(())
+    }
+
+    /// Pays a solver who picked the winning outcome a share of the total pool
+    /// pro rata to their stake.
+    pub fn pay_categorical_winner(
+        ctx: Context<PayCategoricalWinner>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let market = &ctx.accounts.market;
+        require!(market.resolved, ErrorCode::MarketNotResolved);
+
+        let solution = &mut ctx.accounts.solution;
+        require!(!solution.paid, ErrorCode::AlreadyPaid);
+        require!(!solution.payout_pending, ErrorCode::ComputationPending);
+        solution.payout_pending = true;
+
+        let solver_profile = &mut ctx.accounts.solver_profile;
+        solver_profile.bump = ctx.bumps.solver_profile;
+        solver_profile.solver = ctx.accounts.solver.key();
+
+        let args = vec![
+            Argument::ArcisPubkey(solution.solution_pubkey),
+            Argument::PlaintextU128(solution.solution_nonce),
+            Argument::EncryptedU8(solution.encrypted_solution),
+            Argument::PlaintextU8(market.winning_outcome),
+            Argument::PlaintextU64(solution.amount),
+            Argument::PlaintextU64(market.winning_pool),
+            Argument::PlaintextU64(market.total_pool),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![PayCategoricalWinnerCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.solution.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.solver_profile.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "pay_categorical_winner")]
+    pub fn pay_categorical_winner_callback(
+        ctx: Context<PayCategoricalWinnerCallback>,
+        output: ComputationOutputs<PayCategoricalWinnerOutput>,
+    ) -> Result<()> {
+        let solution = &mut ctx.accounts.solution;
+        require!(solution.payout_pending, ErrorCode::NoComputationPending);
+        solution.payout_pending = false;
+
+        // An aborted payout leaves the solution unpaid so it can be queued again.
+        let result = match output {
+            ComputationOutputs::Success(PayCategoricalWinnerOutput { field_0 }) => field_0,
+            _ => {
+                emit!(ComputationAbortedEvent {
+                    problem_id: solution.problem_id,
+                    circuit: "pay_categorical_winner".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        record_solution_payout(
+            solution,
+            &mut ctx.accounts.solver_profile,
+            None,
+            result.field_1,
+            result.field_0,
+        );
+
+        Ok(())
+    }
+
+    pub fn enable_scoring(
+        ctx: Context<EnableScoring>,
+        computation_offset: u64,
+        problem_id: u64,
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.market_kind == MarketKind::Binary,
+            ErrorCode::InvalidMarketKind
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Creator,
+            ErrorCode::InvalidJudgingMode
+        );
+        require!(
+            problem.bounty_mode == BountyMode::Standard,
+            ErrorCode::InvalidBountyMode
+        );
+
+        problem.judging_mode = JudgingMode::Scored;
+
+        let args = vec![Argument::PlaintextU128(nonce)];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitializeScoringCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.score_board.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        let score_board = &mut ctx.accounts.score_board;
+        score_board.bump = ctx.bumps.score_board;
+        score_board.encrypted_board = [[0; 32]; SCORE_BOARD_LEN];
+        score_board.nonce = 0;
+        score_board.problem_id = problem_id;
+        score_board.scored_count = 0;
+        // Nothing is scored until the encrypted board is initialized.
+        score_board.pending = true;
+
+        emit!(ScoringEnabledEvent { problem_id });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "initialize_scoring")]
+    pub fn initialize_scoring_callback(
+        ctx: Context<InitializeScoringCallback>,
+        output: ComputationOutputs<InitializeScoringOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitializeScoringOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        ctx.accounts.score_board.encrypted_board = o.ciphertexts;
+        ctx.accounts.score_board.nonce = o.nonce;
+        ctx.accounts.score_board.pending = false;
+
+        Ok(())
+    }
+
+    pub fn score_solution(
+        ctx: Context<ScoreSolution>,
+        computation_offset: u64,
+        problem_id: u64,
+        encrypted_score: [u8; 32],
+        score_pubkey: [u8; 32],
+        score_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Scored,
+            ErrorCode::InvalidJudgingMode
+        );
+
+        let solution = &mut ctx.accounts.solution;
+        require!(!solution.scored, ErrorCode::AlreadyScored);
+
+        let score_board = &mut ctx.accounts.score_board;
+        // Each score builds on the board the previous one wrote back.
+        require!(!score_board.pending, ErrorCode::ComputationPending);
+
+        solution.scored = true;
+        score_board.scored_count += 1;
+        score_board.pending = true;
+
+        let args = vec![
+            Argument::ArcisPubkey(score_pubkey),
+            Argument::PlaintextU128(score_nonce),
+            Argument::EncryptedU64(encrypted_score),
+            Argument::PlaintextU64(solution.index),
+            Argument::PlaintextU128(score_board.nonce),
+            Argument::Account(score_board.key(), 8 + 1, 32 * SCORE_BOARD_LEN as u32),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ScoreSolutionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.score_board.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.solution.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        emit!(SolutionScoredEvent {
+            problem_id,
+            solver: ctx.accounts.solution.solver,
+            scored_count: ctx.accounts.score_board.scored_count,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "score_solution")]
+    pub fn score_solution_callback(
+        ctx: Context<ScoreSolutionCallback>,
+        output: ComputationOutputs<ScoreSolutionOutput>,
+    ) -> Result<()> {
+        let score_board = &mut ctx.accounts.score_board;
+        require!(score_board.pending, ErrorCode::NoComputationPending);
+        score_board.pending = false;
+
+        // An aborted score is taken back so the solution can be scored again.
+        let o = match output {
+            ComputationOutputs::Success(ScoreSolutionOutput { field_0 }) => field_0,
+            _ => {
+                ctx.accounts.solution.scored = false;
+                score_board.scored_count -= 1;
+                emit!(ComputationAbortedEvent {
+                    problem_id: score_board.problem_id,
+                    circuit: "score_solution".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        score_board.encrypted_board = o.ciphertexts;
+        score_board.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    /// Reveals the best scored solution. The creator names it by passing its
+    /// `Solution`, and the callback only closes the problem if the revealed
+    /// index matches, so `winner` is set alongside `winning_index`.
+    pub fn close_scored_problem(
+        ctx: Context<CloseScoredProblem>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= problem.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Scored,
+            ErrorCode::InvalidJudgingMode
+        );
+
+        let score_board = &ctx.accounts.score_board;
+        require!(score_board.scored_count > 0, ErrorCode::NoScoredSolutions);
+        require!(!score_board.pending, ErrorCode::ComputationPending);
+
+        let args = vec![
+            Argument::PlaintextU128(score_board.nonce),
+            Argument::Account(score_board.key(), 8 + 1, 32 * SCORE_BOARD_LEN as u32),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealBestSolutionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.solution.key(),
+                    is_writable: false,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_best_solution")]
+    pub fn reveal_best_solution_callback(
+        ctx: Context<RevealBestSolutionCallback>,
+        output: ComputationOutputs<RevealBestSolutionOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(RevealBestSolutionOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        require!(result.field_1, ErrorCode::NoScoredSolutions);
+
+        let solution = &ctx.accounts.solution;
+        require!(solution.index == result.field_0, ErrorCode::NotBestSolution);
+
+        let problem = &mut ctx.accounts.problem;
+        problem.winner = Some(solution.solver);
+        problem.winning_index = Some(result.field_0);
+        problem.state = ProblemState::Closed;
+        problem.judged_at = Clock::get()?.unix_timestamp;
+
+        emit!(ScoredProblemClosedEvent {
+            problem_id: problem.problem_id,
+            winner: solution.solver,
+            winning_index: result.field_0,
+        });
+
+        Ok(())
+    }
+
+    pub fn open_sealed_bidding(
+        ctx: Context<OpenSealedBidding>,
+        computation_offset: u64,
+        problem_id: u64,
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.bounty_mode == BountyMode::SealedBid,
+            ErrorCode::InvalidBountyMode
+        );
+
+        let args = vec![Argument::PlaintextU128(nonce)];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitializeBidBoardCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.bid_board.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        let bid_board = &mut ctx.accounts.bid_board;
+        bid_board.bump = ctx.bumps.bid_board;
+        bid_board.encrypted_board = [[0; 32]; BID_BOARD_LEN];
+        bid_board.nonce = 0;
+        bid_board.problem_id = problem_id;
+        bid_board.bid_count = 0;
+        // No bids land until the encrypted board is initialized.
+        bid_board.pending = true;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "initialize_bid_board")]
+    pub fn initialize_bid_board_callback(
+        ctx: Context<InitializeBidBoardCallback>,
+        output: ComputationOutputs<InitializeBidBoardOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitializeBidBoardOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        ctx.accounts.bid_board.encrypted_board = o.ciphertexts;
+        ctx.accounts.bid_board.nonce = o.nonce;
+        ctx.accounts.bid_board.pending = false;
+
+        Ok(())
+    }
+
+    pub fn submit_bid(
+        ctx: Context<SubmitBid>,
+        computation_offset: u64,
+        problem_id: u64,
+        encrypted_bid: [u8; 32],
+        bid_pubkey: [u8; 32],
+        bid_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp < problem.deadline,
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            problem.bounty_mode == BountyMode::SealedBid,
+            ErrorCode::InvalidBountyMode
+        );
+
+        let bid_board = &mut ctx.accounts.bid_board;
+        // Each bid builds on the board the previous one wrote back.
+        require!(!bid_board.pending, ErrorCode::ComputationPending);
+        bid_board.bid_count += 1;
+        bid_board.pending = true;
+
+        let index = problem.total_solutions;
+        problem.total_solutions += 1;
+
+        let args = vec![
+            Argument::ArcisPubkey(bid_pubkey),
+            Argument::PlaintextU128(bid_nonce),
+            Argument::EncryptedU64(encrypted_bid),
+            Argument::PlaintextU64(index),
+            Argument::PlaintextU64(problem.bounty_amount),
+            Argument::PlaintextU128(bid_board.nonce),
+            Argument::Account(bid_board.key(), 8 + 1, 32 * BID_BOARD_LEN as u32),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitBidCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.bid_board.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        let solution = &mut ctx.accounts.solution;
+        solution.bump = ctx.bumps.solution;
+        solution.problem_id = problem_id;
+        solution.index = index;
+        solution.solver = ctx.accounts.solver.key();
+        solution.amount = 0;
+        solution.encrypted_solution = encrypted_bid;
+        solution.solution_pubkey = bid_pubkey;
+        solution.solution_nonce = bid_nonce;
+        solution.timestamp = Clock::get()?.unix_timestamp;
+        solution.paid = false;
+        solution.payout_pending = false;
+        solution.payout_amount = 0;
+        solution.scored = false;
+        solution.valid = false;
+        solution.duplicate = false;
+        solution.bond_claimed = false;
+
+        let timestamp = solution.timestamp;
+        ctx.accounts.solution_index_page.append(
+            ctx.bumps.solution_index_page,
+            problem_id,
+            index,
+            ctx.accounts.solver.key(),
+            timestamp,
+        );
+
+        emit!(SolutionSubmittedEvent {
+            problem_id,
+            solver: ctx.accounts.solver.key(),
+            amount: 0,
+            timestamp: solution.timestamp,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_bid")]
+    pub fn submit_bid_callback(
+        ctx: Context<SubmitBidCallback>,
+        output: ComputationOutputs<SubmitBidOutput>,
+    ) -> Result<()> {
+        let bid_board = &mut ctx.accounts.bid_board;
+        require!(bid_board.pending, ErrorCode::NoComputationPending);
+        bid_board.pending = false;
+
+        // A bid that never reached the board can't win it.
+        let o = match output {
+            ComputationOutputs::Success(SubmitBidOutput { field_0 }) => field_0,
+            _ => {
+                bid_board.bid_count -= 1;
+                emit!(ComputationAbortedEvent {
+                    problem_id: bid_board.problem_id,
+                    circuit: "submit_bid".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        bid_board.encrypted_board = o.ciphertexts;
+        bid_board.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    pub fn close_sealed_bid(
+        ctx: Context<CloseSealedBid>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= problem.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        require!(
+            problem.bounty_mode == BountyMode::SealedBid,
+            ErrorCode::InvalidBountyMode
+        );
+
+        let bid_board = &ctx.accounts.bid_board;
+        // A bid queued before the deadline has to be on the board before it
+        // is revealed; bids are serialized, so at most one is in flight.
+        require!(!bid_board.pending, ErrorCode::ComputationPending);
+
+        let args = vec![
+            Argument::PlaintextU128(bid_board.nonce),
+            Argument::Account(bid_board.key(), 8 + 1, 32 * BID_BOARD_LEN as u32),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealLowestBidCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.creator.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_lowest_bid")]
+    pub fn reveal_lowest_bid_callback(
+        ctx: Context<RevealLowestBidCallback>,
+        output: ComputationOutputs<RevealLowestBidOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(RevealLowestBidOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let problem = &mut ctx.accounts.problem;
+        problem.judged_at = Clock::get()?.unix_timestamp;
+
+        emit!(SealedBidClosedEvent {
+            problem_id: problem.problem_id,
+            has_winner: result.field_2,
+            winning_index: result.field_1,
+            winning_bid: result.field_0,
+        });
+
+        if result.field_2 {
+            problem.winning_index = Some(result.field_1);
+            problem.winning_bid = result.field_0;
+            problem.state = ProblemState::Closed;
+            return Ok(());
+        }
+
+        // Nobody bid under the ceiling, so the whole bounty goes back.
+        let refund = problem.escrow_amount;
+        release_escrow(
+            &problem.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            refund,
+        )?;
+
+        let problem = &mut ctx.accounts.problem;
+        problem.escrow_amount = 0;
+        problem.state = ProblemState::Settled;
+
+        emit!(BountyRefundedEvent {
+            problem_id: problem.problem_id,
+            creator: problem.creator,
+            amount: refund,
+        });
+
+        Ok(())
+    }
+
+    /// Pays the revealed winning price to the lowest bidder and refunds the
+    /// rest of the bounty to the creator. The payout itself is settled like
+    /// any other, through `record_solution_payout` and `mint_winner_badge`.
+    pub fn pay_sealed_bid_winner(ctx: Context<PaySealedBidWinner>, _problem_id: u64) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Closed,
+            ErrorCode::ProblemNotClosed
+        );
+        require!(
+            problem.bounty_mode == BountyMode::SealedBid,
+            ErrorCode::InvalidBountyMode
+        );
+        let winning_index = problem.winning_index.ok_or(ErrorCode::NoWinnerSet)?;
+
+        let solution = &ctx.accounts.solution;
+        require!(solution.index == winning_index, ErrorCode::InvalidSolution);
+        require!(!solution.paid, ErrorCode::AlreadyPaid);
+
+        require!(
+            ctx.accounts.season.is_some() == problem.season_id.is_some()
+                && ctx.accounts.season_entry.is_some() == problem.season_id.is_some(),
+            ErrorCode::InvalidSeasonAccounts
+        );
+
+        let price = problem.winning_bid;
+        let refund = problem.escrow_amount.saturating_sub(price);
+        let problem_info = ctx.accounts.problem.to_account_info();
+        release_escrow(&problem_info, &ctx.accounts.solver.to_account_info(), price)?;
+        release_escrow(
+            &problem_info,
+            &ctx.accounts.creator.to_account_info(),
+            refund,
+        )?;
+
+        let problem = &mut ctx.accounts.problem;
+        problem.escrow_amount = 0;
+        problem.state = ProblemState::Settled;
+
+        emit!(BountyRefundedEvent {
+            problem_id: problem.problem_id,
+            creator: problem.creator,
+            amount: refund,
+        });
+
+        initialize_badge_metadata(
+            &ctx.accounts.problem,
+            &ctx.accounts.badge_mint,
+            &ctx.accounts.badge_authority.to_account_info(),
+            ctx.bumps.badge_authority,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+        )?;
+        mint_winner_badge(
+            &ctx.accounts.badge_mint,
+            &ctx.accounts.badge_account,
+            &ctx.accounts.badge_authority.to_account_info(),
+            ctx.bumps.badge_authority,
+            &ctx.accounts.token_program,
+            &ctx.accounts.solution,
+        )?;
+
+        let solver_profile = &mut ctx.accounts.solver_profile;
+        solver_profile.bump = ctx.bumps.solver_profile;
+        solver_profile.solver = ctx.accounts.solver.key();
+
+        if let Some(season_entry) = ctx.accounts.season_entry.as_mut() {
+            season_entry.bump = ctx.bumps.season_entry.unwrap_or_default();
+            season_entry.season_id = ctx.accounts.problem.season_id.unwrap_or_default();
+            season_entry.solver = ctx.accounts.solver.key();
+        }
+        let season = match (
+            ctx.accounts.season.as_mut(),
+            ctx.accounts.season_entry.as_mut(),
+        ) {
+            (Some(season), Some(season_entry)) => Some((&mut **season, &mut **season_entry)),
+            _ => None,
+        };
+        record_solution_payout(
+            &mut ctx.accounts.solution,
+            solver_profile,
+            season,
+            true,
+            price,
+        );
+
+        Ok(())
+    }
+
+    pub fn enable_raffle(ctx: Context<EnableRaffle>, problem_id: u64) -> Result<()> {
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.market_kind == MarketKind::Binary,
+            ErrorCode::InvalidMarketKind
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Creator,
+            ErrorCode::InvalidJudgingMode
+        );
+        require!(
+            problem.bounty_mode == BountyMode::Standard,
+            ErrorCode::InvalidBountyMode
+        );
+
+        problem.judging_mode = JudgingMode::Raffle;
+
+        let raffle = &mut ctx.accounts.raffle;
+        raffle.bump = ctx.bumps.raffle;
+        raffle.problem_id = problem_id;
+        raffle.entrants = Vec::new();
+
+        Ok(())
+    }
+
+    pub fn mark_solution_valid(ctx: Context<MarkSolutionValid>, problem_id: u64) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Raffle,
+            ErrorCode::InvalidJudgingMode
+        );
+
+        let solution = &mut ctx.accounts.solution;
+        require!(!solution.valid, ErrorCode::AlreadyMarkedValid);
+
+        let raffle = &mut ctx.accounts.raffle;
+        require!(
+            raffle.entrants.len() < MAX_RAFFLE_ENTRANTS,
+            ErrorCode::RaffleFull
+        );
+
+        solution.valid = true;
+        raffle.entrants.push(solution.solver);
+
+        emit!(SolutionMarkedValidEvent {
+            problem_id,
+            solver: solution.solver,
+            entrant_count: raffle.entrants.len() as u64,
+        });
+
+        Ok(())
+    }
+
+    pub fn draw_winner(
+        ctx: Context<DrawWinner>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= problem.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Raffle,
+            ErrorCode::InvalidJudgingMode
+        );
+
+        let raffle = &ctx.accounts.raffle;
+        require!(!raffle.entrants.is_empty(), ErrorCode::NoRaffleEntrants);
+
+        let args = vec![Argument::PlaintextU64(raffle.entrants.len() as u64)];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![DrawRaffleWinnerCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.raffle.key(),
+                    is_writable: false,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "draw_raffle_winner")]
+    pub fn draw_raffle_winner_callback(
+        ctx: Context<DrawRaffleWinnerCallback>,
+        output: ComputationOutputs<DrawRaffleWinnerOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(DrawRaffleWinnerOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        // A failed draw leaves the problem open so `draw_winner` can be retried.
+        require!(result.field_1, ErrorCode::AbortedComputation);
+
+        let problem = &mut ctx.accounts.problem;
+        // Only the first of several concurrently queued draws may settle the problem.
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+
+        let winner = *ctx
+            .accounts
+            .raffle
+            .entrants
+            .get(result.field_0 as usize)
+            .ok_or(ErrorCode::InvalidSolution)?;
+
+        problem.winner = Some(winner);
+        problem.state = ProblemState::Closed;
+        problem.judged_at = Clock::get()?.unix_timestamp;
+
+        emit!(WinnerDrawnEvent {
+            problem_id: problem.problem_id,
+            winner,
+            winner_index: result.field_0,
+            entrant_count: ctx.accounts.raffle.entrants.len() as u64,
+        });
+
+        emit!(ProblemClosedEvent {
+            problem_id: problem.problem_id,
+            winner,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_confidential_problem(
+        ctx: Context<CreateConfidentialProblem>,
+        computation_offset: u64,
+        problem_id: u64,
+        title: String,
+        description: String,
+        deadline: i64,
+        bounty_cap: u64,
+        encrypted_bounty: [u8; 32],
+        bounty_pubkey: [u8; 32],
+        bounty_nonce: u128,
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        require!(
+            deadline > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidDeadline
+        );
+        require!(
+            !title.is_empty() && title.len() <= 200,
+            ErrorCode::InvalidTitle
+        );
+        require!(
+            description.len() <= MAX_DESCRIPTION_LEN,
+            ErrorCode::DescriptionTooLong
+        );
+        require!(bounty_cap > 0, ErrorCode::InvalidAmount);
+
+        // Only the upper bound is public; the circuit clamps the real bounty to it.
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.problem.to_account_info(),
+                },
+            ),
+            bounty_cap,
+        )?;
+
+        let args = vec![
+            Argument::ArcisPubkey(bounty_pubkey),
+            Argument::PlaintextU128(bounty_nonce),
+            Argument::EncryptedU64(encrypted_bounty),
+            Argument::PlaintextU64(bounty_cap),
+            Argument::PlaintextU128(nonce),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitializeConfidentialBountyCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.registry.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        let problem = &mut ctx.accounts.problem;
+        init_problem(
+            problem,
+            ctx.bumps.problem,
+            problem_id,
+            ctx.accounts.creator.key(),
+            title.clone(),
+            description,
+            deadline,
+        );
+        problem.bounty_mode = BountyMode::Confidential;
+        problem.escrow_amount = bounty_cap;
+        // With no winner picked by the end of the window, the cap goes back
+        // through `refund_confidential_bounty`.
+        problem.review_window = MIN_REVIEW_WINDOW;
+
+        ctx.accounts.registry.open(ctx.bumps.registry, problem_id);
+
+        let creator_profile = &mut ctx.accounts.creator_profile;
+        creator_profile.bump = ctx.bumps.creator_profile;
+        creator_profile.creator = ctx.accounts.creator.key();
+        creator_profile.bounties_posted += 1;
+
+        emit!(ProblemPostedEvent {
+            problem_id,
+            creator: ctx.accounts.creator.key(),
+            title,
+            deadline,
+            bounty_amount: 0,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "initialize_confidential_bounty")]
+    pub fn initialize_confidential_bounty_callback(
+        ctx: Context<InitializeConfidentialBountyCallback>,
+        output: ComputationOutputs<InitializeConfidentialBountyOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitializeConfidentialBountyOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        ctx.accounts.problem.encrypted_data = o.field_0.ciphertexts;
+        ctx.accounts.problem.nonce = o.field_0.nonce;
+
+        let registry = &mut ctx.accounts.registry;
+        registry.encrypted_hashes = o.field_1.ciphertexts;
+        registry.nonce = o.field_1.nonce;
+        registry.pending = false;
+
+        Ok(())
+    }
+
+    pub fn pay_confidential_bounty(
+        ctx: Context<PayConfidentialBounty>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Closed,
+            ErrorCode::ProblemNotClosed
+        );
+        require!(
+            problem.bounty_mode == BountyMode::Confidential,
+            ErrorCode::InvalidBountyMode
+        );
+        require!(problem.winner.is_some(), ErrorCode::NoWinnerSet);
+        require!(problem.escrow_amount > 0, ErrorCode::BountyAlreadyReleased);
+
+        let args = vec![
+            Argument::PlaintextU128(problem.nonce),
+            Argument::EncryptedU64(problem.encrypted_data[0]),
+            Argument::EncryptedBool(problem.encrypted_data[1]),
+            Argument::PlaintextU64(problem.escrow_amount),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![PayConfidentialBountyCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.winner.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.creator.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "pay_confidential_bounty")]
+    pub fn pay_confidential_bounty_callback(
+        ctx: Context<PayConfidentialBountyCallback>,
+        output: ComputationOutputs<PayConfidentialBountyOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(PayConfidentialBountyOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        // Only the first of several concurrently queued releases may move the escrow.
+        require!(
+            ctx.accounts.problem.escrow_amount > 0,
+            ErrorCode::BountyAlreadyReleased
+        );
+
+        let problem_info = ctx.accounts.problem.to_account_info();
+        release_escrow(
+            &problem_info,
+            &ctx.accounts.winner.to_account_info(),
+            result.field_0,
+        )?;
+        release_escrow(
+            &problem_info,
+            &ctx.accounts.creator.to_account_info(),
+            result.field_1,
+        )?;
+
+        let problem = &mut ctx.accounts.problem;
+        problem.bounty_amount = result.field_0;
+        problem.total_bounty = result.field_0;
+        problem.escrow_amount = 0;
+
+        emit!(ConfidentialBountyPaidEvent {
+            problem_id: problem.problem_id,
+            winner: ctx.accounts.winner.key(),
+            bounty_amount: result.field_0,
+            capped: result.field_2,
+        });
+
+        emit!(BountyRefundedEvent {
+            problem_id: problem.problem_id,
+            creator: problem.creator,
+            amount: result.field_1,
+        });
+
+        Ok(())
+    }
+
+    pub fn top_up_compute_budget(
+        ctx: Context<TopUpComputeBudget>,
+        _problem_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            ctx.accounts.problem.state != ProblemState::Settled,
+            ErrorCode::ProblemNotActive
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.problem.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let problem = &mut ctx.accounts.problem;
+        problem.compute_budget = problem
+            .compute_budget
+            .checked_add(amount)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        emit!(ComputeBudgetUpdatedEvent {
+            problem_id: problem.problem_id,
+            remaining_budget: problem.compute_budget,
+            mpc_fees: problem.mpc_fees,
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_compute_budget(
+        ctx: Context<WithdrawComputeBudget>,
+        _problem_id: u64,
+    ) -> Result<()> {
+        withdraw_unused_budget(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
+    pub fn register_juror(ctx: Context<RegisterJuror>, stake: u64) -> Result<()> {
+        require!(stake >= MIN_JUROR_STAKE, ErrorCode::InsufficientStake);
+
+        let pool = &mut ctx.accounts.juror_pool;
+        require!(
+            pool.jurors.len() < MAX_POOL_JURORS,
+            ErrorCode::JurorPoolFull
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.juror.to_account_info(),
+                },
+            ),
+            stake,
+        )?;
+
+        pool.bump = ctx.bumps.juror_pool;
+        pool.jurors.push(ctx.accounts.authority.key());
+
+        let juror = &mut ctx.accounts.juror;
+        juror.bump = ctx.bumps.juror;
+        juror.authority = ctx.accounts.authority.key();
+        juror.stake = stake;
+        juror.active_panels = 0;
+        juror.rewards_earned = 0;
+        juror.amount_slashed = 0;
+
+        emit!(JurorRegisteredEvent {
+            juror: juror.authority,
+            stake,
+        });
+
+        Ok(())
+    }
+
+    pub fn leave_juror_pool(ctx: Context<LeaveJurorPool>) -> Result<()> {
+        require!(ctx.accounts.juror.active_panels == 0, ErrorCode::JurorBusy);
+
+        let authority = ctx.accounts.authority.key();
+        ctx.accounts
+            .juror_pool
+            .jurors
+            .retain(|juror| *juror != authority);
+
+        emit!(JurorLeftEvent {
+            juror: authority,
+            stake: ctx.accounts.juror.stake,
+        });
+
+        Ok(())
+    }
+
+    pub fn assign_jurors(
+        ctx: Context<AssignJurors>,
+        problem_id: u64,
+        panel_size: u8,
+        vote_deadline: i64,
+        juror_fee: u64,
+    ) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        // A dispute's panel is opened by `dispute_winner` on fixed terms.
+        require!(
+            problem.state != ProblemState::Disputed,
+            ErrorCode::DisputePanelFixed
+        );
+        require!(
+            panel_size > 0 && panel_size as usize <= MAX_PANEL_SIZE,
+            ErrorCode::InvalidPanelSize
+        );
+        require!(
+            vote_deadline > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidDeadline
+        );
+
+        // Nobody with a stake in the outcome sits on the panel.
+        let mut excluded = vec![problem.creator];
+        excluded.extend(problem.winner);
+        require!(
+            ctx.accounts.juror_pool.candidates(&excluded) >= panel_size as usize,
+            ErrorCode::NotEnoughJurors
+        );
+
+        if juror_fee > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: ctx.accounts.panel.to_account_info(),
+                    },
+                ),
+                juror_fee,
+            )?;
+        }
+
+        // The jurors themselves are drawn by `draw_jury` and locked by `seat_jurors`.
+        let panel = &mut ctx.accounts.panel;
+        panel.bump = ctx.bumps.panel;
+        panel.index = ctx.accounts.problem.jury_count;
+        panel.open(
+            problem_id,
+            ctx.accounts.authority.key(),
+            excluded,
+            panel_size,
+            vote_deadline,
+            juror_fee,
+        );
+        ctx.accounts.problem.jury_count += 1;
+
+        Ok(())
+    }
+
+    /// Queues the draw of a requested panel. The seed comes out of the MPC
+    /// cluster, so neither the creator nor whoever sends this can steer who
+    /// is drawn. Anyone may send it, and again if the draw is aborted.
+    pub fn draw_jury(
+        ctx: Context<DrawJury>,
+        computation_offset: u64,
+        _problem_id: u64,
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let panel = &mut ctx.accounts.panel;
+        require!(
+            panel.jurors.is_empty() && !panel.seated,
+            ErrorCode::JuryAlreadyDrawn
+        );
+        require!(!panel.draw_pending, ErrorCode::ComputationPending);
+        panel.draw_pending = true;
+
+        let args = vec![Argument::PlaintextU128(nonce)];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![DrawJuryCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.panel.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.juror_pool.key(),
+                    is_writable: false,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "draw_jury")]
+    pub fn draw_jury_callback(
+        ctx: Context<DrawJuryCallback>,
+        output: ComputationOutputs<DrawJuryOutput>,
+    ) -> Result<()> {
+        let panel = &mut ctx.accounts.panel;
+        require!(panel.draw_pending, ErrorCode::NoComputationPending);
+        panel.draw_pending = false;
+
+        let o = match output {
+            ComputationOutputs::Success(DrawJuryOutput { field_0 }) => field_0,
+            _ => {
+                emit!(ComputationAbortedEvent {
+                    problem_id: panel.problem_id,
+                    circuit: "draw_jury".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        // The pool is read in the same transaction the seed is revealed in,
+        // so nobody can join or leave it knowing the seed.
+        let seed = hashv(&[
+            b"jury",
+            &panel.problem_id.to_le_bytes(),
+            &o.field_0.to_le_bytes(),
+        ])
+        .to_bytes();
+        panel.jurors = draw_jurors(
+            &ctx.accounts.juror_pool.jurors,
+            &panel.excluded,
+            seed,
+            panel.panel_size as usize,
+        );
+        panel.encrypted_tally = o.field_1.ciphertexts;
+        panel.nonce = o.field_1.nonce;
+
+        emit!(JurorsDrawnEvent {
+            problem_id: panel.problem_id,
+            jurors: panel.jurors.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Locks the drawn jurors, passed as the remaining accounts, so they
+    /// cannot unstake ahead of a slash. A drawn juror who has left the pool
+    /// since the draw is dropped from the panel.
+    pub fn seat_jurors<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeatJurors<'info>>,
+        problem_id: u64,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.juror_pool.jurors;
+        let panel = &mut ctx.accounts.panel;
+
+        require!(!panel.jurors.is_empty(), ErrorCode::JuryNotDrawn);
+        require!(!panel.seated, ErrorCode::JuryAlreadyDrawn);
+        require!(
+            Clock::get()?.unix_timestamp < panel.vote_deadline,
+            ErrorCode::DeadlinePassed
+        );
+
+        panel.jurors.retain(|juror| pool.contains(juror));
+        require!(
+            !panel.jurors.is_empty() && ctx.remaining_accounts.len() == panel.jurors.len(),
+            ErrorCode::NotEnoughJurors
+        );
+
+        for (account, drawn) in ctx.remaining_accounts.iter().zip(&panel.jurors) {
+            let mut juror = Account::<Juror>::try_from(account)?;
+            require!(juror.authority == *drawn, ErrorCode::NotAJuror);
+            juror.active_panels += 1;
+            juror.exit(ctx.program_id)?;
+        }
+
+        panel.quorum_size = panel.jurors.len() as u8 / 2 + 1;
+        panel.seated = true;
+
+        emit!(JurorsAssignedEvent {
+            problem_id,
+            jurors: panel.jurors.clone(),
+            quorum_size: panel.quorum_size,
+            vote_deadline: panel.vote_deadline,
+        });
+
+        Ok(())
+    }
+
+    pub fn submit_juror_vote(
+        ctx: Context<SubmitJurorVote>,
+        computation_offset: u64,
+        problem_id: u64,
+        encrypted_vote: [u8; 32],
+        vote_pubkey: [u8; 32],
+        vote_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Categorical panels vote through `submit_categorical_vote`.
+        require!(
+            ctx.accounts.problem.market_kind == MarketKind::Binary,
+            ErrorCode::InvalidMarketKind
+        );
+
+        let panel = &mut ctx.accounts.panel;
+
+        require!(panel.seated, ErrorCode::JuryNotSeated);
+        require!(
+            Clock::get()?.unix_timestamp < panel.vote_deadline,
+            ErrorCode::DeadlinePassed
+        );
+        let seat = panel
+            .seat(&ctx.accounts.juror.key())
+            .ok_or(ErrorCode::NotAJuror)?;
+        require!(!panel.has_voted(seat), ErrorCode::AlreadyVoted);
+        // Each vote builds on the tally the previous one wrote back.
+        require!(panel.pending_seat.is_none(), ErrorCode::ComputationPending);
+
+        panel.voted |= 1 << seat;
+        panel.votes_cast += 1;
+        panel.pending_seat = Some(seat as u8);
+
+        let args = vec![
+            Argument::ArcisPubkey(vote_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+            Argument::EncryptedBool(encrypted_vote),
+            Argument::PlaintextU128(panel.nonce),
+            Argument::EncryptedU8(panel.encrypted_tally[0]),
+            Argument::EncryptedU8(panel.encrypted_tally[1]),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitQuorumVoteCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.panel.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        // A vote aborted in the MPC cluster is submitted again over this one.
+        let juror_vote = &mut ctx.accounts.juror_vote;
+        juror_vote.bump = ctx.bumps.juror_vote;
+        juror_vote.problem_id = problem_id;
+        juror_vote.juror = ctx.accounts.juror.key();
+        juror_vote.encrypted_vote = encrypted_vote;
+        juror_vote.vote_pubkey = vote_pubkey;
+        juror_vote.vote_nonce = vote_nonce;
+        juror_vote.timestamp = Clock::get()?.unix_timestamp;
+
+        emit!(JurorVoteEvent {
+            problem_id,
+            juror: juror_vote.juror,
+            votes_cast: ctx.accounts.panel.votes_cast,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_quorum_vote")]
+    pub fn submit_quorum_vote_callback(
+        ctx: Context<SubmitQuorumVoteCallback>,
+        output: ComputationOutputs<SubmitQuorumVoteOutput>,
+    ) -> Result<()> {
+        let panel = &mut ctx.accounts.panel;
+        let seat = panel
+            .pending_seat
+            .take()
+            .ok_or(ErrorCode::NoComputationPending)?;
+
+        // An aborted vote is taken back so the juror can cast it again.
+        let o = match output {
+            ComputationOutputs::Success(SubmitQuorumVoteOutput { field_0 }) => field_0,
+            _ => {
+                panel.voted &= !(1 << seat);
+                panel.votes_cast -= 1;
+
+                emit!(ComputationAbortedEvent {
+                    problem_id: panel.problem_id,
+                    circuit: "submit_quorum_vote".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        panel.encrypted_tally = o.ciphertexts;
+        panel.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    pub fn resolve_jury(
+        ctx: Context<ResolveJury>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        require!(
+            ctx.accounts.problem.market_kind == MarketKind::Binary,
+            ErrorCode::InvalidMarketKind
+        );
+
+        let panel = &ctx.accounts.panel;
+
+        // Once every seated juror has voted there is nothing left to wait for.
+        require!(
+            Clock::get()?.unix_timestamp >= panel.vote_deadline
+                || (panel.seated && panel.votes_cast as usize == panel.jurors.len()),
+            ErrorCode::DeadlineNotReached
+        );
+        require!(!panel.resolved, ErrorCode::MarketAlreadyResolved);
+        require!(
+            panel.votes_cast >= panel.quorum_size,
+            ErrorCode::QuorumNotReached
+        );
+        // The last vote has to be in the tally before it is revealed.
+        require!(panel.pending_seat.is_none(), ErrorCode::ComputationPending);
+
+        let args = vec![
+            Argument::PlaintextU128(panel.nonce),
+            Argument::EncryptedU8(panel.encrypted_tally[0]),
+            Argument::EncryptedU8(panel.encrypted_tally[1]),
+            Argument::PlaintextU8(panel.quorum_size),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ResolveMarketCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.panel.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "resolve_market")]
+    pub fn resolve_market_callback(
+        ctx: Context<ResolveMarketCallback>,
+        output: ComputationOutputs<ResolveMarketOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(ResolveMarketOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let panel = &mut ctx.accounts.panel;
+        require!(!panel.resolved, ErrorCode::MarketAlreadyResolved);
+
+        panel.resolved = true;
+        panel.outcome = result.field_0;
+        panel.yes_votes = result.field_1;
+        panel.no_votes = result.field_2;
+
+        emit!(JuryResolvedEvent {
+            problem_id: panel.problem_id,
+            outcome: panel.outcome,
+            yes_votes: panel.yes_votes,
+            no_votes: panel.no_votes,
+        });
+
+        Ok(())
+    }
+
+    pub fn settle_juror_vote(
+        ctx: Context<SettleJurorVote>,
+        computation_offset: u64,
+        _problem_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let panel = &ctx.accounts.panel;
+
+        require!(panel.resolved, ErrorCode::MarketNotResolved);
+        let seat = panel
+            .seat(&ctx.accounts.juror.authority)
+            .ok_or(ErrorCode::NotAJuror)?;
+        require!(!panel.is_settled(seat), ErrorCode::JurorAlreadySettled);
+        // The vote record outlives the panel, so a juror who sat this one out
+        // may still have one from an earlier panel; they settle as absent.
+        require!(panel.has_voted(seat), ErrorCode::JurorDidNotVote);
+
+        let juror_vote = &ctx.accounts.juror_vote;
+        let args = vec![
+            Argument::ArcisPubkey(juror_vote.vote_pubkey),
+            Argument::PlaintextU128(juror_vote.vote_nonce),
+            Argument::EncryptedBool(juror_vote.encrypted_vote),
+            Argument::PlaintextBool(panel.outcome),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CheckJurorVoteCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.panel.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.juror.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "check_juror_vote")]
+    pub fn check_juror_vote_callback(
+        ctx: Context<CheckJurorVoteCallback>,
+        output: ComputationOutputs<CheckJurorVoteOutput>,
+    ) -> Result<()> {
+        let sided_with_outcome = match output {
+            ComputationOutputs::Success(CheckJurorVoteOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let seat = ctx
+            .accounts
+            .panel
+            .seat(&ctx.accounts.juror.authority)
+            .ok_or(ErrorCode::NotAJuror)?;
+        // Only the first of several concurrently queued checks may settle the juror.
+        require!(
+            !ctx.accounts.panel.is_settled(seat),
+            ErrorCode::JurorAlreadySettled
+        );
+
+        // The majority is paid by `close_jury` once every minority and absent
+        // juror's slashed stake is in the pool, and stays on the panel until then.
+        let slashed = if sided_with_outcome {
+            0
+        } else {
+            ctx.accounts.juror.slash_amount()
+        };
+        release_escrow(
+            &ctx.accounts.juror.to_account_info(),
+            &ctx.accounts.panel.to_account_info(),
+            slashed,
+        )?;
+
+        let juror = &mut ctx.accounts.juror;
+        juror.stake = juror
+            .stake
+            .checked_sub(slashed)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        juror.amount_slashed += slashed;
+        if !sided_with_outcome {
+            juror.active_panels -= 1;
+        }
+
+        let panel = &mut ctx.accounts.panel;
+        panel.settled |= 1 << seat;
+        panel.reward_pool += slashed;
+        if sided_with_outcome {
+            panel.majority |= 1 << seat;
+        }
+
+        emit!(JurorSettledEvent {
+            problem_id: panel.problem_id,
+            juror: juror.authority,
+            voted: true,
+            sided_with_outcome,
+            reward: 0,
+            slashed,
+        });
+
+        Ok(())
+    }
+
+    pub fn settle_juror(ctx: Context<SettleJuror>, _problem_id: u64) -> Result<()> {
+        let panel = &ctx.accounts.panel;
+
+        // Jurors who were only drawn were never locked, so there is nothing to settle.
+        require!(panel.seated, ErrorCode::JuryNotSeated);
+        require!(
+            Clock::get()?.unix_timestamp >= panel.vote_deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        let seat = panel
+            .seat(&ctx.accounts.juror.authority)
+            .ok_or(ErrorCode::NotAJuror)?;
+        require!(!panel.is_settled(seat), ErrorCode::JurorAlreadySettled);
+
+        // Voters on a resolved panel settle through `settle_juror_vote`; voters on a
+        // panel that missed quorum are simply released, as are categorical voters,
+        // whose outcome index can't be checked against a yes/no verdict.
+        let voted = panel.has_voted(seat);
+        let categorical = ctx.accounts.problem.market_kind == MarketKind::Categorical;
+        require!(
+            !voted || (!panel.resolved && (panel.votes_cast < panel.quorum_size || categorical)),
+            ErrorCode::AlreadyVoted
+        );
+
+        let slashed = if voted {
+            0
+        } else {
+            ctx.accounts.juror.slash_amount()
+        };
+        release_escrow(
+            &ctx.accounts.juror.to_account_info(),
+            &ctx.accounts.panel.to_account_info(),
+            slashed,
+        )?;
+
+        let juror = &mut ctx.accounts.juror;
+        juror.stake = juror
+            .stake
+            .checked_sub(slashed)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        juror.amount_slashed += slashed;
+        juror.active_panels -= 1;
+
+        let panel = &mut ctx.accounts.panel;
+        panel.settled |= 1 << seat;
+        panel.reward_pool += slashed;
+
+        emit!(JurorSettledEvent {
+            problem_id: panel.problem_id,
+            juror: juror.authority,
+            voted,
+            sided_with_outcome: false,
+            reward: 0,
+            slashed,
+        });
+
+        Ok(())
+    }
+
+    /// Splits the reward pool between the jurors who voted with the outcome,
+    /// passed as the remaining accounts in seat order, and closes the panel.
+    /// Anyone may send it once every juror is settled; the funder gets back
+    /// the rent and whatever the majority doesn't take, all of it when there
+    /// is no majority. A panel that was never seated locked nobody and can
+    /// close right away.
+    pub fn close_jury<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseJury<'info>>,
+        problem_id: u64,
+    ) -> Result<()> {
+        let panel = &ctx.accounts.panel;
+
+        require!(!panel.draw_pending, ErrorCode::ComputationPending);
+        require!(
+            !panel.seated || panel.all_settled(),
+            ErrorCode::PanelNotSettled
+        );
+
+        let majority: Vec<Pubkey> = panel
+            .jurors
+            .iter()
+            .enumerate()
+            .filter(|(seat, _)| panel.in_majority(*seat))
+            .map(|(_, juror)| *juror)
+            .collect();
+        require!(
+            ctx.remaining_accounts.len() == majority.len(),
+            ErrorCode::NotAJuror
+        );
+        let reward = panel.reward_pool / majority.len().max(1) as u64;
+
+        let panel_info = ctx.accounts.panel.to_account_info();
+        for (account, rewarded) in ctx.remaining_accounts.iter().zip(&majority) {
+            let mut juror = Account::<Juror>::try_from(account)?;
+            require!(juror.authority == *rewarded, ErrorCode::NotAJuror);
+            release_escrow(&panel_info, account, reward)?;
+            juror.stake += reward;
+            juror.rewards_earned += reward;
+            juror.active_panels -= 1;
+            juror.exit(ctx.program_id)?;
+
+            emit!(JurorRewardedEvent {
+                problem_id,
+                juror: *rewarded,
+                reward,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn set_dispute_window(
+        ctx: Context<SetDisputeWindow>,
+        _problem_id: u64,
+        dispute_window: i64,
+    ) -> Result<()> {
+        let problem = &mut ctx.accounts.problem;
+
+        // Solvers must know the window before they commit work.
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp < problem.deadline,
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            (0..=MAX_DISPUTE_WINDOW).contains(&dispute_window),
+            ErrorCode::InvalidDisputeWindow
+        );
+
+        problem.dispute_window = dispute_window;
+
+        Ok(())
+    }
+
+    /// Challenges the winner the creator picked. The challenger pays the bond
+    /// and a fixed juror fee, and the dispute's panel is opened here on fixed
+    /// terms; anyone can then draw and seat it with `draw_jury` and
+    /// `seat_jurors`. The jury votes through `submit_juror_vote` and
+    /// `resolve_jury`, which run the `submit_quorum_vote` and `resolve_market`
+    /// circuits directly; `resolve_dispute` reads its outcome.
+    pub fn dispute_winner(ctx: Context<DisputeWinner>, problem_id: u64) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Closed,
+            ErrorCode::ProblemNotClosed
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Creator,
+            ErrorCode::InvalidJudgingMode
+        );
+        // A dispute jury rules yes/no on a single winner.
+        require!(
+            problem.market_kind == MarketKind::Binary,
+            ErrorCode::InvalidMarketKind
+        );
+        require!(
+            problem.in_dispute_window(Clock::get()?.unix_timestamp),
+            ErrorCode::DisputeWindowClosed
+        );
+        require!(
+            problem.winner != Some(ctx.accounts.challenger.key()),
+            ErrorCode::UnauthorizedAccess
+        );
+
+        // Nobody with a stake in the outcome sits on the panel.
+        let mut excluded = vec![problem.creator, ctx.accounts.challenger.key()];
+        excluded.extend(problem.winner);
+        require!(
+            ctx.accounts.juror_pool.candidates(&excluded) >= DISPUTE_PANEL_SIZE as usize,
+            ErrorCode::NotEnoughJurors
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.challenger.to_account_info(),
+                    to: ctx.accounts.dispute.to_account_info(),
+                },
+            ),
+            DISPUTE_BOND,
+        )?;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.challenger.to_account_info(),
+                    to: ctx.accounts.panel.to_account_info(),
+                },
+            ),
+            DISPUTE_JUROR_FEE,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let panel = &mut ctx.accounts.panel;
+        panel.bump = ctx.bumps.panel;
+        panel.index = problem.jury_count;
+        panel.open(
+            problem_id,
+            ctx.accounts.challenger.key(),
+            excluded,
+            DISPUTE_PANEL_SIZE,
+            now + DISPUTE_VOTE_PERIOD,
+            DISPUTE_JUROR_FEE,
+        );
+
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.bump = ctx.bumps.dispute;
+        dispute.problem_id = problem_id;
+        dispute.challenger = ctx.accounts.challenger.key();
+        dispute.disputed_winner = problem.winner.unwrap_or_default();
+        dispute.bond = DISPUTE_BOND;
+        dispute.opened_at = now;
+        dispute.panel_index = problem.jury_count;
+        dispute.deadline = now + DISPUTE_VOTE_PERIOD + DISPUTE_RESOLUTION_PERIOD;
+        dispute.resolved = false;
+        dispute.overturned = false;
+
+        // Each dispute gets its own account and panel, so a winner picked
+        // after an overturn can be challenged again right away.
+        let problem = &mut ctx.accounts.problem;
+        problem.dispute_count += 1;
+        problem.jury_count += 1;
+        problem.state = ProblemState::Disputed;
+
+        emit!(DisputeOpenedEvent {
+            problem_id,
+            challenger: dispute.challenger,
+            disputed_winner: dispute.disputed_winner,
+            bond: DISPUTE_BOND,
+        });
+
+        Ok(())
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, _problem_id: u64) -> Result<()> {
+        let panel = &ctx.accounts.panel;
+
+        require!(
+            ctx.accounts.problem.state == ProblemState::Disputed,
+            ErrorCode::ProblemNotDisputed
+        );
+        require!(!ctx.accounts.dispute.resolved, ErrorCode::DisputeResolved);
+
+        // A panel that never seated or never reached quorum by its vote
+        // deadline, or whose tally is still unrevealed at the dispute's
+        // deadline, leaves the winner standing, but the challenger did nothing
+        // wrong and gets the bond back.
+        let now = Clock::get()?.unix_timestamp;
+        let quorum_missed = !panel.resolved
+            && ((now >= panel.vote_deadline
+                && (!panel.seated || panel.votes_cast < panel.quorum_size))
+                || now >= ctx.accounts.dispute.deadline);
+        require!(
+            panel.resolved || quorum_missed,
+            ErrorCode::MarketNotResolved
+        );
+
+        // A `yes` majority upholds the challenge; ties keep the winner.
+        let overturned = panel.resolved && panel.outcome;
+        let bond_to = if overturned || quorum_missed {
+            ctx.accounts.challenger.to_account_info()
+        } else {
+            ctx.accounts.winner.to_account_info()
+        };
+        let bond = ctx.accounts.dispute.bond;
+        release_escrow(&ctx.accounts.dispute.to_account_info(), &bond_to, bond)?;
+
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.resolved = true;
+        dispute.overturned = overturned;
+
+        let challenger_profile = &mut ctx.accounts.challenger_profile;
+        challenger_profile.bump = ctx.bumps.challenger_profile;
+        challenger_profile.solver = dispute.challenger;
+        let winner_profile = &mut ctx.accounts.winner_profile;
+        winner_profile.bump = ctx.bumps.winner_profile;
+        winner_profile.solver = dispute.disputed_winner;
+        let creator_profile = &mut ctx.accounts.creator_profile;
+        creator_profile.bump = ctx.bumps.creator_profile;
+        creator_profile.creator = ctx.accounts.problem.creator;
+
+        // Whoever the panel ruled against lost the dispute; a missed quorum
+        // rules against nobody.
+        if overturned {
+            winner_profile.disputes_lost += 1;
+            creator_profile.disputes_lost += 1;
+        } else if !quorum_missed {
+            challenger_profile.disputes_lost += 1;
+        }
+
+        // Losing a dispute forfeits the creator bond like missing the review does.
+        if overturned && ctx.accounts.problem.bond_status == BondStatus::Held {
+            slash_creator_bond(
+                &mut ctx.accounts.problem,
+                &ctx.accounts.creator.to_account_info(),
+            )?;
+        }
+
+        let problem = &mut ctx.accounts.problem;
+        if overturned {
+            // Reopen judging so the creator can pick again with `close_problem`.
+            problem.winner = None;
+            problem.closed_at = 0;
+            problem.judged_at = 0;
+            problem.state = ProblemState::Active;
+        } else {
+            problem.state = ProblemState::Closed;
+        }
+
+        emit!(DisputeResolvedEvent {
+            problem_id: problem.problem_id,
+            challenger: dispute.challenger,
+            overturned,
+            bond_returned: overturned || quorum_missed,
+        });
+
+        Ok(())
+    }
+
+    pub fn open_judging_panel(
+        ctx: Context<OpenJudgingPanel>,
+        computation_offset: u64,
+        _problem_id: u64,
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+        let judging_panel = &mut ctx.accounts.judging_panel;
+
+        require!(
+            problem.creator == ctx.accounts.authority.key()
+                || judging_panel.seat(&ctx.accounts.authority.key()).is_some(),
+            ErrorCode::UnauthorizedAccess
+        );
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Panel,
+            ErrorCode::InvalidJudgingMode
+        );
+        // Re-opening would wipe the picks already tallied.
+        require!(!judging_panel.opened, ErrorCode::JudgingPanelOpened);
+        judging_panel.opened = true;
+
+        let args = vec![Argument::PlaintextU128(nonce)];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitializeJudgeTallyCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.judging_panel.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "initialize_judge_tally")]
+    pub fn initialize_judge_tally_callback(
+        ctx: Context<InitializeJudgeTallyCallback>,
+        output: ComputationOutputs<InitializeJudgeTallyOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitializeJudgeTallyOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        ctx.accounts.judging_panel.encrypted_tally = o.ciphertexts;
+        ctx.accounts.judging_panel.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    pub fn submit_judge_pick(
+        ctx: Context<SubmitJudgePick>,
+        computation_offset: u64,
+        problem_id: u64,
+        encrypted_pick: [u8; 32],
+        pick_pubkey: [u8; 32],
+        pick_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= problem.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        require!(
+            problem.judging_mode == JudgingMode::Panel,
+            ErrorCode::InvalidJudgingMode
+        );
+
+        let num_candidates = problem.total_solutions.min(MAX_JUDGE_CANDIDATES as u64) as u8;
+
+        let judging_panel = &mut ctx.accounts.judging_panel;
+        require!(judging_panel.opened, ErrorCode::JudgingPanelNotOpened);
+        // The MXE never encrypts under a zero nonce, so this is the empty tally
+        // `open_judging_panel` queued and its callback hasn't written yet.
+        require!(
+            judging_panel.nonce != 0,
+            ErrorCode::JudgeTallyNotInitialized
+        );
+        let seat = judging_panel
+            .seat(&ctx.accounts.judge.key())
+            .ok_or(ErrorCode::NotAJudge)?;
+        require!(!judging_panel.has_voted(seat), ErrorCode::AlreadyVoted);
+        require!(
+            judging_panel.pending_seat.is_none(),
+            ErrorCode::ComputationPending
+        );
+        judging_panel.voted |= 1 << seat;
+        judging_panel.picks_cast += 1;
+        judging_panel.pending_seat = Some(seat as u8);
+
+        let args = vec![
+            Argument::ArcisPubkey(pick_pubkey),
+            Argument::PlaintextU128(pick_nonce),
+            Argument::EncryptedU8(encrypted_pick),
+            Argument::PlaintextU8(num_candidates),
+            Argument::PlaintextU8(judging_panel.threshold),
+            Argument::PlaintextU128(judging_panel.nonce),
+            Argument::Account(judging_panel.key(), 8 + 1, 32 * MAX_JUDGE_CANDIDATES as u32),
+        ];
+
+        let judge_balance = ctx.accounts.judge.lamports();
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitJudgePickCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.judging_panel.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+        let fee = judge_balance.saturating_sub(ctx.accounts.judge.lamports());
+        reimburse_mpc_fee(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.judge.to_account_info(),
+            fee,
+        )?;
+
+        emit!(JudgePickSubmittedEvent {
+            problem_id,
+            judge: ctx.accounts.judge.key(),
+            picks_cast: ctx.accounts.judging_panel.picks_cast,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_judge_pick")]
+    pub fn submit_judge_pick_callback(
+        ctx: Context<SubmitJudgePickCallback>,
+        output: ComputationOutputs<SubmitJudgePickOutput>,
+    ) -> Result<()> {
+        let judging_panel = &mut ctx.accounts.judging_panel;
+        let seat = judging_panel
+            .pending_seat
+            .take()
+            .ok_or(ErrorCode::NoComputationPending)?;
+
+        // An aborted pick is taken back so the judge can submit it again.
+        let o = match output {
+            ComputationOutputs::Success(SubmitJudgePickOutput { field_0 }) => field_0,
+            _ => {
+                judging_panel.voted &= !(1 << seat);
+                judging_panel.picks_cast -= 1;
+
+                emit!(ComputationAbortedEvent {
+                    problem_id: judging_panel.problem_id,
+                    circuit: "submit_judge_pick".to_string(),
+                });
+                return Ok(());
+            }
+        };
+
+        judging_panel.encrypted_tally = o.field_0.ciphertexts;
+        judging_panel.nonce = o.field_0.nonce;
+
+        let decision = o.field_1;
+        let problem = &mut ctx.accounts.problem;
+
+        // A pick still in flight when the panel decided changes nothing.
+        if decision.field_1 && problem.state == ProblemState::Active {
+            judging_panel.decided = true;
+            problem.winning_index = Some(decision.field_0 as u64);
+            problem.state = ProblemState::Closed;
+            problem.judged_at = Clock::get()?.unix_timestamp;
+
+            emit!(JudgingPanelDecidedEvent {
+                problem_id: problem.problem_id,
+                winning_index: decision.field_0 as u64,
+                picks_cast: judging_panel.picks_cast,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        multisig_id: u64,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_MULTISIG_MEMBERS,
+            ErrorCode::InvalidMultisig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            ErrorCode::InvalidMultisig
+        );
+        require!(
+            members
+                .iter()
+                .enumerate()
+                .all(|(i, member)| !members[..i].contains(member)),
+            ErrorCode::InvalidMultisig
+        );
+
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.bump = ctx.bumps.multisig;
+        multisig.multisig_id = multisig_id;
+        multisig.members = members.clone();
+        multisig.threshold = threshold;
+        multisig.proposal_count = 0;
+
+        emit!(MultisigCreatedEvent {
+            multisig: multisig.key(),
+            members,
+            threshold,
+        });
+
+        Ok(())
+    }
+
+    pub fn assign_problem_to_multisig(
+        ctx: Context<AssignProblemToMultisig>,
+        _problem_id: u64,
+    ) -> Result<()> {
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+
+        // From here on only threshold-approved proposals act as the creator.
+        problem.creator = ctx.accounts.multisig.key();
+
+        emit!(ProblemAssignedToMultisigEvent {
+            problem_id: problem.problem_id,
+            previous_creator: ctx.accounts.creator.key(),
+            multisig: problem.creator,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        problem_id: u64,
+        action: ProposalAction,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        let seat = multisig
+            .seat(&ctx.accounts.member.key())
+            .ok_or(ErrorCode::NotAMultisigMember)?;
+
+        let index = multisig.proposal_count;
+        multisig.proposal_count += 1;
+
+        // Proposing counts as the proposer's own approval.
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.bump = ctx.bumps.proposal;
+        proposal.multisig = multisig.key();
+        proposal.index = index;
+        proposal.problem_id = problem_id;
+        proposal.action = action.clone();
+        proposal.proposer = ctx.accounts.member.key();
+        proposal.approvals = 1 << seat;
+        proposal.executed = false;
+        proposal.created_at = Clock::get()?.unix_timestamp;
+
+        emit!(ProposalCreatedEvent {
+            multisig: proposal.multisig,
+            index,
+            problem_id,
+            proposer: proposal.proposer,
+            action,
+        });
+
+        Ok(())
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>, _index: u64) -> Result<()> {
+        let seat = ctx
+            .accounts
+            .multisig
+            .seat(&ctx.accounts.member.key())
+            .ok_or(ErrorCode::NotAMultisigMember)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::ProposalExecuted);
+        require!(!proposal.has_approved(seat), ErrorCode::AlreadyApproved);
+        proposal.approvals |= 1 << seat;
+
+        emit!(ProposalApprovedEvent {
+            multisig: proposal.multisig,
+            index: proposal.index,
+            member: ctx.accounts.member.key(),
+            approvals: proposal.approvals.count_ones() as u8,
+        });
+
+        Ok(())
+    }
+
+    pub fn execute_proposal(
+        ctx: Context<ExecuteProposal>,
+        _problem_id: u64,
+        _index: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(!proposal.executed, ErrorCode::ProposalExecuted);
+        require!(
+            proposal.approvals.count_ones() >= ctx.accounts.multisig.threshold as u32,
+            ErrorCode::ApprovalThresholdNotReached
+        );
+        proposal.executed = true;
+
+        ctx.accounts.creator_profile.bump = ctx.bumps.creator_profile;
+        ctx.accounts.creator_profile.creator = ctx.accounts.problem.creator;
+
+        // Anything the creator would have been refunded goes to the recipient
+        // the members approved instead.
+        let action = ctx.accounts.proposal.action.clone();
+        let recipient = ctx
+            .accounts
+            .recipient
+            .as_ref()
+            .filter(|account| Some(account.key()) == action.recipient())
+            .map(|account| account.to_account_info())
+            .ok_or(ErrorCode::InvalidRecipient);
+
+        match action {
+            ProposalAction::CloseProblem { winner } => close_with_winner(
+                &mut ctx.accounts.problem,
+                ctx.accounts.judging_panel.as_deref(),
+                &mut ctx.accounts.creator_profile,
+                winner,
+            )?,
+            ProposalAction::UpdateProblem { update } => {
+                apply_problem_update(&mut ctx.accounts.problem, update)?
+            }
+            ProposalAction::CancelProblem { .. } => {
+                cancel_open_problem(&mut ctx.accounts.problem, &recipient?)?
+            }
+            ProposalAction::ReturnCreatorBond { .. } => {
+                return_held_bond(&mut ctx.accounts.problem, &recipient?)?
+            }
+            ProposalAction::WithdrawComputeBudget { .. } => {
+                withdraw_unused_budget(&mut ctx.accounts.problem, &recipient?)?
+            }
+            ProposalAction::RefundMilestones { .. } => refund_open_milestones(
+                &mut ctx.accounts.problem,
+                ctx.accounts
+                    .milestone_plan
+                    .as_mut()
+                    .ok_or(ErrorCode::MilestonePlanRequired)?,
+                &recipient?,
+            )?,
+            ProposalAction::RefundUnsolvedPuzzle { .. } => {
+                refund_unsolved(&mut ctx.accounts.problem, &recipient?)?
+            }
+            ProposalAction::RefundConfidentialBounty { .. } => {
+                refund_confidential_cap(&mut ctx.accounts.problem, &recipient?)?
+            }
+        }
+
+        emit!(ProposalExecutedEvent {
+            multisig: ctx.accounts.proposal.multisig,
+            index: ctx.accounts.proposal.index,
+            problem_id: ctx.accounts.proposal.problem_id,
+        });
+
+        Ok(())
+    }
+
+    pub fn return_creator_bond(ctx: Context<ReturnCreatorBond>, _problem_id: u64) -> Result<()> {
+        return_held_bond(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
+    pub fn slash_missed_review(ctx: Context<SlashMissedReview>, _problem_id: u64) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.bond_status == BondStatus::Held,
+            ErrorCode::CreatorBondNotHeld
+        );
+        require!(
+            problem.missed_review(Clock::get()?.unix_timestamp),
+            ErrorCode::ReviewWindowOpen
+        );
+
+        slash_creator_bond(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
+    pub fn claim_bond_share(ctx: Context<ClaimBondShare>, _problem_id: u64) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.bond_status == BondStatus::Slashed,
+            ErrorCode::CreatorBondNotSlashed
+        );
+
+        let solution = &mut ctx.accounts.solution;
+        require!(!solution.bond_claimed, ErrorCode::AlreadyPaid);
+        solution.bond_claimed = true;
+
+        // Every solver gets an equal share; the division dust stays behind.
+        let share = problem.bond_slashed / problem.total_solutions;
+        release_escrow(
+            &ctx.accounts.problem.to_account_info(),
+            &ctx.accounts.solver.to_account_info(),
+            share,
+        )?;
+
+        emit!(BondShareClaimedEvent {
+            problem_id: ctx.accounts.problem.problem_id,
+            solver: ctx.accounts.solver.key(),
+            amount: share,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>, _problem_id: u64) -> Result<()> {
+        let vesting = &mut ctx.accounts.vesting;
+
+        require!(vesting.start_time > 0, ErrorCode::VestingNotStarted);
+
+        let unlocked = vesting.unlocked_amount(Clock::get()?.unix_timestamp);
+        let amount = unlocked - vesting.claimed_amount;
+        require!(amount > 0, ErrorCode::NothingVested);
+        vesting.claimed_amount = unlocked;
+
+        release_escrow(
+            &ctx.accounts.problem.to_account_info(),
+            &ctx.accounts.beneficiary.to_account_info(),
+            amount,
+        )?;
+        ctx.accounts.problem.escrow_amount -= amount;
+
+        emit!(VestedClaimedEvent {
+            problem_id: ctx.accounts.vesting.problem_id,
+            beneficiary: ctx.accounts.beneficiary.key(),
+            amount,
+            claimed_amount: ctx.accounts.vesting.claimed_amount,
+            total_amount: ctx.accounts.vesting.total_amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u64,
+        end_time: i64,
+        prize_shares: Vec<u16>,
+    ) -> Result<()> {
+        require!(
+            end_time > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidSeason
+        );
+        // Shares are basis points of the prize pool, best-placed solver first.
+        require!(
+            !prize_shares.is_empty() && prize_shares.len() <= MAX_SEASON_WINNERS,
+            ErrorCode::InvalidSeason
+        );
+        require!(
+            prize_shares.iter().map(|&share| share as u32).sum::<u32>() == 10_000,
+            ErrorCode::InvalidSeason
+        );
+
+        let season = &mut ctx.accounts.season;
+        season.bump = ctx.bumps.season;
+        season.season_id = season_id;
+        season.authority = ctx.accounts.authority.key();
+        season.end_time = end_time;
+        season.prize_pool = 0;
+        season.prize_shares = prize_shares.clone();
+        season.problem_count = 0;
+        season.leaderboard = Vec::new();
+        season.finalized = false;
+
+        emit!(SeasonCreatedEvent {
+            season_id,
+            authority: season.authority,
+            end_time,
+            prize_shares,
+        });
+
+        Ok(())
+    }
+
+    pub fn add_problem_to_season(
+        ctx: Context<AddProblemToSeason>,
+        _season_id: u64,
+        problem_id: u64,
+    ) -> Result<()> {
+        let season = &mut ctx.accounts.season;
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            Clock::get()?.unix_timestamp < season.end_time && !season.finalized,
+            ErrorCode::SeasonEnded
+        );
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(problem.season_id.is_none(), ErrorCode::InvalidSeason);
+        // Categorical markets are paid outside `pay_winner` and would never score.
+        require!(
+            problem.market_kind == MarketKind::Binary,
+            ErrorCode::InvalidMarketKind
+        );
+
+        problem.season_id = Some(season.season_id);
+        season.problem_count += 1;
+
+        emit!(SeasonProblemAddedEvent {
+            season_id: season.season_id,
+            problem_id,
+        });
+
+        Ok(())
+    }
+
+    pub fn fund_season(ctx: Context<FundSeason>, _season_id: u64, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(!ctx.accounts.season.finalized, ErrorCode::SeasonFinalized);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.season.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let season = &mut ctx.accounts.season;
+        season.prize_pool += amount;
+
+        emit!(SeasonFundedEvent {
+            season_id: season.season_id,
+            funder: ctx.accounts.funder.key(),
+            amount,
+            prize_pool: season.prize_pool,
+        });
+
+        Ok(())
+    }
+
+    /// Pays the season's prize pool out to its leaderboard. The remaining
+    /// accounts are the leaderboard's solvers, best-placed first; shares with
+    /// nobody to pay go back to the season authority.
+    pub fn finalize_season(ctx: Context<FinalizeSeason>, _season_id: u64) -> Result<()> {
+        let season = &ctx.accounts.season;
+
+        require!(!season.finalized, ErrorCode::SeasonFinalized);
+        require!(
+            Clock::get()?.unix_timestamp >= season.end_time,
+            ErrorCode::SeasonNotEnded
+        );
+        require!(
+            ctx.remaining_accounts.len() == season.leaderboard.len(),
+            ErrorCode::InvalidSeasonAccounts
+        );
+
+        let season_info = season.to_account_info();
+        let prize_pool = season.prize_pool;
+        let mut paid_out = 0;
+
+        for (rank, (account, standing)) in ctx
+            .remaining_accounts
+            .iter()
+            .zip(&season.leaderboard)
+            .enumerate()
+        {
+            require!(
+                account.key() == standing.solver,
+                ErrorCode::InvalidSeasonAccounts
+            );
+
+            let prize = (prize_pool as u128 * season.prize_shares[rank] as u128 / 10_000) as u64;
+            release_escrow(&season_info, account, prize)?;
+            paid_out += prize;
+
+            emit!(SeasonPrizePaidEvent {
+                season_id: season.season_id,
+                solver: standing.solver,
+                rank: rank as u8,
+                points: standing.points,
+                prize,
+            });
+        }
+
+        let refunded = prize_pool - paid_out;
+        release_escrow(
+            &season_info,
+            &ctx.accounts.authority.to_account_info(),
+            refunded,
+        )?;
+
+        let season = &mut ctx.accounts.season;
+        season.finalized = true;
+        season.prize_pool = 0;
+
+        emit!(SeasonFinalizedEvent {
+            season_id: season.season_id,
+            paid_out,
+            refunded,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_bounty_template(
+        ctx: Context<CreateBountyTemplate>,
+        template_id: u64,
+        title: String,
+        description: String,
+        bounty_amount: u64,
+        bounty_mode: BountyMode,
+        compute_budget: u64,
+        cadence: i64,
+    ) -> Result<()> {
+        require!(
+            !title.is_empty() && title.len() <= 200,
+            ErrorCode::InvalidTitle
+        );
+        require!(
+            description.len() <= MAX_DESCRIPTION_LEN,
+            ErrorCode::DescriptionTooLong
+        );
+        require!(cadence >= MIN_TEMPLATE_CADENCE, ErrorCode::InvalidTemplate);
+        // Rounds are posted by anyone, so they can't carry judges, bonds or
+        // eligibility rules that only the creator could set up.
+        match bounty_mode {
+            BountyMode::Standard => {}
+            BountyMode::SealedBid => require!(bounty_amount > 0, ErrorCode::InvalidAmount),
+            BountyMode::Confidential => return Err(ErrorCode::InvalidBountyMode.into()),
+        }
+
+        let template = &mut ctx.accounts.template;
+        template.bump = ctx.bumps.template;
+        template.template_id = template_id;
+        template.creator = ctx.accounts.creator.key();
+        template.title = title.clone();
+        template.description = description;
+        template.bounty_amount = bounty_amount;
+        template.bounty_mode = bounty_mode;
+        template.compute_budget = compute_budget;
+        template.cadence = cadence;
+        template.vault_balance = 0;
+        template.rounds = 0;
+        template.last_problem_id = None;
+        template.last_deadline = 0;
+        template.active = true;
+
+        emit!(BountyTemplateCreatedEvent {
+            template_id,
+            creator: template.creator,
+            title,
+            bounty_amount,
+            cadence,
+        });
+
+        Ok(())
+    }
+
+    pub fn fund_bounty_template(
+        ctx: Context<FundBountyTemplate>,
+        _template_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(ctx.accounts.template.active, ErrorCode::TemplateInactive);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.template.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let template = &mut ctx.accounts.template;
+        template.vault_balance += amount;
+
+        emit!(BountyTemplateFundedEvent {
+            template_id: template.template_id,
+            funder: ctx.accounts.funder.key(),
+            amount,
+            vault_balance: template.vault_balance,
+        });
+
+        Ok(())
+    }
+
+    /// Permissionless crank that posts a template's next round once the
+    /// previous one's deadline has passed. Deadlines stay on the template's
+    /// cadence, skipping any periods nobody cranked in. The cranker is paid
+    /// back for the MPC fee out of the round's compute budget.
+    pub fn spawn_round(
+        ctx: Context<SpawnRound>,
+        computation_offset: u64,
+        _template_id: u64,
+        problem_id: u64,
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let template = &ctx.accounts.template;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(template.active, ErrorCode::TemplateInactive);
+        require!(now >= template.last_deadline, ErrorCode::RoundStillOpen);
+
+        let deadline = if template.last_deadline == 0 {
+            now + template.cadence
+        } else {
+            let periods = (now - template.last_deadline) / template.cadence + 1;
+            template.last_deadline + periods * template.cadence
+        };
+
+        // The vault escrows the bounty either way: as the price ceiling of a
+        // sealed-bid round, or for `pay_winner` to hand the winner of a
+        // standard one.
+        let escrow_amount = template.bounty_amount;
+        let escrowed_bounty = match template.bounty_mode {
+            BountyMode::Standard => template.bounty_amount,
+            _ => 0,
+        };
+        let deposit = escrow_amount
+            .checked_add(template.compute_budget)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        require!(
+            deposit <= template.vault_balance,
+            ErrorCode::InsufficientEscrow
+        );
+        release_escrow(
+            &template.to_account_info(),
+            &ctx.accounts.problem.to_account_info(),
+            deposit,
+        )?;
+
+        let args = vec![Argument::PlaintextU128(nonce)];
+
+        let payer_balance = ctx.accounts.payer.lamports();
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitializeProblemCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.problem.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.registry.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+        let fee = payer_balance.saturating_sub(ctx.accounts.payer.lamports());
+
+        let template = &mut ctx.accounts.template;
+        template.vault_balance -= deposit;
+        template.rounds += 1;
+        template.last_problem_id = Some(problem_id);
+        template.last_deadline = deadline;
+
+        let problem = &mut ctx.accounts.problem;
+        init_problem(
+            problem,
+            ctx.bumps.problem,
+            problem_id,
+            template.creator,
+            template.title.clone(),
+            template.description.clone(),
+            deadline,
+        );
+        problem.bounty_amount = template.bounty_amount;
+        problem.bounty_mode = template.bounty_mode;
+        problem.escrow_amount = escrow_amount;
+        problem.escrowed_bounty = escrowed_bounty;
+        problem.compute_budget = template.compute_budget;
+        problem.total_bounty = template.bounty_amount;
+
+        reimburse_mpc_fee(problem, &ctx.accounts.payer.to_account_info(), fee)?;
+
+        ctx.accounts.registry.open(ctx.bumps.registry, problem_id);
+
+        let creator_profile = &mut ctx.accounts.creator_profile;
+        creator_profile.bump = ctx.bumps.creator_profile;
+        creator_profile.creator = template.creator;
+        creator_profile.bounties_posted += 1;
+
+        emit!(ProblemPostedEvent {
+            problem_id,
+            creator: template.creator,
+            title: template.title.clone(),
+            deadline,
+            bounty_amount: template.bounty_amount,
+        });
+
+        emit!(RoundSpawnedEvent {
+            template_id: template.template_id,
+            round: template.rounds,
+            problem_id,
+            deadline,
+            vault_balance: template.vault_balance,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_bounty_template(
+        ctx: Context<CloseBountyTemplate>,
+        _template_id: u64,
+    ) -> Result<()> {
+        let template = &mut ctx.accounts.template;
+
+        require!(template.active, ErrorCode::TemplateInactive);
+
+        let refunded = template.vault_balance;
+        release_escrow(
+            &template.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            refunded,
+        )?;
+
+        template.vault_balance = 0;
+        template.active = false;
+
+        emit!(BountyTemplateClosedEvent {
+            template_id: template.template_id,
+            rounds: template.rounds,
+            refunded,
+        });
+
+        Ok(())
+    }
+
+    /// Splits a Standard problem's delivery into ordered milestones, escrowing
+    /// every milestone's amount in the problem. Milestones not approved by
+    /// `delivery_deadline` can be refunded to the creator.
+    pub fn create_milestone_plan(
+        ctx: Context<CreateMilestonePlan>,
+        problem_id: u64,
+        amounts: Vec<u64>,
+        delivery_deadline: i64,
+    ) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            problem.bounty_mode == BountyMode::Standard
+                && problem.market_kind == MarketKind::Binary
+                && problem.vesting_duration == 0,
+            ErrorCode::InvalidBountyMode
+        );
+        // Puzzle and verifier problems release the whole escrow to the first
+        // correct answer, milestone funds included, and a raffle winner isn't
+        // chosen for their work; only judged problems can plan deliverables.
+        require!(
+            matches!(
+                problem.judging_mode,
+                JudgingMode::Creator | JudgingMode::Panel | JudgingMode::Scored
+            ),
+            ErrorCode::InvalidJudgingMode
+        );
+        require!(
+            !amounts.is_empty() && amounts.len() <= MAX_MILESTONES,
+            ErrorCode::InvalidMilestonePlan
+        );
+        require!(
+            amounts.iter().all(|&amount| amount > 0),
+            ErrorCode::InvalidAmount
+        );
+        require!(
+            delivery_deadline > problem.deadline,
+            ErrorCode::InvalidDeadline
+        );
+
+        let total = amounts
+            .iter()
+            .try_fold(0u64, |total, &amount| total.checked_add(amount))
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.problem.to_account_info(),
+                },
+            ),
+            total,
+        )?;
+        ctx.accounts.problem.escrow_amount += total;
+
+        let plan = &mut ctx.accounts.milestone_plan;
+        plan.bump = ctx.bumps.milestone_plan;
+        plan.problem_id = problem_id;
+        plan.solver = None;
+        plan.delivery_deadline = delivery_deadline;
+        plan.milestones = amounts
+            .iter()
+            .map(|&amount| Milestone {
+                amount,
+                deliverable: [0; 32],
+                status: MilestoneStatus::Pending,
+            })
+            .collect();
+        plan.released = 0;
+        plan.refunded = 0;
+
+        emit!(MilestonePlanCreatedEvent {
+            problem_id,
+            amounts,
+            total,
+            delivery_deadline,
+        });
+
+        Ok(())
+    }
+
+    pub fn select_milestone_solver(
+        ctx: Context<SelectMilestoneSolver>,
+        problem_id: u64,
+        solver: Pubkey,
+    ) -> Result<()> {
+        let plan = &mut ctx.accounts.milestone_plan;
+
+        require!(plan.solver.is_none(), ErrorCode::MilestoneSolverSelected);
+        require!(
+            Clock::get()?.unix_timestamp < plan.delivery_deadline,
+            ErrorCode::DeadlinePassed
+        );
+
+        plan.solver = Some(solver);
+
+        emit!(MilestoneSolverSelectedEvent { problem_id, solver });
+
+        Ok(())
+    }
+
+    /// Milestones are delivered in order, and a submitted milestone can be
+    /// resubmitted until the creator approves it.
+    pub fn submit_milestone(
+        ctx: Context<SubmitMilestone>,
+        problem_id: u64,
+        index: u8,
+        deliverable: [u8; 32],
+    ) -> Result<()> {
+        let plan = &mut ctx.accounts.milestone_plan;
+
+        require!(
+            plan.solver == Some(ctx.accounts.solver.key()),
+            ErrorCode::UnauthorizedAccess
+        );
+        require!(
+            Clock::get()?.unix_timestamp < plan.delivery_deadline,
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            index as usize == plan.next_milestone(),
+            ErrorCode::MilestoneOutOfOrder
+        );
+
+        let milestone = plan
+            .milestones
+            .get_mut(index as usize)
+            .ok_or(ErrorCode::MilestoneOutOfOrder)?;
+        milestone.deliverable = deliverable;
+        milestone.status = MilestoneStatus::Submitted;
+
+        emit!(MilestoneSubmittedEvent {
+            problem_id,
+            index,
+            solver: ctx.accounts.solver.key(),
+            deliverable,
+        });
+
+        Ok(())
+    }
+
+    pub fn approve_milestone(
+        ctx: Context<ApproveMilestone>,
+        problem_id: u64,
+        index: u8,
+    ) -> Result<()> {
+        let plan = &mut ctx.accounts.milestone_plan;
+
+        require!(
+            plan.solver == Some(ctx.accounts.solver.key()),
+            ErrorCode::UnauthorizedAccess
+        );
+
+        let milestone = plan
+            .milestones
+            .get_mut(index as usize)
+            .ok_or(ErrorCode::MilestoneOutOfOrder)?;
+        require!(
+            milestone.status == MilestoneStatus::Submitted,
+            ErrorCode::MilestoneNotSubmitted
+        );
+        milestone.status = MilestoneStatus::Approved;
+        let amount = milestone.amount;
+        plan.released += amount;
+
+        let problem = &mut ctx.accounts.problem;
+        release_escrow(
+            &problem.to_account_info(),
+            &ctx.accounts.solver.to_account_info(),
+            amount,
+        )?;
+        problem.escrow_amount -= amount;
+
+        emit!(MilestoneApprovedEvent {
+            problem_id,
+            index,
+            solver: ctx.accounts.solver.key(),
+            amount,
+            released: plan.released,
+        });
+
+        Ok(())
+    }
+
+    pub fn refund_milestones(ctx: Context<RefundMilestones>, _problem_id: u64) -> Result<()> {
+        refund_open_milestones(
+            &mut ctx.accounts.problem,
+            &mut ctx.accounts.milestone_plan,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
+    /// First half of answering a puzzle: `commitment` is
+    /// `hashv([preimage, solver])`, which reveals nothing about the preimage
+    /// and cannot be replayed by anyone else.
+    pub fn commit_puzzle_solution(
+        ctx: Context<CommitPuzzleSolution>,
+        problem_id: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        let problem = &ctx.accounts.problem;
+
+        require!(
+            problem.judging_mode == JudgingMode::Puzzle,
+            ErrorCode::InvalidJudgingMode
+        );
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp < problem.deadline,
+            ErrorCode::DeadlinePassed
+        );
+
+        let commit = &mut ctx.accounts.puzzle_commit;
+        commit.bump = ctx.bumps.puzzle_commit;
+        commit.problem_id = problem_id;
+        commit.solver = ctx.accounts.solver.key();
+        commit.commitment = commitment;
+        commit.committed_slot = Clock::get()?.slot;
+
+        emit!(PuzzleCommittedEvent {
+            problem_id,
+            solver: commit.solver,
+            commitment,
+        });
+
+        Ok(())
+    }
+
+    /// Second half: the preimage is checked against both the solver's
+    /// commitment and the puzzle's target hash, and the first match is paid
+    /// the escrowed bounty. The commitment must be from an earlier slot, so
+    /// someone copying a reveal out of the mempool is always too late.
+    pub fn reveal_puzzle_solution(
+        ctx: Context<RevealPuzzleSolution>,
+        problem_id: u64,
+        preimage: Vec<u8>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let solver = ctx.accounts.solver.key();
+        let commit = &ctx.accounts.puzzle_commit;
+        let problem = &mut ctx.accounts.problem;
+
+        require!(
+            problem.state == ProblemState::Active,
+            ErrorCode::ProblemNotActive
+        );
+        require!(
+            clock.unix_timestamp < problem.deadline,
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            preimage.len() <= MAX_PUZZLE_PREIMAGE_LEN,
+            ErrorCode::InvalidPuzzle
+        );
+        require!(
+            commit.committed_slot < clock.slot,
+            ErrorCode::CommitmentTooRecent
+        );
+        require!(
+            hashv(&[&preimage, solver.as_ref()]).to_bytes() == commit.commitment,
+            ErrorCode::CommitmentMismatch
+        );
+        require!(
+            Some(hashv(&[&preimage]).to_bytes()) == problem.puzzle_hash,
+            ErrorCode::WrongPuzzleSolution
+        );
+
+        let amount = problem.escrow_amount;
+        release_escrow(
+            &problem.to_account_info(),
+            &ctx.accounts.solver.to_account_info(),
+            amount,
+        )?;
+
+        problem.escrow_amount = 0;
+        problem.winner = Some(solver);
+        problem.state = ProblemState::Settled;
+        problem.judged_at = clock.unix_timestamp;
+
+        emit!(PuzzleSolvedEvent {
+            problem_id,
+            solver,
+            preimage,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn refund_unsolved_puzzle(
+        ctx: Context<RefundUnsolvedPuzzle>,
+        _problem_id: u64,
+    ) -> Result<()> {
+        refund_unsolved(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
+    pub fn refund_confidential_bounty(
+        ctx: Context<RefundConfidentialBounty>,
+        _problem_id: u64,
+    ) -> Result<()> {
+        refund_confidential_cap(
+            &mut ctx.accounts.problem,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
+    /// Submits a plaintext solution to the problem's verifier program and pays
+    /// the escrowed bounty to the first one it accepts.
+    ///
+    /// The verifier is invoked as `verify(problem_id, solver, solution)` with
+    /// the problem and solver as read-only accounts, followed by the remaining
+    /// accounts. It accepts by returning `true`; returning `false` or failing
+    /// rejects the solution. Since the solution is public once sent, verifiers
+    /// should check something bound to `solver` where copying matters.
+    pub fn verify_solution(
+        ctx: Context<VerifySolution>,
+        problem_id: u64,
+        solution: Vec<u8>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let solver = ctx.accounts.solver.key();
+        let verifier = ctx.accounts.verifier_program.key();
+
+        {
+            let problem = &ctx.accounts.problem;
+            require!(
+                problem.judging_mode == JudgingMode::Verifier,
+                ErrorCode::InvalidJudgingMode
+            );
+            require!(
+                problem.state == ProblemState::Active,
+                ErrorCode::ProblemNotActive
+            );
+            require!(now < problem.deadline, ErrorCode::DeadlinePassed);
+            require!(
+                problem.verifier == Some(verifier),
+                ErrorCode::InvalidVerifier
+            );
+        }
+
+        let mut data = hashv(&[b"global:verify"]).to_bytes()[..8].to_vec();
+        VerifyArgs {
+            problem_id,
+            solver,
+            solution: solution.clone(),
+        }
+        .serialize(&mut data)?;
+
+        // Every account is passed read-only and unsigned, so the verifier can
+        // read them but can't act for the solver or touch the escrow.
+        let mut accounts = vec![
+            AccountMeta::new_readonly(ctx.accounts.problem.key(), false),
+            AccountMeta::new_readonly(solver, false),
+        ];
+        let mut infos = vec![
+            ctx.accounts.problem.to_account_info(),
+            ctx.accounts.solver.to_account_info(),
+        ];
+        for account in ctx.remaining_accounts {
+            accounts.push(AccountMeta::new_readonly(account.key(), false));
+            infos.push(account.clone());
+        }
+        infos.push(ctx.accounts.verifier_program.to_account_info());
+
+        invoke(
+            &Instruction {
+                program_id: verifier,
+                accounts,
+                data,
+            },
+            &infos,
+        )?;
+
+        let accepted = match get_return_data() {
+            Some((program_id, data)) if program_id == verifier => {
+                bool::try_from_slice(&data).unwrap_or(false)
+            }
+            _ => false,
+        };
+        require!(accepted, ErrorCode::SolutionRejected);
+
+        let problem = &mut ctx.accounts.problem;
+        let amount = problem.escrow_amount;
+        release_escrow(
+            &problem.to_account_info(),
+            &ctx.accounts.solver.to_account_info(),
+            amount,
+        )?;
+
+        problem.escrow_amount = 0;
+        problem.winner = Some(solver);
+        problem.state = ProblemState::Settled;
+        problem.judged_at = now;
+
+        emit!(SolutionVerifiedEvent {
+            problem_id,
+            solver,
+            verifier,
+            solution,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Writes `data` at `offset` into one chunk of a problem's content,
+    /// replacing anything after it, so a chunk can be filled over several
+    /// transactions. Content is only writable until the problem is created.
+    pub fn write_problem_content(
+        ctx: Context<WriteProblemContent>,
+        problem_id: u64,
+        index: u8,
+        offset: u32,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.problem.data_is_empty(),
+            ErrorCode::ProblemContentSealed
+        );
+        require!(
+            (index as usize) < MAX_CONTENT_CHUNKS,
+            ErrorCode::InvalidProblemContent
+        );
+
+        let chunk = &mut ctx.accounts.problem_content;
+        chunk.bump = ctx.bumps.problem_content;
+        chunk.problem_id = problem_id;
+        chunk.creator = ctx.accounts.creator.key();
+        chunk.index = index;
+
+        let offset = offset as usize;
+        require!(offset <= chunk.data.len(), ErrorCode::InvalidContentOffset);
+        require!(
+            offset + data.len() <= CONTENT_CHUNK_LEN,
+            ErrorCode::ContentTooLong
+        );
+        chunk.data.truncate(offset);
+        chunk.data.extend_from_slice(&data);
+
+        emit!(ProblemContentWrittenEvent {
+            problem_id,
+            creator: chunk.creator,
+            index,
+            len: chunk.data.len() as u32,
+        });
+
+        Ok(())
+    }
+}
+
+#[queue_computation_accounts("initialize_problem", creator)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct CreateProblem<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = creator,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(
+            versioned_comp_def_offset("initialize_problem", CIRCUIT_VERSION)
+        )
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Problem::INIT_SPACE,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + JudgingPanel::INIT_SPACE,
+        seeds = [b"judges", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub judging_panel: Option<Account<'info, JudgingPanel>>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + SubmissionRegistry::INIT_SPACE,
+        seeds = [b"submissions", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, SubmissionRegistry>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator_profile", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+}
+
+#[callback_accounts("initialize_problem")]
+#[derive(Accounts)]
+pub struct InitializeProblemCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "initialize_problem")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    #[account(mut)]
+    pub registry: Account<'info, SubmissionRegistry>,
+}
+
+#[queue_computation_accounts("submit_solution", solver)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct SubmitSolution<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = solver,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("submit_solution"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = solver,
+        space = 8 + Solution::INIT_SPACE,
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump
+    )]
+    pub solution: Account<'info, Solution>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + SolutionIndexPage::INIT_SPACE,
+        seeds = [
+            b"solution_index",
+            problem_id.to_le_bytes().as_ref(),
+            (problem.total_solutions / SOLUTIONS_PER_PAGE).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub solution_index_page: Account<'info, SolutionIndexPage>,
+    #[account(
+        mut,
+        seeds = [b"submissions", problem_id.to_le_bytes().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, SubmissionRegistry>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + SolverProfile::INIT_SPACE,
+        seeds = [b"solver_profile", solver.key().as_ref()],
+        bump
+    )]
+    pub solver_profile: Account<'info, SolverProfile>,
+    // An interface account, so holdings of a Token-2022 mint gate a problem
+    // the same way as those of a legacy one.
+    pub eligibility_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    // The Metaplex metadata of `eligibility_token`'s mint, for collection rules.
+    pub eligibility_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("submit_solution")]
+#[derive(Accounts)]
+pub struct SubmitSolutionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "submit_solution")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    #[account(mut)]
+    pub registry: Account<'info, SubmissionRegistry>,
+    #[account(mut)]
+    pub solution: Account<'info, Solution>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct CloseProblem<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"judges", problem_id.to_le_bytes().as_ref()],
+        bump = judging_panel.bump
+    )]
+    pub judging_panel: Option<Account<'info, JudgingPanel>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator_profile", problem.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct UpdateProblem<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct CancelProblem<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+}
+
+#[queue_computation_accounts("pay_winner", solver)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct PayWinner<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = solver,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("pay_winner"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump = solution.bump
+    )]
+    pub solution: Account<'info, Solution>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + SolverProfile::INIT_SPACE,
+        seeds = [b"solver_profile", solver.key().as_ref()],
+        bump
+    )]
+    pub solver_profile: Account<'info, SolverProfile>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [b"vesting", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump
+    )]
+    pub vesting: Option<Account<'info, VestingSchedule>>,
+    #[account(
+        seeds = [BADGE_AUTHORITY_SEED],
+        bump
+    )]
+    /// CHECK: badge_authority, a data-less PDA that signs badge mints.
+    pub badge_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        seeds = [b"badge", problem_id.to_le_bytes().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = badge_authority,
+        mint::token_program = token_program,
+        extensions::non_transferable,
+        extensions::metadata_pointer::authority = badge_authority,
+        extensions::metadata_pointer::metadata_address = badge_mint,
+    )]
+    pub badge_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        associated_token::mint = badge_mint,
+        associated_token::authority = solver,
+        associated_token::token_program = token_program,
+    )]
+    pub badge_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        mut,
+        seeds = [b"season", problem.season_id.unwrap_or_default().to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + SeasonEntry::INIT_SPACE,
+        seeds = [
+            b"season_entry",
+            problem.season_id.unwrap_or_default().to_le_bytes().as_ref(),
+            solver.key().as_ref()
+        ],
+        bump
+    )]
+    pub season_entry: Option<Account<'info, SeasonEntry>>,
+}
+
+#[callback_accounts("pay_winner")]
+#[derive(Accounts)]
+pub struct PayWinnerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "pay_winner")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub solution: Account<'info, Solution>,
+    #[account(mut)]
+    pub solver_profile: Account<'info, SolverProfile>,
+    #[account(mut)]
+    pub vesting: Option<Account<'info, VestingSchedule>>,
+    #[account(
+        seeds = [BADGE_AUTHORITY_SEED],
+        bump
+    )]
+    /// CHECK: badge_authority, a data-less PDA that signs badge mints.
+    pub badge_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub badge_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub badge_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token2022>,
+    #[account(mut)]
+    pub season: Option<Account<'info, Season>>,
+    #[account(mut)]
+    pub season_entry: Option<Account<'info, SeasonEntry>>,
+}
+
+#[init_computation_definition_accounts("initialize_problem", payer)]
+#[derive(Accounts)]
+pub struct InitInitializeProblemCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_INITIALIZE_PROBLEM.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("submit_solution", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitSolutionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_SUBMIT_SOLUTION.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("pay_winner", payer)]
+#[derive(Accounts)]
+pub struct InitPayWinnerCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_PAY_WINNER.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[queue_computation_accounts("initialize_categorical_problem", creator)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct CreateCategoricalMarket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = creator,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("initialize_categorical_problem"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + CategoricalMarket::INIT_SPACE,
+        seeds = [b"categorical", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub market: Account<'info, CategoricalMarket>,
+}
+
+#[callback_accounts("initialize_categorical_problem")]
+#[derive(Accounts)]
+pub struct InitializeCategoricalProblemCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "initialize_categorical_problem")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, CategoricalMarket>,
+}
+
+#[queue_computation_accounts("submit_categorical_solution", solver)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct SubmitCategoricalSolution<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = solver,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("submit_categorical_solution"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"categorical", problem_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, CategoricalMarket>,
+    #[account(
+        init,
+        payer = solver,
+        space = 8 + Solution::INIT_SPACE,
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump
+    )]
+    pub solution: Account<'info, Solution>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + SolutionIndexPage::INIT_SPACE,
+        seeds = [
+            b"solution_index",
+            problem_id.to_le_bytes().as_ref(),
+            (problem.total_solutions / SOLUTIONS_PER_PAGE).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub solution_index_page: Account<'info, SolutionIndexPage>,
+}
+
+#[callback_accounts("submit_categorical_solution")]
+#[derive(Accounts)]
+pub struct SubmitCategoricalSolutionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "submit_categorical_solution")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, CategoricalMarket>,
+    #[account(mut)]
+    pub solution: Account<'info, Solution>,
+}
+
+#[queue_computation_accounts("submit_categorical_vote", voter)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct SubmitCategoricalVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = voter,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("submit_categorical_vote"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"categorical", problem_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, CategoricalMarket>,
+    #[account(
+        mut,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    // The panel's voted bits stop a second vote; the record survives an
+    // aborted one so the juror can vote again.
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote", problem_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+#[callback_accounts("submit_categorical_vote")]
+#[derive(Accounts)]
+pub struct SubmitCategoricalVoteCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "submit_categorical_vote")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, CategoricalMarket>,
+    #[account(mut)]
+    pub panel: Account<'info, JuryPanel>,
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+#[queue_computation_accounts("resolve_categorical_market", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct ResolveCategoricalMarket<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("resolve_categorical_market"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"categorical", problem_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, CategoricalMarket>,
+}
+
+#[callback_accounts("resolve_categorical_market")]
+#[derive(Accounts)]
+pub struct ResolveCategoricalMarketCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "resolve_categorical_market")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    #[account(mut)]
+    pub market: Account<'info, CategoricalMarket>,
+}
+
+#[queue_computation_accounts("pay_categorical_winner", solver)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct PayCategoricalWinner<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = solver,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("pay_categorical_winner"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"categorical", problem_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, CategoricalMarket>,
+    #[account(
+        mut,
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump = solution.bump
+    )]
+    pub solution: Account<'info, Solution>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + SolverProfile::INIT_SPACE,
+        seeds = [b"solver_profile", solver.key().as_ref()],
+        bump
+    )]
+    pub solver_profile: Account<'info, SolverProfile>,
+}
+
+#[callback_accounts("pay_categorical_winner")]
+#[derive(Accounts)]
+pub struct PayCategoricalWinnerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "pay_categorical_winner")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub solution: Account<'info, Solution>,
+    #[account(mut)]
+    pub solver_profile: Account<'info, SolverProfile>,
+}
+
+#[init_computation_definition_accounts("initialize_categorical_problem", payer)]
+#[derive(Accounts)]
+pub struct InitInitializeCategoricalProblemCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_INITIALIZE_CATEGORICAL_PROBLEM.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("submit_categorical_solution", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitCategoricalSolutionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_SUBMIT_CATEGORICAL_SOLUTION.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("submit_categorical_vote", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitCategoricalVoteCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_SUBMIT_CATEGORICAL_VOTE.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("resolve_categorical_market", payer)]
+#[derive(Accounts)]
+pub struct InitResolveCategoricalMarketCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_RESOLVE_CATEGORICAL_MARKET.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("pay_categorical_winner", payer)]
+#[derive(Accounts)]
+pub struct InitPayCategoricalWinnerCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_PAY_CATEGORICAL_WINNER.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("initialize_scoring", payer)]
+#[derive(Accounts)]
+pub struct InitInitializeScoringCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_INITIALIZE_SCORING.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("score_solution", payer)]
+#[derive(Accounts)]
+pub struct InitScoreSolutionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_SCORE_SOLUTION.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("reveal_best_solution", payer)]
+#[derive(Accounts)]
+pub struct InitRevealBestSolutionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_REVEAL_BEST_SOLUTION.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("initialize_bid_board", payer)]
+#[derive(Accounts)]
+pub struct InitInitializeBidBoardCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_INITIALIZE_BID_BOARD.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("submit_bid", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitBidCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_SUBMIT_BID.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("reveal_lowest_bid", payer)]
+#[derive(Accounts)]
+pub struct InitRevealLowestBidCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_REVEAL_LOWEST_BID.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("draw_raffle_winner", payer)]
+#[derive(Accounts)]
+pub struct InitDrawRaffleWinnerCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_DRAW_RAFFLE_WINNER.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("initialize_confidential_bounty", payer)]
+#[derive(Accounts)]
+pub struct InitInitializeConfidentialBountyCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_INITIALIZE_CONFIDENTIAL_BOUNTY.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("pay_confidential_bounty", payer)]
+#[derive(Accounts)]
+pub struct InitPayConfidentialBountyCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_PAY_CONFIDENTIAL_BOUNTY.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("submit_quorum_vote", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitQuorumVoteCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_SUBMIT_QUORUM_VOTE.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("resolve_market", payer)]
+#[derive(Accounts)]
+pub struct InitResolveMarketCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_RESOLVE_MARKET.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("check_juror_vote", payer)]
+#[derive(Accounts)]
+pub struct InitCheckJurorVoteCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_CHECK_JUROR_VOTE.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("draw_jury", payer)]
+#[derive(Accounts)]
+pub struct InitDrawJuryCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_DRAW_JURY.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("initialize_judge_tally", payer)]
+#[derive(Accounts)]
+pub struct InitInitializeJudgeTallyCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_INITIALIZE_JUDGE_TALLY.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[init_computation_definition_accounts("submit_judge_pick", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitJudgePickCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CircuitRecord::INIT_SPACE,
+        seeds = [b"circuit", COMP_DEF_OFFSET_SUBMIT_JUDGE_PICK.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_record: Account<'info, CircuitRecord>,
+}
+
+#[queue_computation_accounts("initialize_scoring", creator)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct EnableScoring<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = creator,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("initialize_scoring"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + ScoreBoard::INIT_SPACE,
+        seeds = [b"score_board", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub score_board: Account<'info, ScoreBoard>,
+}
+
+#[callback_accounts("initialize_scoring")]
+#[derive(Accounts)]
+pub struct InitializeScoringCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "initialize_scoring")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub score_board: Account<'info, ScoreBoard>,
+}
+
+#[queue_computation_accounts("score_solution", creator)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct ScoreSolution<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = creator,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("score_solution"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"score_board", problem_id.to_le_bytes().as_ref()],
+        bump = score_board.bump
+    )]
+    pub score_board: Account<'info, ScoreBoard>,
+    #[account(
+        mut,
+        constraint = solution.problem_id == problem_id @ ErrorCode::InvalidSolution
+    )]
+    pub solution: Account<'info, Solution>,
+}
+
+#[callback_accounts("score_solution")]
+#[derive(Accounts)]
+pub struct ScoreSolutionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "score_solution")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub score_board: Account<'info, ScoreBoard>,
+    #[account(mut)]
+    pub solution: Account<'info, Solution>,
+}
+
+#[queue_computation_accounts("reveal_best_solution", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct CloseScoredProblem<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("reveal_best_solution"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"score_board", problem_id.to_le_bytes().as_ref()],
+        bump = score_board.bump
+    )]
+    pub score_board: Account<'info, ScoreBoard>,
+    #[account(
+        constraint = solution.problem_id == problem_id @ ErrorCode::InvalidSolution
+    )]
+    pub solution: Account<'info, Solution>,
+}
+
+#[callback_accounts("reveal_best_solution")]
+#[derive(Accounts)]
+pub struct RevealBestSolutionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "reveal_best_solution")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    pub solution: Account<'info, Solution>,
+}
+
+#[queue_computation_accounts("initialize_bid_board", creator)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct OpenSealedBidding<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = creator,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("initialize_bid_board"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BidBoard::INIT_SPACE,
+        seeds = [b"bid_board", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bid_board: Account<'info, BidBoard>,
+}
+
+#[callback_accounts("initialize_bid_board")]
+#[derive(Accounts)]
+pub struct InitializeBidBoardCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "initialize_bid_board")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub bid_board: Account<'info, BidBoard>,
+}
+
+#[queue_computation_accounts("submit_bid", solver)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct SubmitBid<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = solver,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("submit_bid"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"bid_board", problem_id.to_le_bytes().as_ref()],
+        bump = bid_board.bump
+    )]
+    pub bid_board: Account<'info, BidBoard>,
+    #[account(
+        init,
+        payer = solver,
+        space = 8 + Solution::INIT_SPACE,
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump
+    )]
+    pub solution: Account<'info, Solution>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + SolutionIndexPage::INIT_SPACE,
+        seeds = [
+            b"solution_index",
+            problem_id.to_le_bytes().as_ref(),
+            (problem.total_solutions / SOLUTIONS_PER_PAGE).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub solution_index_page: Account<'info, SolutionIndexPage>,
+}
+
+#[callback_accounts("submit_bid")]
+#[derive(Accounts)]
+pub struct SubmitBidCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "submit_bid")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub bid_board: Account<'info, BidBoard>,
+}
+
+#[queue_computation_accounts("reveal_lowest_bid", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct CloseSealedBid<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("reveal_lowest_bid"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"bid_board", problem_id.to_le_bytes().as_ref()],
+        bump = bid_board.bump
+    )]
+    pub bid_board: Account<'info, BidBoard>,
+    #[account(
+        mut,
+        address = problem.creator @ ErrorCode::UnauthorizedAccess
+    )]
+    pub creator: SystemAccount<'info>,
+}
+
+#[callback_accounts("reveal_lowest_bid")]
+#[derive(Accounts)]
+pub struct RevealLowestBidCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "reveal_lowest_bid")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct PaySealedBidWinner<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        address = problem.creator @ ErrorCode::UnauthorizedAccess
+    )]
+    pub creator: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump = solution.bump
+    )]
+    pub solution: Account<'info, Solution>,
+    #[account(mut)]
+    pub solver: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SolverProfile::INIT_SPACE,
+        seeds = [b"solver_profile", solver.key().as_ref()],
+        bump
+    )]
+    pub solver_profile: Account<'info, SolverProfile>,
+    #[account(
+        seeds = [BADGE_AUTHORITY_SEED],
+        bump
+    )]
+    /// CHECK: badge_authority, a data-less PDA that signs badge mints.
+    pub badge_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"badge", problem_id.to_le_bytes().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = badge_authority,
+        mint::token_program = token_program,
+        extensions::non_transferable,
+        extensions::metadata_pointer::authority = badge_authority,
+        extensions::metadata_pointer::metadata_address = badge_mint,
+    )]
+    pub badge_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = badge_mint,
+        associated_token::authority = solver,
+        associated_token::token_program = token_program,
+    )]
+    pub badge_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        mut,
+        seeds = [b"season", problem.season_id.unwrap_or_default().to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SeasonEntry::INIT_SPACE,
+        seeds = [
+            b"season_entry",
+            problem.season_id.unwrap_or_default().to_le_bytes().as_ref(),
+            solver.key().as_ref()
+        ],
+        bump
+    )]
+    pub season_entry: Option<Account<'info, SeasonEntry>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct EnableRaffle<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Raffle::INIT_SPACE,
+        seeds = [b"raffle", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub raffle: Account<'info, Raffle>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct MarkSolutionValid<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"raffle", problem_id.to_le_bytes().as_ref()],
+        bump = raffle.bump
+    )]
+    pub raffle: Account<'info, Raffle>,
+    #[account(
+        mut,
+        constraint = solution.problem_id == problem_id @ ErrorCode::InvalidSolution
+    )]
+    pub solution: Account<'info, Solution>,
+}
+
+#[queue_computation_accounts("draw_raffle_winner", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct DrawWinner<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("draw_raffle_winner"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"raffle", problem_id.to_le_bytes().as_ref()],
+        bump = raffle.bump
+    )]
+    pub raffle: Account<'info, Raffle>,
+}
+
+#[callback_accounts("draw_raffle_winner")]
+#[derive(Accounts)]
+pub struct DrawRaffleWinnerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "draw_raffle_winner")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    pub raffle: Account<'info, Raffle>,
+}
+
+#[queue_computation_accounts("initialize_confidential_bounty", creator)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct CreateConfidentialProblem<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = creator,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("initialize_confidential_bounty"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Problem::INIT_SPACE,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + SubmissionRegistry::INIT_SPACE,
+        seeds = [b"submissions", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, SubmissionRegistry>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator_profile", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+}
+
+#[callback_accounts("initialize_confidential_bounty")]
+#[derive(Accounts)]
+pub struct InitializeConfidentialBountyCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "initialize_confidential_bounty")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    #[account(mut)]
+    pub registry: Account<'info, SubmissionRegistry>,
+}
+
+#[queue_computation_accounts("pay_confidential_bounty", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct PayConfidentialBounty<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("pay_confidential_bounty"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        constraint = problem.winner == Some(winner.key()) @ ErrorCode::UnauthorizedAccess
+    )]
+    pub winner: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = problem.creator @ ErrorCode::UnauthorizedAccess
+    )]
+    pub creator: SystemAccount<'info>,
+}
+
+#[callback_accounts("pay_confidential_bounty")]
+#[derive(Accounts)]
+pub struct PayConfidentialBountyCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "pay_confidential_bounty")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    #[account(mut)]
+    pub winner: SystemAccount<'info>,
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct TopUpComputeBudget<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct WithdrawComputeBudget<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterJuror<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Juror::INIT_SPACE,
+        seeds = [b"juror", authority.key().as_ref()],
+        bump
+    )]
+    pub juror: Account<'info, Juror>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + JurorPool::INIT_SPACE,
+        seeds = [b"juror_pool"],
+        bump
+    )]
+    pub juror_pool: Account<'info, JurorPool>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LeaveJurorPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"juror", authority.key().as_ref()],
+        bump = juror.bump
+    )]
+    pub juror: Account<'info, Juror>,
+    #[account(
+        mut,
+        seeds = [b"juror_pool"],
+        bump = juror_pool.bump
+    )]
+    pub juror_pool: Account<'info, JurorPool>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct AssignJurors<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"juror_pool"],
+        bump = juror_pool.bump
+    )]
+    pub juror_pool: Account<'info, JurorPool>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + JuryPanel::INIT_SPACE,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            problem.jury_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("draw_jury", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct DrawJury<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("draw_jury"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"juror_pool"],
+        bump = juror_pool.bump
+    )]
+    pub juror_pool: Account<'info, JurorPool>,
+    #[account(
+        mut,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+}
+
+#[callback_accounts("draw_jury")]
+#[derive(Accounts)]
+pub struct DrawJuryCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "draw_jury")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub panel: Account<'info, JuryPanel>,
+    #[account(
+        seeds = [b"juror_pool"],
+        bump = juror_pool.bump
+    )]
+    pub juror_pool: Account<'info, JurorPool>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct SeatJurors<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"juror_pool"],
+        bump = juror_pool.bump
+    )]
+    pub juror_pool: Account<'info, JurorPool>,
+    #[account(
+        mut,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+}
+
+#[queue_computation_accounts("submit_quorum_vote", juror)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct SubmitJurorVote<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = juror,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("submit_quorum_vote"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    #[account(
+        init_if_needed,
+        payer = juror,
+        space = 8 + JurorVote::INIT_SPACE,
+        seeds = [b"juror_vote", problem_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub juror_vote: Account<'info, JurorVote>,
+}
+
+#[callback_accounts("submit_quorum_vote")]
+#[derive(Accounts)]
+pub struct SubmitQuorumVoteCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "submit_quorum_vote")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub panel: Account<'info, JuryPanel>,
+}
+
+#[queue_computation_accounts("resolve_market", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct ResolveJury<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("resolve_market"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+}
+
+#[callback_accounts("resolve_market")]
+#[derive(Accounts)]
+pub struct ResolveMarketCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "resolve_market")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub panel: Account<'info, JuryPanel>,
+}
+
+#[queue_computation_accounts("check_juror_vote", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct SettleJurorVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("check_juror_vote"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    #[account(
+        mut,
+        seeds = [b"juror", juror_vote.juror.as_ref()],
+        bump = juror.bump
+    )]
+    pub juror: Account<'info, Juror>,
+    #[account(
+        seeds = [b"juror_vote", problem_id.to_le_bytes().as_ref(), juror_vote.juror.as_ref()],
+        bump = juror_vote.bump
+    )]
+    pub juror_vote: Account<'info, JurorVote>,
+}
+
+#[callback_accounts("check_juror_vote")]
+#[derive(Accounts)]
+pub struct CheckJurorVoteCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "check_juror_vote")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub panel: Account<'info, JuryPanel>,
+    #[account(mut)]
+    pub juror: Account<'info, Juror>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct SettleJuror<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    #[account(
+        mut,
+        seeds = [b"juror", juror.authority.as_ref()],
+        bump = juror.bump
+    )]
+    pub juror: Account<'info, Juror>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct CloseJury<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        close = funder,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            panel.index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    #[account(
+        mut,
+        address = panel.funder @ ErrorCode::UnauthorizedAccess
+    )]
+    /// CHECK: funder, only credited with the rent and the unpaid reward pool
+    pub funder: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct SetDisputeWindow<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct DisputeWinner<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    // Only solvers of this problem may challenge its winner.
+    #[account(
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), challenger.key().as_ref()],
+        bump = solution.bump
+    )]
+    pub solution: Account<'info, Solution>,
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [
+            b"dispute",
+            problem_id.to_le_bytes().as_ref(),
+            problem.dispute_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        seeds = [b"juror_pool"],
+        bump = juror_pool.bump
+    )]
+    pub juror_pool: Account<'info, JurorPool>,
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + JuryPanel::INIT_SPACE,
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            problem.jury_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct ResolveDispute<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    // Resolved disputes are closed, handing the rent back to the challenger.
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [
+            b"dispute",
+            problem_id.to_le_bytes().as_ref(),
+            problem.dispute_count.saturating_sub(1).to_le_bytes().as_ref()
+        ],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        seeds = [
+            b"jury",
+            problem_id.to_le_bytes().as_ref(),
+            dispute.panel_index.to_le_bytes().as_ref()
+        ],
+        bump = panel.bump
+    )]
+    pub panel: Account<'info, JuryPanel>,
+    #[account(
+        mut,
+        address = dispute.challenger @ ErrorCode::UnauthorizedAccess
+    )]
+    pub challenger: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = dispute.disputed_winner @ ErrorCode::UnauthorizedAccess
+    )]
+    pub winner: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = problem.creator @ ErrorCode::UnauthorizedAccess
+    )]
+    /// CHECK: creator, only credited with the unslashed share of the creator bond
+    pub creator: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SolverProfile::INIT_SPACE,
+        seeds = [b"solver_profile", challenger.key().as_ref()],
+        bump
+    )]
+    pub challenger_profile: Account<'info, SolverProfile>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SolverProfile::INIT_SPACE,
+        seeds = [b"solver_profile", winner.key().as_ref()],
+        bump
+    )]
+    pub winner_profile: Account<'info, SolverProfile>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator_profile", problem.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    pub system_program: Program<'info, System>,
+}
+#[queue_computation_accounts("initialize_judge_tally", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct OpenJudgingPanel<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("initialize_judge_tally"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"judges", problem_id.to_le_bytes().as_ref()],
+        bump = judging_panel.bump
+    )]
+    pub judging_panel: Account<'info, JudgingPanel>,
+}
+
+#[callback_accounts("initialize_judge_tally")]
+#[derive(Accounts)]
+pub struct InitializeJudgeTallyCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "initialize_judge_tally")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub judging_panel: Account<'info, JudgingPanel>,
+}
+
+#[queue_computation_accounts("submit_judge_pick", judge)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, problem_id: u64)]
+pub struct SubmitJudgePick<'info> {
+    #[account(mut)]
+    pub judge: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = judge,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(problem.comp_def_offset("submit_judge_pick"))
+            @ ErrorCode::CircuitVersionMismatch
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"judges", problem_id.to_le_bytes().as_ref()],
+        bump = judging_panel.bump
+    )]
+    pub judging_panel: Account<'info, JudgingPanel>,
+}
+
+#[callback_accounts("submit_judge_pick")]
+#[derive(Accounts)]
+pub struct SubmitJudgePickCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = is_versioned_comp_def(&comp_def_account.key(), "submit_judge_pick")
+            @ ErrorCode::InvalidComputationDefinition
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub problem: Account<'info, Problem>,
+    #[account(mut)]
+    pub judging_panel: Account<'info, JudgingPanel>,
+}
+#[derive(Accounts)]
+#[instruction(multisig_id: u64)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Multisig::INIT_SPACE,
+        seeds = [b"multisig", multisig_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct AssignProblemToMultisig<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"multisig", multisig.multisig_id.to_le_bytes().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.multisig_id.to_le_bytes().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == multisig.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = member,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            b"proposal",
+            multisig.key().as_ref(),
+            multisig.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ApproveProposal<'info> {
+    pub member: Signer<'info>,
+    #[account(
+        seeds = [b"multisig", multisig.multisig_id.to_le_bytes().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), index.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64, index: u64)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    #[account(
+        seeds = [b"multisig", multisig.multisig_id.to_le_bytes().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.problem_id == problem_id @ ErrorCode::UnauthorizedAccess
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == multisig.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        seeds = [b"judges", problem_id.to_le_bytes().as_ref()],
+        bump = judging_panel.bump
+    )]
+    pub judging_panel: Option<Account<'info, JudgingPanel>>,
+    #[account(
+        mut,
+        seeds = [b"milestones", problem_id.to_le_bytes().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Option<Account<'info, MilestonePlan>>,
+    #[account(mut)]
+    /// CHECK: recipient, matched against the approved action's recipient
+    pub recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator_profile", problem.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    pub system_program: Program<'info, System>,
+}
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct ReturnCreatorBond<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct SlashMissedReview<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        address = problem.creator @ ErrorCode::UnauthorizedAccess
+    )]
+    /// CHECK: creator, only credited with the unslashed share of the creator bond
+    pub creator: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct ClaimBondShare<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump = solution.bump
+    )]
+    pub solution: Account<'info, Solution>,
+}
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"vesting", problem_id.to_le_bytes().as_ref(), beneficiary.key().as_ref()],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, VestingSchedule>,
+}
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct CreateSeason<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [b"season", season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The season authority and the problem's creator both sign, so a season only
+/// scores problems both sides agreed to include.
+#[derive(Accounts)]
+#[instruction(season_id: u64, problem_id: u64)]
+pub struct AddProblemToSeason<'info> {
+    pub authority: Signer<'info>,
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"season", season_id.to_le_bytes().as_ref()],
+        bump = season.bump,
+        has_one = authority
+    )]
+    pub season: Account<'info, Season>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        has_one = creator
+    )]
+    pub problem: Account<'info, Problem>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct FundSeason<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"season", season_id.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct FinalizeSeason<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"season", season_id.to_le_bytes().as_ref()],
+        bump = season.bump,
+        has_one = authority
+    )]
+    pub season: Account<'info, Season>,
+    /// CHECK: authority, receives unclaimed shares; matched by `has_one`.
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+}
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct CreateBountyTemplate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BountyTemplate::INIT_SPACE,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub template: Account<'info, BountyTemplate>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct FundBountyTemplate<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = template.bump
+    )]
+    pub template: Account<'info, BountyTemplate>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("initialize_problem", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, template_id: u64, problem_id: u64)]
+pub struct SpawnRound<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(
+            versioned_comp_def_offset("initialize_problem", CIRCUIT_VERSION)
+        )
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
     pub pool_account: Account<'info, FeePool>,
     #[account(
         address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
@@ -349,363 +9488,1851 @@ pub struct CreateProblem<'info> {
     pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = template.bump
+    )]
+    pub template: Account<'info, BountyTemplate>,
     #[account(
         init,
-        payer = creator,
+        payer = payer,
         space = 8 + Problem::INIT_SPACE,
         seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
         bump
     )]
     pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SubmissionRegistry::INIT_SPACE,
+        seeds = [b"submissions", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, SubmissionRegistry>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator_profile", template.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+}
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct CloseBountyTemplate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = template.bump,
+        has_one = creator
+    )]
+    pub template: Account<'info, BountyTemplate>,
+}
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct CreateMilestonePlan<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + MilestonePlan::INIT_SPACE,
+        seeds = [b"milestones", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub milestone_plan: Account<'info, MilestonePlan>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64, solver: Pubkey)]
+pub struct SelectMilestoneSolver<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"milestones", problem_id.to_le_bytes().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Account<'info, MilestonePlan>,
+    // Only someone who actually submitted to the problem can be selected.
+    #[account(
+        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.as_ref()],
+        bump = solution.bump
+    )]
+    pub solution: Account<'info, Solution>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct SubmitMilestone<'info> {
+    pub solver: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"milestones", problem_id.to_le_bytes().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Account<'info, MilestonePlan>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct ApproveMilestone<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"milestones", problem_id.to_le_bytes().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Account<'info, MilestonePlan>,
+    /// CHECK: solver, the selected solver being paid; checked against the plan.
+    #[account(mut)]
+    pub solver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct RefundMilestones<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        seeds = [b"milestones", problem_id.to_le_bytes().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Account<'info, MilestonePlan>,
+}
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct CommitPuzzleSolution<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    // Recommitting overwrites the earlier commitment and restarts its slot.
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + PuzzleCommit::INIT_SPACE,
+        seeds = [b"puzzle_commit", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump
+    )]
+    pub puzzle_commit: Account<'info, PuzzleCommit>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct RevealPuzzleSolution<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    #[account(
+        mut,
+        close = solver,
+        seeds = [b"puzzle_commit", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
+        bump = puzzle_commit.bump
+    )]
+    pub puzzle_commit: Account<'info, PuzzleCommit>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct RefundUnsolvedPuzzle<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct RefundConfidentialBounty<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump,
+        constraint = problem.creator == creator.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub problem: Account<'info, Problem>,
+}
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct VerifySolution<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump = problem.bump
+    )]
+    pub problem: Account<'info, Problem>,
+    /// CHECK: verifier_program, matched against the problem's verifier.
+    #[account(executable)]
+    pub verifier_program: UncheckedAccount<'info>,
+}
+#[derive(Accounts)]
+#[instruction(problem_id: u64, index: u8)]
+pub struct WriteProblemContent<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// CHECK: problem, only checked to not exist yet.
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub problem: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + ProblemContent::INIT_SPACE,
+        seeds = [
+            b"problem_content",
+            problem_id.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            index.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub problem_content: Account<'info, ProblemContent>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Problem {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub creator: Pubkey,
+
+    #[max_len(200)]
+    pub title: String,
+
+    #[max_len(MAX_DESCRIPTION_LEN)]
+    pub description: String,
+
+    pub deadline: i64,
+
+    pub bounty_amount: u64,
+
+    pub state: ProblemState,
+
+    pub market_kind: MarketKind,
+
+    pub judging_mode: JudgingMode,
+
+    pub bounty_mode: BountyMode,
+
+    pub eligibility: EligibilityRule,
+
+    pub circuit_version: u8,
+
+    pub escrow_amount: u64,
+
+    /// The share of `escrow_amount` `pay_winner` hands the winner on top of
+    /// their payout; only rounds funded from a template vault set it.
+    pub escrowed_bounty: u64,
+
+    pub compute_budget: u64,
+
+    pub mpc_fees: u64,
+
+    pub total_bounty: u64,
+
+    pub total_solutions: u64,
+
+    pub yes_pool: u64,
+
+    pub no_pool: u64,
+
+    pub winner: Option<Pubkey>,
+
+    pub winning_index: Option<u64>,
+
+    pub winning_bid: u64,
+
+    pub dispute_window: i64,
+
+    /// Disputes opened so far; the next `Dispute` is seeded by this count.
+    pub dispute_count: u64,
+
+    /// Jury panels opened so far; the next `JuryPanel` is seeded by this count,
+    /// so an old panel never stands in the way of a new dispute.
+    pub jury_count: u64,
+
+    pub closed_at: i64,
+
+    pub creator_bond: u64,
+
+    pub bond_slash_bps: u16,
+
+    pub review_window: i64,
+
+    pub bond_status: BondStatus,
+
+    pub bond_slashed: u64,
+
+    pub judged_at: i64,
+
+    pub vesting_cliff: i64,
+
+    pub vesting_duration: i64,
+
+    pub season_id: Option<u64>,
+
+    pub puzzle_hash: Option<[u8; 32]>,
+
+    pub verifier: Option<Pubkey>,
+
+    pub content_hash: Option<[u8; 32]>,
+
+    pub content_chunks: u8,
+
+    pub encrypted_data: [[u8; 32]; 2],
+
+    pub nonce: u128,
+}
+
+impl Problem {
+    /// Offset of the comp def that runs `circuit` for this problem, at the
+    /// circuit version it was created under.
+    pub fn comp_def_offset(&self, circuit: &str) -> u32 {
+        versioned_comp_def_offset(circuit, self.circuit_version)
+    }
+
+    /// Whether `solution` won, by pubkey for creator-judged problems and by
+    /// revealed index for scored ones.
+    pub fn is_winner(&self, solution: &Solution) -> bool {
+        self.winner == Some(solution.solver) || self.winning_index == Some(solution.index)
+    }
+
+    /// Whether a creator-picked winner can still be challenged at `now`.
+    pub fn in_dispute_window(&self, now: i64) -> bool {
+        self.closed_at > 0 && now < self.closed_at.saturating_add(self.dispute_window)
+    }
+
+    /// Whether the creator let the review window after the deadline run out,
+    /// either by judging late or by not judging at all as of `now`.
+    pub fn missed_review(&self, now: i64) -> bool {
+        let review_deadline = self.deadline.saturating_add(self.review_window);
+        if self.judged_at > 0 {
+            self.judged_at > review_deadline
+        } else {
+            self.state == ProblemState::Active && now > review_deadline
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Solution {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub index: u64,
+
+    pub solver: Pubkey,
+
+    pub amount: u64,
+
+    pub encrypted_solution: [u8; 32],
+
+    pub solution_pubkey: [u8; 32],
+
+    pub solution_nonce: u128,
+
+    pub timestamp: i64,
+
+    pub paid: bool,
+
+    /// A `pay_winner` computation is in flight; cleared by its callback.
+    pub payout_pending: bool,
+
+    pub payout_amount: u64,
+
+    pub scored: bool,
+
+    pub valid: bool,
+
+    pub duplicate: bool,
+
+    pub bond_claimed: bool,
+}
+
+/// Encrypted content hashes of a problem's first `MAX_TRACKED_SUBMISSIONS`
+/// submissions, opened with the problem and filled in by `submit_solution`.
+#[account]
+#[derive(InitSpace)]
+pub struct SubmissionRegistry {
+    pub bump: u8,
+
+    pub encrypted_hashes: [[u8; 32]; MAX_TRACKED_SUBMISSIONS],
+
+    pub nonce: u128,
+
+    pub problem_id: u64,
+
+    /// A `submit_solution` computation is in flight; cleared by its callback.
+    pub pending: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CategoricalMarket {
+    pub bump: u8,
+
+    pub encrypted_stats: [[u8; 32]; CATEGORICAL_STATS_LEN],
+
+    pub nonce: u128,
+
+    pub problem_id: u64,
+
+    pub num_outcomes: u8,
+
+    pub quorum_size: u8,
+
+    pub total_votes: u8,
+
+    pub resolved: bool,
+
+    pub winning_outcome: u8,
+
+    pub winning_votes: u8,
+
+    pub winning_pool: u64,
+
+    pub total_pool: u64,
+
+    /// A computation writing `encrypted_stats` is in flight; cleared by its callback.
+    pub pending: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub voter: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ScoreBoard {
+    pub bump: u8,
+
+    pub encrypted_board: [[u8; 32]; SCORE_BOARD_LEN],
+
+    pub nonce: u128,
+
+    pub problem_id: u64,
+
+    pub scored_count: u64,
+
+    /// A computation writing `encrypted_board` is in flight; cleared by its callback.
+    pub pending: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BidBoard {
+    pub bump: u8,
+
+    pub encrypted_board: [[u8; 32]; BID_BOARD_LEN],
+
+    pub nonce: u128,
+
+    pub problem_id: u64,
+
+    pub bid_count: u64,
+
+    /// A computation writing `encrypted_board` is in flight; cleared by its callback.
+    pub pending: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Raffle {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    #[max_len(MAX_RAFFLE_ENTRANTS)]
+    pub entrants: Vec<Pubkey>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CircuitRecord {
+    pub bump: u8,
+
+    pub comp_def_offset: u32,
+
+    pub version: u8,
+
+    #[max_len(MAX_CIRCUIT_SOURCE_LEN)]
+    pub source: String,
+
+    pub hash: [u8; 32],
+
+    pub registered_at: i64,
+}
+
+impl CircuitRecord {
+    /// Records where version `version` of a circuit, behind `comp_def_offset`,
+    /// was loaded from. An empty `source` means the circuit was uploaded on-chain.
+    pub fn register(
+        &mut self,
+        bump: u8,
+        comp_def_offset: u32,
+        version: u8,
+        circuit_source: &Option<OffChainSource>,
+    ) -> Result<()> {
+        require!(
+            (1..=CIRCUIT_VERSION).contains(&version),
+            ErrorCode::CircuitVersionMismatch
+        );
+
+        let (source, hash) = match circuit_source {
+            Some(off_chain) => {
+                require!(
+                    !off_chain.source.is_empty()
+                        && off_chain.source.len() <= MAX_CIRCUIT_SOURCE_LEN,
+                    ErrorCode::InvalidCircuitSource
+                );
+                (off_chain.source.clone(), off_chain.hash)
+            }
+            None => (String::new(), [0; 32]),
+        };
+
+        self.bump = bump;
+        self.comp_def_offset = comp_def_offset;
+        self.version = version;
+        self.source = source;
+        self.hash = hash;
+        self.registered_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OffChainSource {
+    pub source: String,
+
+    pub hash: [u8; 32],
+}
+
+impl OffChainSource {
+    pub fn into_circuit_source(self) -> CircuitSource {
+        CircuitSource::OffChain(OffChainCircuitSource {
+            source: self.source,
+            hash: self.hash,
+        })
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct JurorPool {
+    pub bump: u8,
+
+    #[max_len(MAX_POOL_JURORS)]
+    pub jurors: Vec<Pubkey>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Juror {
+    pub bump: u8,
+
+    pub authority: Pubkey,
+
+    pub stake: u64,
+
+    pub active_panels: u32,
+
+    pub rewards_earned: u64,
+
+    pub amount_slashed: u64,
+}
+
+impl Juror {
+    pub fn slash_amount(&self) -> u64 {
+        (self.stake as u128 * JUROR_SLASH_BPS as u128 / 10_000) as u64
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct JuryPanel {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub index: u64,
+
+    /// Paid the juror fee and the rent, and gets back what the majority doesn't take.
+    pub funder: Pubkey,
+
+    #[max_len(MAX_PANEL_SIZE)]
+    pub jurors: Vec<Pubkey>,
+
+    pub panel_size: u8,
+
+    /// The creator, the disputed winner and the challenger, who can't be drawn.
+    #[max_len(3)]
+    pub excluded: Vec<Pubkey>,
+
+    /// A `draw_jury` computation is in flight; cleared by its callback.
+    pub draw_pending: bool,
+
+    pub seated: bool,
+
+    pub quorum_size: u8,
+
+    pub vote_deadline: i64,
+
+    pub juror_fee: u64,
+
+    pub voted: u16,
+
+    pub settled: u16,
+
+    pub votes_cast: u8,
+
+    /// Seat whose vote is being tallied; cleared by its callback.
+    pub pending_seat: Option<u8>,
+
+    pub encrypted_tally: [[u8; 32]; 2],
+
+    pub nonce: u128,
+
+    pub resolved: bool,
+
+    pub outcome: bool,
+
+    pub yes_votes: u8,
+
+    pub no_votes: u8,
+
+    /// The juror fee plus the stake slashed from minority and absent jurors,
+    /// split between the majority by `close_jury`.
+    pub reward_pool: u64,
+
+    /// Seats that voted with the outcome, waiting on `close_jury` for their reward.
+    pub majority: u16,
+}
+
+impl JurorPool {
+    /// Pool members who aren't `excluded` and so could be drawn.
+    pub fn candidates(&self, excluded: &[Pubkey]) -> usize {
+        self.jurors
+            .iter()
+            .filter(|juror| !excluded.contains(juror))
+            .count()
+    }
+}
+
+impl JuryPanel {
+    /// Starts a panel with no jurors; `draw_jury` and `seat_jurors` fill it in.
+    pub fn open(
+        &mut self,
+        problem_id: u64,
+        funder: Pubkey,
+        excluded: Vec<Pubkey>,
+        panel_size: u8,
+        vote_deadline: i64,
+        juror_fee: u64,
+    ) {
+        self.problem_id = problem_id;
+        self.funder = funder;
+        self.jurors = Vec::new();
+        self.panel_size = panel_size;
+        self.excluded = excluded;
+        self.draw_pending = false;
+        self.seated = false;
+        self.quorum_size = 0;
+        self.vote_deadline = vote_deadline;
+        self.juror_fee = juror_fee;
+        self.voted = 0;
+        self.settled = 0;
+        self.votes_cast = 0;
+        self.pending_seat = None;
+        self.encrypted_tally = [[0; 32]; 2];
+        self.nonce = 0;
+        self.resolved = false;
+        self.outcome = false;
+        self.yes_votes = 0;
+        self.no_votes = 0;
+        self.reward_pool = juror_fee;
+        self.majority = 0;
+    }
+
+    pub fn seat(&self, juror: &Pubkey) -> Option<usize> {
+        self.jurors.iter().position(|seated| seated == juror)
+    }
+
+    pub fn has_voted(&self, seat: usize) -> bool {
+        self.voted & (1 << seat) != 0
+    }
+
+    pub fn is_settled(&self, seat: usize) -> bool {
+        self.settled & (1 << seat) != 0
+    }
+
+    pub fn all_settled(&self) -> bool {
+        (0..self.jurors.len()).all(|seat| self.is_settled(seat))
+    }
+
+    pub fn in_majority(&self, seat: usize) -> bool {
+        self.majority & (1 << seat) != 0
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct JurorVote {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub juror: Pubkey,
+
+    pub encrypted_vote: [u8; 32],
+
+    pub vote_pubkey: [u8; 32],
+
+    pub vote_nonce: u128,
+
+    pub timestamp: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub challenger: Pubkey,
+
+    pub disputed_winner: Pubkey,
+
+    pub bond: u64,
+
+    pub opened_at: i64,
+
+    /// Index of the `JuryPanel` opened for this dispute.
+    pub panel_index: u64,
+
+    /// When an unseated or unresolved panel counts as having missed quorum.
+    pub deadline: i64,
+
+    pub resolved: bool,
+
+    pub overturned: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct JudgingPanel {
+    pub bump: u8,
+
+    pub encrypted_tally: [[u8; 32]; MAX_JUDGE_CANDIDATES],
+
+    pub nonce: u128,
+
+    pub problem_id: u64,
+
+    #[max_len(MAX_JUDGES)]
+    pub judges: Vec<Pubkey>,
+
+    pub threshold: u8,
+
+    pub opened: bool,
+
+    pub voted: u16,
+
+    pub picks_cast: u8,
+
+    pub decided: bool,
+
+    /// Seat whose pick is in flight. Picks are tallied one at a time so each
+    /// computation starts from the tally the previous one left.
+    pub pending_seat: Option<u8>,
+}
+
+impl JudgingPanel {
+    pub fn seat(&self, judge: &Pubkey) -> Option<usize> {
+        self.judges.iter().position(|seated| seated == judge)
+    }
+
+    pub fn has_voted(&self, seat: usize) -> bool {
+        self.voted & (1 << seat) != 0
+    }
+
+    /// Every judge has picked and no solution reached the threshold.
+    pub fn is_deadlocked(&self) -> bool {
+        !self.decided
+            && self.pending_seat.is_none()
+            && self.picks_cast as usize == self.judges.len()
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Multisig {
+    pub bump: u8,
+
+    pub multisig_id: u64,
+
+    #[max_len(MAX_MULTISIG_MEMBERS)]
+    pub members: Vec<Pubkey>,
+
+    pub threshold: u8,
+
+    pub proposal_count: u64,
+}
+
+impl Multisig {
+    pub fn seat(&self, member: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|seated| seated == member)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub bump: u8,
+
+    pub multisig: Pubkey,
+
+    pub index: u64,
+
+    pub problem_id: u64,
+
+    pub action: ProposalAction,
+
+    pub proposer: Pubkey,
+
+    pub approvals: u16,
+
+    pub executed: bool,
+
+    pub created_at: i64,
+}
+
+impl Proposal {
+    pub fn has_approved(&self, seat: usize) -> bool {
+        self.approvals & (1 << seat) != 0
+    }
+}
+
+/// What an executed proposal does on the multisig's behalf. Every action that
+/// refunds the creator names the account the refund goes to, since the
+/// multisig itself can't spend lamports.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalAction {
+    CloseProblem { winner: Pubkey },
+
+    UpdateProblem { update: ProblemUpdate },
+
+    CancelProblem { recipient: Pubkey },
+
+    ReturnCreatorBond { recipient: Pubkey },
+
+    WithdrawComputeBudget { recipient: Pubkey },
+
+    RefundMilestones { recipient: Pubkey },
+
+    RefundUnsolvedPuzzle { recipient: Pubkey },
+
+    RefundConfidentialBounty { recipient: Pubkey },
+}
+
+impl ProposalAction {
+    pub fn recipient(&self) -> Option<Pubkey> {
+        match self {
+            Self::CloseProblem { .. } | Self::UpdateProblem { .. } => None,
+            Self::CancelProblem { recipient }
+            | Self::ReturnCreatorBond { recipient }
+            | Self::WithdrawComputeBudget { recipient }
+            | Self::RefundMilestones { recipient }
+            | Self::RefundUnsolvedPuzzle { recipient }
+            | Self::RefundConfidentialBounty { recipient } => Some(*recipient),
+        }
+    }
+}
+
+/// Fields `update_problem` changes; `None` leaves a field as it is.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ProblemUpdate {
+    #[max_len(200)]
+    pub title: Option<String>,
+
+    #[max_len(MAX_DESCRIPTION_LEN)]
+    pub description: Option<String>,
+
+    pub deadline: Option<i64>,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum EligibilityRule {
+    Open,
+
+    Allowlist { merkle_root: [u8; 32] },
+
+    TokenHolder { mint: Pubkey, min_amount: u64 },
+
+    NftCollection { collection: Pubkey },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SolverProfile {
+    pub bump: u8,
+
+    pub solver: Pubkey,
+
+    pub submissions: u64,
+
+    pub wins: u64,
+
+    pub total_earned: u64,
+
+    pub disputes_lost: u64,
+}
+
+impl SolverProfile {
+    pub fn record_payout(&mut self, won: bool, payout_amount: u64) {
+        if won {
+            self.wins += 1;
+        }
+        self.total_earned = self.total_earned.saturating_add(payout_amount);
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorProfile {
+    pub bump: u8,
+
+    pub creator: Pubkey,
+
+    pub bounties_posted: u64,
+
+    pub bounties_closed: u64,
+
+    pub bounties_paid_on_time: u64,
+
+    pub disputes_lost: u64,
+}
+
+impl CreatorProfile {
+    /// Counts a winner picked at `now`, on time if it came within
+    /// `ON_TIME_CLOSE_WINDOW` of the deadline.
+    pub fn record_close(&mut self, deadline: i64, now: i64) {
+        self.bounties_closed += 1;
+        if now <= deadline.saturating_add(ON_TIME_CLOSE_WINDOW) {
+            self.bounties_paid_on_time += 1;
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct CreatorBondTerms {
+    pub amount: u64,
+
+    pub slash_bps: u16,
+
+    pub review_window: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct VestingTerms {
+    pub cliff: i64,
+
+    pub duration: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub beneficiary: Pubkey,
+
+    pub total_amount: u64,
+
+    pub claimed_amount: u64,
+
+    pub start_time: i64,
+
+    pub cliff: i64,
+
+    pub duration: i64,
+}
+
+impl VestingSchedule {
+    /// Amount unlocked at `now`: nothing before the cliff, then linear from
+    /// `start_time` until the whole amount unlocks after `duration`.
+    pub fn unlocked_amount(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.start_time);
+        if self.start_time == 0 || elapsed < self.cliff {
+            0
+        } else if elapsed >= self.duration {
+            self.total_amount
+        } else {
+            (self.total_amount as u128 * elapsed as u128 / self.duration as u128) as u64
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct SeasonStanding {
+    pub solver: Pubkey,
+
+    pub points: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub bump: u8,
+
+    pub season_id: u64,
+
+    pub authority: Pubkey,
+
+    pub end_time: i64,
+
+    pub prize_pool: u64,
+
+    #[max_len(MAX_SEASON_WINNERS)]
+    pub prize_shares: Vec<u16>,
+
+    pub problem_count: u32,
+
+    #[max_len(MAX_SEASON_WINNERS)]
+    pub leaderboard: Vec<SeasonStanding>,
+
+    pub finalized: bool,
+}
+
+impl Season {
+    /// Updates `solver`'s standing to `points`, keeping only the top
+    /// `prize_shares.len()` solvers, best first. Ties keep the earlier entrant.
+    pub fn record_points(&mut self, solver: Pubkey, points: u64) {
+        match self
+            .leaderboard
+            .iter_mut()
+            .find(|standing| standing.solver == solver)
+        {
+            Some(standing) => standing.points = points,
+            None => self.leaderboard.push(SeasonStanding { solver, points }),
+        }
+
+        self.leaderboard.sort_by(|a, b| b.points.cmp(&a.points));
+        self.leaderboard.truncate(self.prize_shares.len());
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonEntry {
+    pub bump: u8,
+
+    pub season_id: u64,
+
+    pub solver: Pubkey,
+
+    pub points: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BountyTemplate {
+    pub bump: u8,
+
+    pub template_id: u64,
+
+    pub creator: Pubkey,
+
+    #[max_len(200)]
+    pub title: String,
+
+    #[max_len(MAX_DESCRIPTION_LEN)]
+    pub description: String,
+
+    pub bounty_amount: u64,
+
+    pub bounty_mode: BountyMode,
+
+    pub compute_budget: u64,
+
+    pub cadence: i64,
+
+    pub vault_balance: u64,
+
+    pub rounds: u64,
+
+    pub last_problem_id: Option<u64>,
+
+    pub last_deadline: i64,
+
+    pub active: bool,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MilestoneStatus {
+    Pending,
+    Submitted,
+    Approved,
+    Refunded,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct Milestone {
+    pub amount: u64,
+
+    pub deliverable: [u8; 32],
+
+    pub status: MilestoneStatus,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MilestonePlan {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub solver: Option<Pubkey>,
+
+    pub delivery_deadline: i64,
+
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+
+    pub released: u64,
+
+    pub refunded: u64,
+}
+
+impl MilestonePlan {
+    /// Index of the first milestone not yet approved, which is the only one
+    /// the solver may deliver.
+    pub fn next_milestone(&self) -> usize {
+        self.milestones
+            .iter()
+            .position(|milestone| milestone.status != MilestoneStatus::Approved)
+            .unwrap_or(self.milestones.len())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PuzzleCommit {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub solver: Pubkey,
+
+    pub commitment: [u8; 32],
+
+    pub committed_slot: u64,
+}
+
+/// Arguments of the `verify` instruction every verifier program implements.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VerifyArgs {
+    pub problem_id: u64,
+
+    pub solver: Pubkey,
+
+    pub solution: Vec<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct SolutionIndexEntry {
+    pub solver: Pubkey,
+
+    pub timestamp: i64,
+}
+
+/// One page of a problem's solutions in submission order, so clients can list
+/// them by walking pages `0..=total_solutions / SOLUTIONS_PER_PAGE` instead of
+/// scanning program accounts. Entry `i` of page `n` is solution index
+/// `n * SOLUTIONS_PER_PAGE + i`.
+#[account]
+#[derive(InitSpace)]
+pub struct SolutionIndexPage {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub page: u64,
+
+    #[max_len(SOLUTIONS_PER_PAGE)]
+    pub entries: Vec<SolutionIndexEntry>,
+}
+
+impl SubmissionRegistry {
+    /// Starts an empty registry; the problem's initializing computation
+    /// encrypts the hashes and clears `pending`.
+    pub fn open(&mut self, bump: u8, problem_id: u64) {
+        self.bump = bump;
+        self.encrypted_hashes = [[0; 32]; MAX_TRACKED_SUBMISSIONS];
+        self.nonce = 0;
+        self.problem_id = problem_id;
+        self.pending = true;
+    }
+}
+
+impl SolutionIndexPage {
+    /// Lists solution `index`, filling in the page's identity on first use.
+    pub fn append(
+        &mut self,
+        bump: u8,
+        problem_id: u64,
+        index: u64,
+        solver: Pubkey,
+        timestamp: i64,
+    ) {
+        self.bump = bump;
+        self.problem_id = problem_id;
+        self.page = index / SOLUTIONS_PER_PAGE;
+        self.entries.push(SolutionIndexEntry { solver, timestamp });
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProblemContent {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub creator: Pubkey,
+
+    pub index: u8,
+
+    #[max_len(CONTENT_CHUNK_LEN)]
+    pub data: Vec<u8>,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProblemState {
+    Active = 0,
+
+    Closed = 1,
+
+    Settled = 2,
+
+    Disputed = 3,
+
+    Cancelled = 4,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarketKind {
+    Binary = 0,
+
+    Categorical = 1,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum JudgingMode {
+    Creator = 0,
+
+    Scored = 1,
+
+    Raffle = 2,
+
+    Panel = 3,
+
+    Puzzle = 4,
+
+    Verifier = 5,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BondStatus {
+    None = 0,
+
+    Held = 1,
+
+    Returned = 2,
+
+    Slashed = 3,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BountyMode {
+    Standard = 0,
+
+    SealedBid = 1,
+
+    Confidential = 2,
 }
 
-#[callback_accounts("initialize_problem")]
-#[derive(Accounts)]
-pub struct InitializeProblemCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INITIALIZE_PROBLEM)
-    )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-    #[account(mut)]
-    pub problem: Account<'info, Problem>,
+#[event]
+pub struct ProblemPostedEvent {
+    pub problem_id: u64,
+    pub creator: Pubkey,
+    pub title: String,
+    pub deadline: i64,
+    pub bounty_amount: u64,
 }
 
-#[queue_computation_accounts("submit_solution", solver)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64, problem_id: u64)]
-pub struct SubmitSolution<'info> {
-    #[account(mut)]
-    pub solver: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = solver,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_SOLUTION)
-    )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Account<'info, Cluster>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Account<'info, FeePool>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        mut,
-        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
-        bump = problem.bump
-    )]
-    pub problem: Account<'info, Problem>,
-    #[account(
-        init,
-        payer = solver,
-        space = 8 + Solution::INIT_SPACE,
-        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
-        bump
-    )]
-    pub solution: Account<'info, Solution>,
+#[event]
+pub struct SolutionSubmittedEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
 }
 
-#[callback_accounts("submit_solution")]
-#[derive(Accounts)]
-pub struct SubmitSolutionCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_SOLUTION)
-    )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-    #[account(mut)]
-    pub problem: Account<'info, Problem>,
+#[event]
+pub struct PoolUpdatedEvent {
+    pub problem_id: u64,
+    pub yes_pool_delta: u64,
+    pub no_pool_delta: u64,
+    pub yes_pool: u64,
+    pub no_pool: u64,
 }
 
-#[derive(Accounts)]
-#[instruction(problem_id: u64)]
-pub struct CloseProblem<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
-        bump = problem.bump,
-        constraint = problem.creator == authority.key() @ ErrorCode::UnauthorizedAccess
-    )]
-    pub problem: Account<'info, Problem>,
+#[event]
+pub struct ProblemClosedEvent {
+    pub problem_id: u64,
+    pub winner: Pubkey,
 }
 
-#[queue_computation_accounts("pay_winner", solver)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64, problem_id: u64)]
-pub struct PayWinner<'info> {
-    #[account(mut)]
-    pub solver: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = solver,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_PAY_WINNER)
-    )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Account<'info, Cluster>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Account<'info, FeePool>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
-        bump = problem.bump
-    )]
-    pub problem: Account<'info, Problem>,
-    #[account(
-        mut,
-        seeds = [b"solution", problem_id.to_le_bytes().as_ref(), solver.key().as_ref()],
-        bump = solution.bump
-    )]
-    pub solution: Account<'info, Solution>,
+#[event]
+pub struct WinnerPaidEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub solution_amount: u64,
+    pub payout_amount: u64,
+    pub is_winner: bool,
+}
+
+#[event]
+pub struct CategoricalMarketCreatedEvent {
+    pub problem_id: u64,
+    pub num_outcomes: u8,
+    pub quorum_size: u8,
+}
+
+#[event]
+pub struct CategoricalVoteEvent {
+    pub problem_id: u64,
+    pub voter: Pubkey,
+    pub total_votes: u8,
+}
+
+#[event]
+pub struct CategoricalMarketResolvedEvent {
+    pub problem_id: u64,
+    pub winning_outcome: u8,
+    pub winning_votes: u8,
+    pub winning_pool: u64,
+    pub total_pool: u64,
+}
+
+#[event]
+pub struct ScoringEnabledEvent {
+    pub problem_id: u64,
+}
+
+#[event]
+pub struct SolutionScoredEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub scored_count: u64,
+}
+
+#[event]
+pub struct ScoredProblemClosedEvent {
+    pub problem_id: u64,
+    pub winner: Pubkey,
+    pub winning_index: u64,
+}
+
+#[event]
+pub struct SealedBidClosedEvent {
+    pub problem_id: u64,
+    pub has_winner: bool,
+    pub winning_index: u64,
+    pub winning_bid: u64,
+}
+
+#[event]
+pub struct BountyRefundedEvent {
+    pub problem_id: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SolutionMarkedValidEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub entrant_count: u64,
+}
+
+#[event]
+pub struct WinnerDrawnEvent {
+    pub problem_id: u64,
+    pub winner: Pubkey,
+    pub winner_index: u64,
+    pub entrant_count: u64,
+}
+
+#[event]
+pub struct ConfidentialBountyPaidEvent {
+    pub problem_id: u64,
+    pub winner: Pubkey,
+    pub bounty_amount: u64,
+    pub capped: bool,
+}
+
+#[event]
+pub struct DuplicateSolutionEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub index: u64,
 }
 
-#[callback_accounts("pay_winner")]
-#[derive(Accounts)]
-pub struct PayWinnerCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_PAY_WINNER)
-    )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-    #[account(mut)]
-    pub solution: Account<'info, Solution>,
+#[event]
+pub struct MpcFeeReimbursedEvent {
+    pub problem_id: u64,
+    pub payer: Pubkey,
+    pub fee: u64,
+    pub reimbursed: u64,
+    pub remaining_budget: u64,
 }
 
-#[init_computation_definition_accounts("initialize_problem", payer)]
-#[derive(Accounts)]
-pub struct InitInitializeProblemCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct ComputationAbortedEvent {
+    pub problem_id: u64,
+    pub circuit: String,
 }
 
-#[init_computation_definition_accounts("submit_solution", payer)]
-#[derive(Accounts)]
-pub struct InitSubmitSolutionCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct ComputeBudgetUpdatedEvent {
+    pub problem_id: u64,
+    pub remaining_budget: u64,
+    pub mpc_fees: u64,
 }
 
-#[init_computation_definition_accounts("pay_winner", payer)]
-#[derive(Accounts)]
-pub struct InitPayWinnerCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program. Can't check it here as it's not initialized yet.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct JurorRegisteredEvent {
+    pub juror: Pubkey,
+    pub stake: u64,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct Problem {
-    pub bump: u8,
+#[event]
+pub struct JurorLeftEvent {
+    pub juror: Pubkey,
+    pub stake: u64,
+}
 
+#[event]
+pub struct JurorsDrawnEvent {
     pub problem_id: u64,
+    pub jurors: Vec<Pubkey>,
+}
 
-    pub creator: Pubkey,
+#[event]
+pub struct JurorsAssignedEvent {
+    pub problem_id: u64,
+    pub jurors: Vec<Pubkey>,
+    pub quorum_size: u8,
+    pub vote_deadline: i64,
+}
 
-    #[max_len(200)]
-    pub title: String,
+#[event]
+pub struct JurorVoteEvent {
+    pub problem_id: u64,
+    pub juror: Pubkey,
+    pub votes_cast: u8,
+}
 
-    #[max_len(200)]
-    pub description: String,
+#[event]
+pub struct JuryResolvedEvent {
+    pub problem_id: u64,
+    pub outcome: bool,
+    pub yes_votes: u8,
+    pub no_votes: u8,
+}
 
-    pub deadline: i64,
+#[event]
+pub struct JurorSettledEvent {
+    pub problem_id: u64,
+    pub juror: Pubkey,
+    pub voted: bool,
+    pub sided_with_outcome: bool,
+    pub reward: u64,
+    pub slashed: u64,
+}
 
-    pub bounty_amount: u64,
+#[event]
+pub struct JurorRewardedEvent {
+    pub problem_id: u64,
+    pub juror: Pubkey,
+    pub reward: u64,
+}
 
-    pub state: ProblemState,
+#[event]
+pub struct DisputeOpenedEvent {
+    pub problem_id: u64,
+    pub challenger: Pubkey,
+    pub disputed_winner: Pubkey,
+    pub bond: u64,
+}
 
-    pub total_bounty: u64,
+#[event]
+pub struct DisputeResolvedEvent {
+    pub problem_id: u64,
+    pub challenger: Pubkey,
+    pub overturned: bool,
+    pub bond_returned: bool,
+}
 
-    pub total_solutions: u64,
+#[event]
+pub struct JudgingPanelNamedEvent {
+    pub problem_id: u64,
+    pub judges: Vec<Pubkey>,
+    pub threshold: u8,
+}
 
-    pub winner: Option<Pubkey>,
+#[event]
+pub struct JudgePickSubmittedEvent {
+    pub problem_id: u64,
+    pub judge: Pubkey,
+    pub picks_cast: u8,
+}
 
-    pub encrypted_data: [[u8; 32]; 2],
+#[event]
+pub struct JudgingPanelDecidedEvent {
+    pub problem_id: u64,
+    pub winning_index: u64,
+    pub picks_cast: u8,
+}
 
-    pub nonce: u128,
+#[event]
+pub struct MultisigCreatedEvent {
+    pub multisig: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct Solution {
-    pub bump: u8,
+#[event]
+pub struct ProblemAssignedToMultisigEvent {
+    pub problem_id: u64,
+    pub previous_creator: Pubkey,
+    pub multisig: Pubkey,
+}
+
+#[event]
+pub struct ProposalCreatedEvent {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub problem_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct ProposalApprovedEvent {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub member: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub problem_id: u64,
+}
 
+#[event]
+pub struct ProblemUpdatedEvent {
     pub problem_id: u64,
+    pub title: String,
+    pub deadline: i64,
+}
 
-    pub solver: Pubkey,
+#[event]
+pub struct ProblemCancelledEvent {
+    pub problem_id: u64,
+    pub refunded: u64,
+}
 
+#[event]
+pub struct CreatorBondReturnedEvent {
+    pub problem_id: u64,
     pub amount: u64,
+}
 
-    pub encrypted_solution: [u8; 32],
+#[event]
+pub struct CreatorBondSlashedEvent {
+    pub problem_id: u64,
+    pub slashed: u64,
+    pub refunded: u64,
+}
 
-    pub solution_pubkey: [u8; 32],
+#[event]
+pub struct BondShareClaimedEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub amount: u64,
+}
 
-    pub solution_nonce: u128,
+#[event]
+pub struct VestingStartedEvent {
+    pub problem_id: u64,
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub start_time: i64,
+    pub cliff: i64,
+    pub duration: i64,
+}
 
-    pub timestamp: i64,
+#[event]
+pub struct VestedClaimedEvent {
+    pub problem_id: u64,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub total_amount: u64,
+}
 
-    pub paid: bool,
+#[event]
+pub struct WinnerBadgeMintedEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub mint: Pubkey,
+}
 
-    pub payout_amount: u64,
+#[event]
+pub struct SeasonCreatedEvent {
+    pub season_id: u64,
+    pub authority: Pubkey,
+    pub end_time: i64,
+    pub prize_shares: Vec<u16>,
 }
 
-#[repr(u8)]
-#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
-pub enum ProblemState {
-    Active = 0,
+#[event]
+pub struct SeasonProblemAddedEvent {
+    pub season_id: u64,
+    pub problem_id: u64,
+}
 
-    Closed = 1,
+#[event]
+pub struct SeasonFundedEvent {
+    pub season_id: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub prize_pool: u64,
 }
 
 #[event]
-pub struct ProblemPostedEvent {
-    pub problem_id: u64,
+pub struct SeasonPointsAwardedEvent {
+    pub season_id: u64,
+    pub solver: Pubkey,
+    pub points: u64,
+    pub total_points: u64,
+}
+
+#[event]
+pub struct SeasonPrizePaidEvent {
+    pub season_id: u64,
+    pub solver: Pubkey,
+    pub rank: u8,
+    pub points: u64,
+    pub prize: u64,
+}
+
+#[event]
+pub struct SeasonFinalizedEvent {
+    pub season_id: u64,
+    pub paid_out: u64,
+    pub refunded: u64,
+}
+
+#[event]
+pub struct BountyTemplateCreatedEvent {
+    pub template_id: u64,
     pub creator: Pubkey,
     pub title: String,
-    pub deadline: i64,
     pub bounty_amount: u64,
+    pub cadence: i64,
 }
 
 #[event]
-pub struct SolutionSubmittedEvent {
+pub struct BountyTemplateFundedEvent {
+    pub template_id: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub vault_balance: u64,
+}
+
+#[event]
+pub struct RoundSpawnedEvent {
+    pub template_id: u64,
+    pub round: u64,
+    pub problem_id: u64,
+    pub deadline: i64,
+    pub vault_balance: u64,
+}
+
+#[event]
+pub struct BountyTemplateClosedEvent {
+    pub template_id: u64,
+    pub rounds: u64,
+    pub refunded: u64,
+}
+
+#[event]
+pub struct MilestonePlanCreatedEvent {
+    pub problem_id: u64,
+    pub amounts: Vec<u64>,
+    pub total: u64,
+    pub delivery_deadline: i64,
+}
+
+#[event]
+pub struct MilestoneSolverSelectedEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+}
+
+#[event]
+pub struct MilestoneSubmittedEvent {
+    pub problem_id: u64,
+    pub index: u8,
+    pub solver: Pubkey,
+    pub deliverable: [u8; 32],
+}
+
+#[event]
+pub struct MilestoneApprovedEvent {
     pub problem_id: u64,
+    pub index: u8,
     pub solver: Pubkey,
     pub amount: u64,
-    pub timestamp: i64,
+    pub released: u64,
 }
 
 #[event]
-pub struct ProblemClosedEvent {
+pub struct MilestonesRefundedEvent {
     pub problem_id: u64,
-    pub winner: Pubkey,
+    pub amount: u64,
+    pub refunded: u64,
 }
 
 #[event]
-pub struct WinnerPaidEvent {
+pub struct PuzzleCommittedEvent {
     pub problem_id: u64,
     pub solver: Pubkey,
-    pub solution_amount: u64,
-    pub payout_amount: u64,
-    pub is_winner: bool,
+    pub commitment: [u8; 32],
+}
+
+#[event]
+pub struct PuzzleSolvedEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub preimage: Vec<u8>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PuzzleRefundedEvent {
+    pub problem_id: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SolutionVerifiedEvent {
+    pub problem_id: u64,
+    pub solver: Pubkey,
+    pub verifier: Pubkey,
+    pub solution: Vec<u8>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProblemContentWrittenEvent {
+    pub problem_id: u64,
+    pub creator: Pubkey,
+    pub index: u8,
+    pub len: u32,
 }
 
 #[error_code]
@@ -734,4 +11361,186 @@ pub enum ErrorCode {
     AlreadyPaid,
     #[msg("Unauthorized access")]
     UnauthorizedAccess,
+    #[msg("Operation not supported for this market kind")]
+    InvalidMarketKind,
+    #[msg("Invalid number of outcomes")]
+    InvalidOutcomeCount,
+    #[msg("Invalid quorum size")]
+    InvalidQuorumSize,
+    #[msg("Market already resolved")]
+    MarketAlreadyResolved,
+    #[msg("Market not resolved")]
+    MarketNotResolved,
+    #[msg("Quorum not reached")]
+    QuorumNotReached,
+    #[msg("Operation not supported for this judging mode")]
+    InvalidJudgingMode,
+    #[msg("Solution already scored")]
+    AlreadyScored,
+    #[msg("No solutions have been scored")]
+    NoScoredSolutions,
+    #[msg("Solution is not the best scored solution")]
+    NotBestSolution,
+    #[msg("Solution does not belong to this problem")]
+    InvalidSolution,
+    #[msg("Operation not supported for this bounty mode")]
+    InvalidBountyMode,
+    #[msg("Escrow balance too low")]
+    InsufficientEscrow,
+    #[msg("Solution already marked valid")]
+    AlreadyMarkedValid,
+    #[msg("Raffle is full")]
+    RaffleFull,
+    #[msg("No valid solutions to draw from")]
+    NoRaffleEntrants,
+    #[msg("Bounty already released")]
+    BountyAlreadyReleased,
+    #[msg("Invalid off-chain circuit source")]
+    InvalidCircuitSource,
+    #[msg("Stake below the juror minimum")]
+    InsufficientStake,
+    #[msg("Juror pool is full")]
+    JurorPoolFull,
+    #[msg("Juror still sits on an unsettled panel")]
+    JurorBusy,
+    #[msg("Invalid panel size")]
+    InvalidPanelSize,
+    #[msg("Not enough eligible jurors")]
+    NotEnoughJurors,
+    #[msg("Not a juror on this panel")]
+    NotAJuror,
+    #[msg("Jurors for this panel have not been drawn")]
+    JuryNotDrawn,
+    #[msg("Jurors for this panel have already been drawn")]
+    JuryAlreadyDrawn,
+    #[msg("Jurors for this panel have not been seated")]
+    JuryNotSeated,
+    #[msg("Juror already voted")]
+    AlreadyVoted,
+    #[msg("Juror already settled")]
+    JurorAlreadySettled,
+    #[msg("Juror did not vote on this panel")]
+    JurorDidNotVote,
+    #[msg("Not every juror has settled")]
+    PanelNotSettled,
+    #[msg("Invalid dispute window")]
+    InvalidDisputeWindow,
+    #[msg("Dispute window is closed")]
+    DisputeWindowClosed,
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+    #[msg("Problem is not disputed")]
+    ProblemNotDisputed,
+    #[msg("Dispute already resolved")]
+    DisputeResolved,
+    #[msg("A dispute's panel is opened by dispute_winner")]
+    DisputePanelFixed,
+    #[msg("Invalid judging panel")]
+    InvalidJudgingPanel,
+    #[msg("Judging panel already opened")]
+    JudgingPanelOpened,
+    #[msg("Judging panel not opened")]
+    JudgingPanelNotOpened,
+    #[msg("Judge tally has not been initialized yet")]
+    JudgeTallyNotInitialized,
+    #[msg("Not a judge on this panel")]
+    NotAJudge,
+    #[msg("Judging panel has not deadlocked")]
+    PanelStillJudging,
+    #[msg("Invalid multisig members or threshold")]
+    InvalidMultisig,
+    #[msg("Problem already has solutions")]
+    ProblemHasSolutions,
+    #[msg("Recipient does not match the approved proposal")]
+    InvalidRecipient,
+    #[msg("This action needs the problem's milestone plan")]
+    MilestonePlanRequired,
+    #[msg("Not a member of this multisig")]
+    NotAMultisigMember,
+    #[msg("Member already approved")]
+    AlreadyApproved,
+    #[msg("Proposal already executed")]
+    ProposalExecuted,
+    #[msg("Not enough approvals")]
+    ApprovalThresholdNotReached,
+    #[msg("Solver is not eligible for this problem")]
+    NotEligible,
+    #[msg("Invalid creator bond terms")]
+    InvalidCreatorBond,
+    #[msg("No creator bond is held")]
+    CreatorBondNotHeld,
+    #[msg("Creator bond was not slashed")]
+    CreatorBondNotSlashed,
+    #[msg("Review window was missed")]
+    ReviewWindowMissed,
+    #[msg("Review window is still open")]
+    ReviewWindowOpen,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("Vesting has not started")]
+    VestingNotStarted,
+    #[msg("Nothing has vested since the last claim")]
+    NothingVested,
+    #[msg("Badge accounts must be passed for the winning solution only")]
+    InvalidBadgeAccounts,
+    #[msg("Invalid season")]
+    InvalidSeason,
+    #[msg("Season accounts do not match the problem's season")]
+    InvalidSeasonAccounts,
+    #[msg("Season has ended")]
+    SeasonEnded,
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+    #[msg("Season has already been finalized")]
+    SeasonFinalized,
+    #[msg("Invalid bounty template")]
+    InvalidTemplate,
+    #[msg("Bounty template is no longer active")]
+    TemplateInactive,
+    #[msg("Previous round is still open")]
+    RoundStillOpen,
+    #[msg("Invalid milestone plan")]
+    InvalidMilestonePlan,
+    #[msg("A solver has already been selected for the milestones")]
+    MilestoneSolverSelected,
+    #[msg("Milestones must be delivered in order")]
+    MilestoneOutOfOrder,
+    #[msg("Milestone has not been submitted")]
+    MilestoneNotSubmitted,
+    #[msg("Nothing left to refund")]
+    NothingToRefund,
+    #[msg("Invalid puzzle")]
+    InvalidPuzzle,
+    #[msg("Commitment must be made in an earlier slot than the reveal")]
+    CommitmentTooRecent,
+    #[msg("Revealed solution does not match the commitment")]
+    CommitmentMismatch,
+    #[msg("Revealed solution does not match the puzzle hash")]
+    WrongPuzzleSolution,
+    #[msg("Invalid verifier program")]
+    InvalidVerifier,
+    #[msg("Verifier rejected the solution")]
+    SolutionRejected,
+    #[msg("Description is longer than MAX_DESCRIPTION_LEN; use ProblemContent chunks")]
+    DescriptionTooLong,
+    #[msg("Invalid problem content chunks")]
+    InvalidProblemContent,
+    #[msg("Problem content does not match its hash")]
+    ContentHashMismatch,
+    #[msg("Problem content can't be written once the problem exists")]
+    ProblemContentSealed,
+    #[msg("Content offset is past the end of the chunk")]
+    InvalidContentOffset,
+    #[msg("Content is longer than CONTENT_CHUNK_LEN")]
+    ContentTooLong,
+    #[msg("The winner's badge has already been minted")]
+    BadgeAlreadyMinted,
+    #[msg("Computation definition is not for this problem's circuit version")]
+    CircuitVersionMismatch,
+    #[msg("Computation definition does not run this circuit")]
+    InvalidComputationDefinition,
+    #[msg("A computation for this account is already in flight")]
+    ComputationPending,
+    #[msg("No computation for this account is in flight")]
+    NoComputationPending,
 }
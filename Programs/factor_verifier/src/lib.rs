@@ -0,0 +1,217 @@
+/*
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+//! Example verifier for verifier-judged bounties.
+//!
+//! A creator posts a semiprime for their problem, and a solution is accepted
+//! when it decodes as two little-endian `u64` factors, both above one, whose
+//! product is that semiprime. The bounty program invokes `verify` with the
+//! problem and solver, followed by the `Semiprime` account and the solver's
+//! `FactorCommitment`.
+//!
+//! Factors travel in the clear, so a solver first commits to
+//! `hashv([solver, solution])` and only submits the solution in a later slot.
+//! Anyone copying a pending solution out of the mempool has no commitment to
+//! it under their own key, and would need a slot of their own to make one.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::pubkey;
+
+declare_id!("HnKjENBTHiW2DXcBFrDqb6M8EGZBzzdak6AYisBZRQqu");
+
+/// The bounty program whose problems this verifier judges.
+pub const BOUNTY_PROGRAM_ID: Pubkey = pubkey!("AMgZmVhB17SVSQAbhTHaZzHPurArHaJ7zJeLdcwKRhE2");
+
+/// Byte range of `Problem::creator`, after the discriminator, `bump` and `problem_id`.
+const PROBLEM_CREATOR_OFFSET: usize = 8 + 1 + 8;
+
+#[program]
+pub mod factor_verifier {
+    use super::*;
+
+    pub fn set_semiprime(ctx: Context<SetSemiprime>, problem_id: u64, target: u128) -> Result<()> {
+        require!(target > 3, ErrorCode::InvalidTarget);
+        // Only the bounty's own creator may say what it is asking for.
+        require!(
+            problem_creator(&ctx.accounts.problem)? == ctx.accounts.authority.key(),
+            ErrorCode::NotProblemCreator
+        );
+
+        let semiprime = &mut ctx.accounts.semiprime;
+        semiprime.bump = ctx.bumps.semiprime;
+        semiprime.problem_id = problem_id;
+        semiprime.authority = ctx.accounts.authority.key();
+        semiprime.target = target;
+
+        Ok(())
+    }
+
+    pub fn commit_factors(
+        ctx: Context<CommitFactors>,
+        problem_id: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        let factor_commitment = &mut ctx.accounts.commitment;
+        factor_commitment.bump = ctx.bumps.commitment;
+        factor_commitment.problem_id = problem_id;
+        factor_commitment.solver = ctx.accounts.solver.key();
+        factor_commitment.commitment = commitment;
+        factor_commitment.slot = Clock::get()?.slot;
+
+        Ok(())
+    }
+
+    pub fn verify(
+        ctx: Context<Verify>,
+        _problem_id: u64,
+        _solver: Pubkey,
+        solution: Vec<u8>,
+    ) -> Result<bool> {
+        let factor_commitment = &ctx.accounts.commitment;
+        if factor_commitment.slot >= Clock::get()?.slot
+            || factor_commitment.commitment != commitment_for(&ctx.accounts.solver.key(), &solution)
+        {
+            return Ok(false);
+        }
+
+        let Some((p, q)) = decode_factors(&solution) else {
+            return Ok(false);
+        };
+
+        Ok(p > 1 && q > 1 && p as u128 * q as u128 == ctx.accounts.semiprime.target)
+    }
+}
+
+/// The commitment a solver posts before submitting `solution`.
+pub fn commitment_for(solver: &Pubkey, solution: &[u8]) -> [u8; 32] {
+    hashv(&[solver.as_ref(), solution]).to_bytes()
+}
+
+/// Reads `creator` out of a bounty program `Problem` account.
+fn problem_creator(problem: &AccountInfo) -> Result<Pubkey> {
+    let data = problem.try_borrow_data()?;
+    let creator = data
+        .get(PROBLEM_CREATOR_OFFSET..PROBLEM_CREATOR_OFFSET + 32)
+        .ok_or(ErrorCode::NotProblemCreator)?;
+
+    Ok(Pubkey::try_from(creator).unwrap())
+}
+
+/// Splits a solution into its two factors, or `None` if it isn't 16 bytes.
+pub fn decode_factors(solution: &[u8]) -> Option<(u64, u64)> {
+    if solution.len() != 16 {
+        return None;
+    }
+
+    let p = u64::from_le_bytes(solution[..8].try_into().ok()?);
+    let q = u64::from_le_bytes(solution[8..].try_into().ok()?);
+
+    Some((p, q))
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct SetSemiprime<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: problem, the bounty program's problem account; its address and
+    /// owner are checked here and its creator in `set_semiprime`.
+    #[account(
+        seeds = [b"problem", problem_id.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = BOUNTY_PROGRAM_ID,
+        owner = BOUNTY_PROGRAM_ID
+    )]
+    pub problem: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Semiprime::INIT_SPACE,
+        seeds = [b"semiprime", problem_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub semiprime: Account<'info, Semiprime>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct CommitFactors<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + FactorCommitment::INIT_SPACE,
+        seeds = [
+            b"commitment",
+            problem_id.to_le_bytes().as_ref(),
+            solver.key().as_ref()
+        ],
+        bump
+    )]
+    pub commitment: Account<'info, FactorCommitment>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(problem_id: u64)]
+pub struct Verify<'info> {
+    /// CHECK: problem, the bounty program's problem account; unused here.
+    pub problem: UncheckedAccount<'info>,
+    /// CHECK: solver, the submitting solver; its commitment is derived from it.
+    pub solver: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"semiprime", problem_id.to_le_bytes().as_ref()],
+        bump = semiprime.bump
+    )]
+    pub semiprime: Account<'info, Semiprime>,
+    #[account(
+        seeds = [
+            b"commitment",
+            problem_id.to_le_bytes().as_ref(),
+            solver.key().as_ref()
+        ],
+        bump = commitment.bump
+    )]
+    pub commitment: Account<'info, FactorCommitment>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Semiprime {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub authority: Pubkey,
+
+    pub target: u128,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FactorCommitment {
+    pub bump: u8,
+
+    pub problem_id: u64,
+
+    pub solver: Pubkey,
+
+    pub commitment: [u8; 32],
+
+    pub slot: u64,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Target must be a product of two factors above one")]
+    InvalidTarget,
+    #[msg("Only the problem's creator can set its semiprime")]
+    NotProblemCreator,
+}
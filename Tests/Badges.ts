@@ -0,0 +1,130 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Winner badges: `pay_winner` mints exactly one non-transferable Token-2022
+// badge per problem to the winning solver.
+
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  Harness,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  badgeAccount,
+  expectError,
+  now,
+} from "./Harness";
+
+describe("Winner badges", () => {
+  const harness = new Harness();
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution", "pay_winner"]);
+  });
+
+  it("mints one badge to the winner and never a second", async () => {
+    const { winner, loser, problemId } = await closedProblem();
+    const mint = harness.badgeMint(problemId);
+
+    await harness.payWinner(problemId, winner, { isWinner: true });
+    await harness.payWinner(problemId, loser, { isWinner: false });
+
+    const supply = await harness.provider.connection.getTokenSupply(mint);
+    expect(supply.value.amount).to.equal("1");
+    const balance = await harness.provider.connection.getTokenAccountBalance(
+      badgeAccount(winner.publicKey, mint)
+    );
+    expect(balance.value.amount).to.equal("1");
+
+    // The payout can't be queued again, so neither can the mint.
+    await expectError(harness.payWinner(problemId, winner, { isWinner: true }), "AlreadyPaid");
+    const after = await harness.provider.connection.getTokenSupply(mint);
+    expect(after.value.amount).to.equal("1");
+  });
+
+  it("rejects transfers of a minted badge", async () => {
+    const { winner, loser, problemId } = await closedProblem();
+    const mint = harness.badgeMint(problemId);
+    await harness.payWinner(problemId, winner, { isWinner: true });
+
+    const source = badgeAccount(winner.publicKey, mint);
+    const destination = badgeAccount(loser.publicKey, mint);
+    const transfer = new Transaction().add(
+      createAssociatedTokenAccountIx(winner.publicKey, destination, loser.publicKey, mint),
+      transferCheckedIx(source, mint, destination, winner.publicKey, BigInt(1), 0)
+    );
+
+    // Token-2022 refuses with `NonTransferable` (0x25).
+    await expectError(
+      harness.provider.sendAndConfirm(transfer, [winner], { commitment: "confirmed" }),
+      "0x25"
+    );
+
+    const balance = await harness.provider.connection.getTokenAccountBalance(source);
+    expect(balance.value.amount).to.equal("1");
+  });
+
+  async function closedProblem() {
+    const deadline = now() + 30;
+    const { problemId } = await harness.createProblem({ deadline });
+    const [winner, loser] = await harness.funded(2);
+
+    await harness.submitSolution(problemId, winner, true, new anchor.BN(1_000));
+    await harness.submitSolution(problemId, loser, false, new anchor.BN(1_000));
+
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, winner.publicKey);
+
+    return { winner, loser, problemId };
+  }
+
+  function createAssociatedTokenAccountIx(
+    payer: PublicKey,
+    account: PublicKey,
+    owner: PublicKey,
+    mint: PublicKey
+  ) {
+    return new TransactionInstruction({
+      programId: ASSOCIATED_TOKEN_PROGRAM_ID,
+      keys: [
+        { pubkey: payer, isSigner: true, isWritable: true },
+        { pubkey: account, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: false, isWritable: false },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: anchor.web3.SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.alloc(0),
+    });
+  }
+
+  function transferCheckedIx(
+    source: PublicKey,
+    mint: PublicKey,
+    destination: PublicKey,
+    owner: PublicKey,
+    amount: bigint,
+    decimals: number
+  ) {
+    const data = Buffer.alloc(10);
+    data.writeUInt8(12, 0);
+    data.writeBigUInt64LE(amount, 1);
+    data.writeUInt8(decimals, 9);
+
+    return new TransactionInstruction({
+      programId: TOKEN_2022_PROGRAM_ID,
+      keys: [
+        { pubkey: source, isSigner: false, isWritable: true },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: destination, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: false },
+      ],
+      data,
+    });
+  }
+});
@@ -224,7 +224,17 @@ describe("Prediction Market", () => {
         description,
         new anchor.BN(resolutionDeadline),
         new anchor.BN(minStakeAmount),
-        new anchor.BN(deserializeLE(nonce).toString())
+        new anchor.BN(deserializeLE(nonce).toString()),
+        { standard: {} },
+        new anchor.BN(0),
+        [],
+        0,
+        { open: {} },
+        null,
+        null,
+        null,
+        null,
+        null
       )
       .accountsPartial({
         creator: owner.publicKey,
@@ -315,6 +325,8 @@ describe("Prediction Market", () => {
     const user1Prediction = true;
     const user1Nonce = randomBytes(16);
     const user1EncryptedPrediction = cipher.encrypt([BigInt(user1Prediction ? 1 : 0)], user1Nonce);
+    const user1HashNonce = randomBytes(16);
+    const user1EncryptedHash = cipher.encrypt([deserializeLE(randomBytes(16))], user1HashNonce);
 
     console.log("Encrypted prediction length:", user1EncryptedPrediction[0].length);
     console.log("Encrypted prediction:", Array.from(user1EncryptedPrediction[0]));
@@ -341,7 +353,10 @@ describe("Prediction Market", () => {
         new anchor.BN(user1BetAmount),
         Array.from(user1EncryptedPrediction[0]),
         Array.from(publicKey),
-        new anchor.BN(deserializeLE(user1Nonce).toString())
+        new anchor.BN(deserializeLE(user1Nonce).toString()),
+        Array.from(user1EncryptedHash[0]),
+        new anchor.BN(deserializeLE(user1HashNonce).toString()),
+        []
       )
       .accountsPartial({
         solver: user1.publicKey,
@@ -359,6 +374,14 @@ describe("Prediction Market", () => {
         ),
         problem: marketPDA,
         solution: user1BetPDA,
+        solutionIndexPage: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("solution_index"),
+            marketIdBuffer,
+            new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0],
       })
       .signers([user1])
       .rpc({ commitment: "confirmed" });
@@ -470,7 +493,17 @@ describe("Prediction Market", () => {
         description,
         new anchor.BN(deadline),
         new anchor.BN(minStakeAmount),
-        new anchor.BN(deserializeLE(nonce).toString())
+        new anchor.BN(deserializeLE(nonce).toString()),
+        { standard: {} },
+        new anchor.BN(0),
+        [],
+        0,
+        { open: {} },
+        null,
+        null,
+        null,
+        null,
+        null
       )
       .accountsPartial({
         creator: owner.publicKey,
@@ -529,7 +562,7 @@ describe("Prediction Market", () => {
 
     try {
       const sig = await program.methods
-        .initInitializeProblemCompDef()
+        .initInitializeProblemCompDef(null)
         .accounts({
           compDefAccount: compDefPDA,
           payer: owner.publicKey,
@@ -601,7 +634,7 @@ describe("Prediction Market", () => {
 
     try {
       const sig = await program.methods
-        .initSubmitSolutionCompDef()
+        .initSubmitSolutionCompDef(null)
         .accounts({
           compDefAccount: compDefPDA,
           payer: owner.publicKey,
@@ -664,7 +697,7 @@ describe("Prediction Market", () => {
 
     try {
       const sig = await program.methods
-        .initPayWinnerCompDef()
+        .initPayWinnerCompDef(null)
         .accounts({
           compDefAccount: compDefPDA,
           payer: owner.publicKey,
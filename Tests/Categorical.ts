@@ -0,0 +1,258 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Categorical markets: `create_categorical_market` turns a fresh problem into
+// an N-outcome market, solvers stake on an encrypted choice, a seated jury
+// votes an encrypted outcome after the deadline, and `pay_categorical_winner`
+// splits the total pool among the winning outcome's backers.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { deserializeLE } from "@arcium-hq/client";
+import { Harness, expectError, now, randomOffset } from "./Harness";
+
+describe("Categorical markets", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "submit_solution",
+      "draw_jury",
+      "initialize_categorical_problem",
+      "submit_categorical_solution",
+      "submit_categorical_vote",
+      "resolve_categorical_market",
+      "pay_categorical_winner",
+    ]);
+    await harness.registerJurors(2);
+  });
+
+  // Every panel is released after its vote deadline, so the jurors are free to leave.
+  after(async () => {
+    await harness.leaveJurorPool();
+  });
+
+  it("pays the winning outcome's backers pro rata from the total pool", async () => {
+    const deadline = now() + 90;
+    const { problemId, problemPDA } = await harness.createProblem({ deadline });
+    await createMarket(problemId, 3, 2);
+
+    // Outcome 1 is backed by 4_000 of the 5_000 staked.
+    const [loser, major, minor] = await harness.funded(3);
+    await submitChoice(problemId, loser, 0, 1_000);
+    await submitChoice(problemId, major, 1, 3_000);
+    await submitChoice(problemId, minor, 1, 1_000);
+    await expectError(harness.submitSolution(problemId, loser, true), "InvalidMarketKind");
+
+    const voteDeadline = deadline + 90;
+    const panel = await harness.assignJurors(problemId, {
+      panelSize: 2,
+      voteDeadline,
+      jurorFee: 0,
+    });
+    const [first, second] = (await harness.seatJury(problemId, panel)).map((juror) =>
+      harness.juror(juror)
+    );
+
+    await expectError(vote(problemId, panel, first, 1), "DeadlineNotReached");
+    await harness.waitUntil(deadline);
+    await vote(problemId, panel, first, 1);
+    await expectError(vote(problemId, panel, first, 1), "AlreadyVoted");
+    await expectError(resolve(problemId), "QuorumNotReached");
+    await expectError(queuePayout(problemId, major), "MarketNotResolved");
+
+    await vote(problemId, panel, second, 1);
+    await resolve(problemId);
+
+    const market = await program.account.categoricalMarket.fetch(marketAddress(problemId));
+    expect(market.resolved).to.equal(true);
+    expect(market.winningOutcome).to.equal(1);
+    expect(market.winningVotes).to.equal(2);
+    expect(market.winningPool.toNumber()).to.equal(4_000);
+    expect(market.totalPool.toNumber()).to.equal(5_000);
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ closed: {} });
+    await expectError(resolve(problemId), "ProblemNotActive");
+
+    expect(await pay(problemId, major)).to.deep.equal({ payoutAmount: 3_750, isWinner: true });
+    expect(await pay(problemId, minor)).to.deep.equal({ payoutAmount: 1_250, isWinner: true });
+    expect(await pay(problemId, loser)).to.deep.equal({ payoutAmount: 0, isWinner: false });
+    await expectError(queuePayout(problemId, major), "AlreadyPaid");
+
+    // Payouts are recorded on the solvers' profiles like binary ones.
+    const profile = await program.account.solverProfile.fetch(
+      harness.pda("solver_profile", major.publicKey)
+    );
+    expect(profile.wins.toNumber()).to.equal(1);
+    expect(profile.totalEarned.toNumber()).to.equal(3_750);
+    const solution = await program.account.solution.fetch(
+      harness.solutionAddress(problemId, major.publicKey)
+    );
+    expect(solution.paid).to.equal(true);
+
+    // Categorical voters aren't checked against an outcome; they are released
+    // once voting closes.
+    await harness.waitUntil(voteDeadline);
+    for (const juror of [first, second]) {
+      await settle(problemId, panel, juror.publicKey);
+    }
+    await harness.closeJury(problemId, panel);
+  });
+
+  it("only turns fresh problems with a valid outcome count into markets", async () => {
+    const { problemId } = await harness.createProblem({ deadline: now() + 60 });
+
+    await expectError(createMarket(problemId, 1, 1), "InvalidOutcomeCount");
+    await expectError(createMarket(problemId, 5, 1), "InvalidOutcomeCount");
+    await expectError(createMarket(problemId, 2, 0), "InvalidQuorumSize");
+
+    // Binary stakes already on the problem can't be reinterpreted as choices.
+    const [solver] = await harness.funded(1);
+    await harness.submitSolution(problemId, solver, true);
+    await expectError(createMarket(problemId, 2, 1), "InvalidMarketKind");
+  });
+
+  it("doesn't close a categorical market on the creator's pick", async () => {
+    const deadline = now() + 30;
+    const { problemId } = await harness.createProblem({ deadline });
+    await createMarket(problemId, 2, 1);
+
+    const [solver] = await harness.funded(1);
+    await submitChoice(problemId, solver, 0, 1_000);
+    await harness.waitUntil(deadline);
+
+    await expectError(harness.closeProblem(problemId, solver.publicKey), "InvalidMarketKind");
+  });
+
+  function marketAddress(problemId: anchor.BN) {
+    return harness.pda("categorical", problemId);
+  }
+
+  async function createMarket(problemId: anchor.BN, numOutcomes: number, quorumSize: number) {
+    const offset = randomOffset();
+    await program.methods
+      .createCategoricalMarket(
+        offset,
+        problemId,
+        numOutcomes,
+        quorumSize,
+        new anchor.BN(deserializeLE(randomBytes(16)).toString())
+      )
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "initialize_categorical_problem"),
+        problem: harness.problemAddress(problemId),
+        market: marketAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function submitChoice(
+    problemId: anchor.BN,
+    solver: Keypair,
+    choice: number,
+    amount: number
+  ) {
+    const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(choice)]);
+    const offset = randomOffset();
+
+    await program.methods
+      .submitCategoricalSolution(
+        offset,
+        problemId,
+        new anchor.BN(amount),
+        ciphertexts[0],
+        publicKey,
+        nonce
+      )
+      .accountsPartial({
+        solver: solver.publicKey,
+        ...harness.arciumAccounts(offset, "submit_categorical_solution"),
+        problem: harness.problemAddress(problemId),
+        market: marketAddress(problemId),
+        solutionIndexPage: harness.solutionIndexPage(problemId, 0),
+      })
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function vote(problemId: anchor.BN, panel: PublicKey, voter: Keypair, outcome: number) {
+    const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(outcome)]);
+    const offset = randomOffset();
+
+    await program.methods
+      .submitCategoricalVote(offset, problemId, ciphertexts[0], publicKey, nonce)
+      .accountsPartial({
+        voter: voter.publicKey,
+        ...harness.arciumAccounts(offset, "submit_categorical_vote"),
+        problem: harness.problemAddress(problemId),
+        market: marketAddress(problemId),
+        panel,
+        voteRecord: harness.pda("vote", problemId, voter.publicKey),
+      })
+      .signers([voter])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function resolve(problemId: anchor.BN) {
+    const offset = randomOffset();
+    await program.methods
+      .resolveCategoricalMarket(offset, problemId)
+      .accountsPartial({
+        authority: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "resolve_categorical_market"),
+        problem: harness.problemAddress(problemId),
+        market: marketAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function pay(problemId: anchor.BN, solver: Keypair) {
+    const paid = harness.awaitEvent("winnerPaidEvent");
+    await queuePayout(problemId, solver);
+
+    const event = await paid;
+    return { payoutAmount: event.payoutAmount.toNumber(), isWinner: event.isWinner };
+  }
+
+  async function queuePayout(problemId: anchor.BN, solver: Keypair) {
+    const offset = randomOffset();
+
+    await program.methods
+      .payCategoricalWinner(offset, problemId)
+      .accountsPartial({
+        solver: solver.publicKey,
+        ...harness.arciumAccounts(offset, "pay_categorical_winner"),
+        problem: harness.problemAddress(problemId),
+        market: marketAddress(problemId),
+        solution: harness.solutionAddress(problemId, solver.publicKey),
+      })
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  function settle(problemId: anchor.BN, panel: PublicKey, juror: PublicKey) {
+    return program.methods
+      .settleJuror(problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        panel,
+        juror: harness.pda("juror", juror),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
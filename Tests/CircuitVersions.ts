@@ -0,0 +1,63 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Circuit versioning: every `init_*_comp_def` records the circuit's version and
+// source in a `CircuitRecord`, new problems are pinned to `CIRCUIT_VERSION`,
+// and computations queued for a problem must use the comp def of the version
+// it was created under.
+
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { getCompDefAccAddress, getCompDefAccOffset } from "@arcium-hq/client";
+import { Harness, expectError, now } from "./Harness";
+
+// Mirrors `CIRCUIT_VERSION` in the program.
+const CIRCUIT_VERSION = 1;
+
+const CIRCUITS = ["initialize_problem", "submit_solution", "pay_winner"];
+
+describe("Circuit versions", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(CIRCUITS);
+  });
+
+  it("records the version and source of every registered circuit", async () => {
+    for (const circuit of CIRCUITS) {
+      const offset = Buffer.from(getCompDefAccOffset(circuit));
+      const record = await program.account.circuitRecord.fetch(harness.pda("circuit", offset));
+
+      expect(record.compDefOffset).to.equal(offset.readUInt32LE());
+      expect(record.version).to.equal(CIRCUIT_VERSION);
+      // The harness uploads circuits on-chain, so there is no off-chain source.
+      expect(record.source).to.equal("");
+      expect(record.hash).to.deep.equal(new Array(32).fill(0));
+    }
+  });
+
+  it("pins new problems to the current circuit version", async () => {
+    const { problemPDA } = await harness.createProblem();
+
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.circuitVersion).to.equal(CIRCUIT_VERSION);
+  });
+
+  it("refuses computations queued against another circuit's comp def", async () => {
+    const { problemId } = await harness.createProblem({ deadline: now() + 60 });
+    const [solver] = await harness.funded(1);
+
+    const payWinner = Buffer.from(getCompDefAccOffset("pay_winner")).readUInt32LE();
+    await expectError(
+      harness.submitSolution(problemId, solver, true, new anchor.BN(0), {
+        compDefAccount: getCompDefAccAddress(program.programId, payWinner),
+      }),
+      "CircuitVersionMismatch"
+    );
+  });
+});
@@ -0,0 +1,633 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Differential harness: replays the golden vectors produced by the plaintext
+// reference model (Arcium/circuits/EncryptedIxs/tests/differential.rs) against
+// the compiled circuits on localnet and compares every revealed output.
+// The empty quorum is only checked on the Rust side, since a panel nobody
+// voted on can't be resolved.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { deserializeLE } from "@arcium-hq/client";
+import * as fs from "fs";
+import { Harness, now, randomOffset } from "./Harness";
+
+const VECTORS = JSON.parse(
+  fs.readFileSync("Arcium/circuits/EncryptedIxs/tests/vectors.json", "utf8")
+);
+
+// Enough jurors to seat the largest panel a vector votes on.
+const JURORS = 3;
+
+type Payout = { payout_amount: string; won: boolean };
+
+type Scenario = {
+  name: string;
+  winner: number;
+  submissions: {
+    prediction: boolean;
+    amount: string;
+    yes_pool_delta: string;
+    no_pool_delta: string;
+  }[];
+  yes_pool: string;
+  no_pool: string;
+  payouts: Payout[];
+};
+
+type Quorum = {
+  votes: boolean[];
+  outcome: boolean;
+  yes_votes: number;
+  no_votes: number;
+  majority: boolean[];
+};
+
+type Categorical = {
+  name: string;
+  num_outcomes: number;
+  submissions: { choice: number; amount: string }[];
+  votes: number[];
+  winning_outcome: number;
+  winning_votes: number;
+  winning_pool: string;
+  total_pool: string;
+  payouts: Payout[];
+};
+
+type Scoring = { scores: string[]; best_index: string; has_score: boolean };
+
+type Bids = {
+  name: string;
+  max_price: string;
+  bids: string[];
+  lowest_bid: string;
+  lowest_index: string;
+  has_bid: boolean;
+};
+
+type ConfidentialBounty = {
+  bounty: string;
+  bounty_cap: string;
+  bounty_amount: string;
+  payout_amount: string;
+  refund_amount: string;
+  capped: boolean;
+};
+
+type SubmissionHashes = { hashes: string[]; duplicates: boolean[] };
+
+type JudgePicks = {
+  name: string;
+  num_candidates: number;
+  threshold: number;
+  picks: number[];
+  decisions: { winning_index: number; decided: boolean }[];
+};
+
+describe("Circuits match the reference model", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "submit_solution",
+      "pay_winner",
+      "draw_jury",
+      "submit_quorum_vote",
+      "resolve_market",
+      "check_juror_vote",
+      "initialize_categorical_problem",
+      "submit_categorical_solution",
+      "submit_categorical_vote",
+      "resolve_categorical_market",
+      "pay_categorical_winner",
+      "initialize_scoring",
+      "score_solution",
+      "reveal_best_solution",
+      "initialize_bid_board",
+      "submit_bid",
+      "reveal_lowest_bid",
+      "initialize_confidential_bounty",
+      "pay_confidential_bounty",
+      "initialize_judge_tally",
+      "submit_judge_pick",
+    ]);
+    await harness.registerJurors(JURORS);
+  });
+
+  // Every panel is settled and closed by its replay, so the jurors are free to leave.
+  after(async () => {
+    await harness.leaveJurorPool();
+  });
+
+  for (const scenario of VECTORS.scenarios as Scenario[]) {
+    it(`replays "${scenario.name}"`, async () => {
+      const deadline = now() + 30 + 15 * scenario.submissions.length;
+      const { problemId, problemPDA } = await harness.createProblem({
+        title: `Circuit vector: ${scenario.name}`,
+        deadline,
+      });
+
+      const solvers = await harness.funded(scenario.submissions.length);
+      for (const [i, submission] of scenario.submissions.entries()) {
+        const poolUpdated = harness.awaitEvent("poolUpdatedEvent");
+        await harness.submitSolution(
+          problemId,
+          solvers[i],
+          submission.prediction,
+          new anchor.BN(submission.amount)
+        );
+
+        const event = await poolUpdated;
+        expect(event.yesPoolDelta.toString()).to.equal(submission.yes_pool_delta);
+        expect(event.noPoolDelta.toString()).to.equal(submission.no_pool_delta);
+      }
+
+      // Every scenario fits on the first page of the solution index.
+      const index = await program.account.solutionIndexPage.fetch(
+        harness.solutionIndexPage(problemId, 0)
+      );
+      expect(index.entries.map((entry) => entry.solver.toBase58())).to.deep.equal(
+        solvers.map((solver) => solver.publicKey.toBase58())
+      );
+
+      const problem = await program.account.problem.fetch(problemPDA);
+      expect(problem.yesPool.toString()).to.equal(scenario.yes_pool);
+      expect(problem.noPool.toString()).to.equal(scenario.no_pool);
+
+      await harness.waitUntil(deadline);
+      await harness.closeProblem(problemId, solvers[scenario.winner].publicKey);
+
+      for (const [i, solver] of solvers.entries()) {
+        const paid = harness.awaitEvent("winnerPaidEvent");
+        await harness.payWinner(problemId, solver, { isWinner: i === scenario.winner });
+
+        const event = await paid;
+        expect(event.payoutAmount.toString()).to.equal(scenario.payouts[i].payout_amount);
+        expect(event.isWinner).to.equal(scenario.payouts[i].won);
+      }
+    });
+  }
+
+  for (const quorum of (VECTORS.quorums as Quorum[]).filter((q) => q.votes.length > 0)) {
+    it(`replays the jury vote [${quorum.votes}]`, async () => {
+      const { problemId } = await harness.createProblem();
+      const panel = await harness.assignJurors(problemId, {
+        panelSize: quorum.votes.length,
+        voteDeadline: now() + 600,
+        jurorFee: 0,
+      });
+
+      const drawn = await harness.seatJury(problemId, panel);
+      for (const [seat, juror] of drawn.entries()) {
+        await harness.castJurorVote(problemId, panel, harness.juror(juror), quorum.votes[seat]);
+      }
+      await harness.resolveJury(problemId, panel);
+
+      let jury = await program.account.juryPanel.fetch(panel);
+      expect(jury.outcome).to.equal(quorum.outcome);
+      expect(jury.yesVotes).to.equal(quorum.yes_votes);
+      expect(jury.noVotes).to.equal(quorum.no_votes);
+
+      await harness.settleJurorVotes(problemId, panel, drawn);
+      jury = await program.account.juryPanel.fetch(panel);
+      expect(drawn.map((_, seat) => (jury.majority & (1 << seat)) !== 0)).to.deep.equal(
+        quorum.majority
+      );
+      await harness.closeJury(problemId, panel);
+    });
+  }
+
+  for (const market of VECTORS.categorical as Categorical[]) {
+    it(`replays the categorical market "${market.name}"`, async () => {
+      const deadline = now() + 60 + 15 * market.submissions.length;
+      const { problemId } = await harness.createProblem({ deadline });
+      const marketPDA = harness.pda("categorical", problemId);
+
+      const offset = randomOffset();
+      await program.methods
+        .createCategoricalMarket(
+          offset,
+          problemId,
+          market.num_outcomes,
+          market.votes.length,
+          new anchor.BN(deserializeLE(randomBytes(16)).toString())
+        )
+        .accountsPartial({
+          creator: harness.owner.publicKey,
+          ...harness.arciumAccounts(offset, "initialize_categorical_problem"),
+          problem: harness.problemAddress(problemId),
+          market: marketPDA,
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(offset);
+
+      const solvers = await harness.funded(market.submissions.length);
+      for (const [i, submission] of market.submissions.entries()) {
+        await submitChoice(problemId, solvers[i], submission.choice, submission.amount);
+      }
+
+      // The panel is seated before the deadline and votes after it.
+      const voteDeadline = deadline + 90;
+      const panel = await harness.assignJurors(problemId, {
+        panelSize: market.votes.length,
+        voteDeadline,
+        jurorFee: 0,
+      });
+      const drawn = await harness.seatJury(problemId, panel);
+      await harness.waitUntil(deadline);
+      for (const [seat, juror] of drawn.entries()) {
+        await voteOutcome(problemId, panel, harness.juror(juror), market.votes[seat]);
+      }
+
+      const resolveOffset = randomOffset();
+      await program.methods
+        .resolveCategoricalMarket(resolveOffset, problemId)
+        .accountsPartial({
+          authority: harness.owner.publicKey,
+          ...harness.arciumAccounts(resolveOffset, "resolve_categorical_market"),
+          problem: harness.problemAddress(problemId),
+          market: marketPDA,
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(resolveOffset);
+
+      const resolved = await program.account.categoricalMarket.fetch(marketPDA);
+      expect(resolved.winningOutcome).to.equal(market.winning_outcome);
+      expect(resolved.winningVotes).to.equal(market.winning_votes);
+      expect(resolved.winningPool.toString()).to.equal(market.winning_pool);
+      expect(resolved.totalPool.toString()).to.equal(market.total_pool);
+
+      for (const [i, solver] of solvers.entries()) {
+        const paid = harness.awaitEvent("winnerPaidEvent");
+        const payOffset = randomOffset();
+        await program.methods
+          .payCategoricalWinner(payOffset, problemId)
+          .accountsPartial({
+            solver: solver.publicKey,
+            ...harness.arciumAccounts(payOffset, "pay_categorical_winner"),
+            problem: harness.problemAddress(problemId),
+            market: marketPDA,
+            solution: harness.solutionAddress(problemId, solver.publicKey),
+          })
+          .signers([solver])
+          .rpc({ commitment: "confirmed" });
+        await harness.finalize(payOffset);
+
+        const event = await paid;
+        expect(event.payoutAmount.toString()).to.equal(market.payouts[i].payout_amount);
+        expect(event.isWinner).to.equal(market.payouts[i].won);
+      }
+
+      // Categorical voters are released once voting closes.
+      await harness.waitUntil(voteDeadline);
+      for (const juror of drawn) {
+        await program.methods
+          .settleJuror(problemId)
+          .accountsPartial({
+            payer: harness.owner.publicKey,
+            problem: harness.problemAddress(problemId),
+            panel,
+            juror: harness.pda("juror", juror),
+          })
+          .rpc({ commitment: "confirmed" });
+      }
+      await harness.closeJury(problemId, panel);
+    });
+  }
+
+  for (const scoring of VECTORS.scoring as Scoring[]) {
+    it(`replays the scores [${scoring.scores}]`, async () => {
+      const deadline = now() + 30 + 15 * scoring.scores.length;
+      const { problemId, problemPDA } = await harness.createProblem({ deadline });
+      const scoreBoard = harness.pda("score_board", problemId);
+
+      const offset = randomOffset();
+      await program.methods
+        .enableScoring(offset, problemId, new anchor.BN(deserializeLE(randomBytes(16)).toString()))
+        .accountsPartial({
+          creator: harness.owner.publicKey,
+          ...harness.arciumAccounts(offset, "initialize_scoring"),
+          problem: problemPDA,
+          scoreBoard,
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(offset);
+
+      const solvers = await harness.funded(scoring.scores.length);
+      for (const solver of solvers) {
+        await harness.submitSolution(problemId, solver, true);
+      }
+      for (const [i, solver] of solvers.entries()) {
+        const { ciphertexts, publicKey, nonce } = await harness.encrypt([
+          BigInt(scoring.scores[i]),
+        ]);
+        const scoreOffset = randomOffset();
+        await program.methods
+          .scoreSolution(scoreOffset, problemId, ciphertexts[0], publicKey, nonce)
+          .accountsPartial({
+            creator: harness.owner.publicKey,
+            ...harness.arciumAccounts(scoreOffset, "score_solution"),
+            problem: problemPDA,
+            scoreBoard,
+            solution: harness.solutionAddress(problemId, solver.publicKey),
+          })
+          .rpc({ commitment: "confirmed" });
+        await harness.finalize(scoreOffset);
+      }
+
+      // The callback only closes the problem on the best solution.
+      const best = solvers[Number(scoring.best_index)];
+      await harness.waitUntil(deadline);
+      const closeOffset = randomOffset();
+      await program.methods
+        .closeScoredProblem(closeOffset, problemId)
+        .accountsPartial({
+          authority: harness.owner.publicKey,
+          ...harness.arciumAccounts(closeOffset, "reveal_best_solution"),
+          problem: problemPDA,
+          scoreBoard,
+          solution: harness.solutionAddress(problemId, best.publicKey),
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(closeOffset);
+
+      const problem = await program.account.problem.fetch(problemPDA);
+      expect(problem.state).to.deep.equal({ closed: {} });
+      expect(problem.winningIndex.toString()).to.equal(scoring.best_index);
+      expect(problem.winner.toBase58()).to.equal(best.publicKey.toBase58());
+    });
+  }
+
+  for (const auction of VECTORS.bids as Bids[]) {
+    it(`replays the sealed bids "${auction.name}"`, async () => {
+      const deadline = now() + 30 + 15 * auction.bids.length;
+      const { problemId, problemPDA } = await harness.createProblem({
+        deadline,
+        bountyAmount: new anchor.BN(auction.max_price),
+        bountyMode: { sealedBid: {} },
+      });
+      const bidBoard = harness.pda("bid_board", problemId);
+
+      const offset = randomOffset();
+      await program.methods
+        .openSealedBidding(
+          offset,
+          problemId,
+          new anchor.BN(deserializeLE(randomBytes(16)).toString())
+        )
+        .accountsPartial({
+          creator: harness.owner.publicKey,
+          ...harness.arciumAccounts(offset, "initialize_bid_board"),
+          problem: problemPDA,
+          bidBoard,
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(offset);
+
+      for (const bid of auction.bids) {
+        const [bidder] = await harness.funded(1);
+        const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(bid)]);
+        const bidOffset = randomOffset();
+        await program.methods
+          .submitBid(bidOffset, problemId, ciphertexts[0], publicKey, nonce)
+          .accountsPartial({
+            solver: bidder.publicKey,
+            ...harness.arciumAccounts(bidOffset, "submit_bid"),
+            problem: problemPDA,
+            bidBoard,
+            solutionIndexPage: harness.solutionIndexPage(problemId, 0),
+          })
+          .signers([bidder])
+          .rpc({ commitment: "confirmed" });
+        await harness.finalize(bidOffset);
+      }
+
+      await harness.waitUntil(deadline);
+      const closed = harness.awaitEvent("sealedBidClosedEvent");
+      const closeOffset = randomOffset();
+      await program.methods
+        .closeSealedBid(closeOffset, problemId)
+        .accountsPartial({
+          authority: harness.owner.publicKey,
+          ...harness.arciumAccounts(closeOffset, "reveal_lowest_bid"),
+          problem: problemPDA,
+          bidBoard,
+          creator: harness.owner.publicKey,
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(closeOffset);
+
+      const event = await closed;
+      expect(event.hasWinner).to.equal(auction.has_bid);
+      expect(event.winningBid.toString()).to.equal(auction.lowest_bid);
+      expect(event.winningIndex.toString()).to.equal(auction.lowest_index);
+    });
+  }
+
+  for (const bounty of VECTORS.confidential_bounties as ConfidentialBounty[]) {
+    it(`replays a confidential bounty of ${bounty.bounty} against a cap`, async () => {
+      const problemId = new anchor.BN(randomBytes(6));
+      const problemPDA = harness.problemAddress(problemId);
+      const deadline = now() + 30;
+      const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(bounty.bounty)]);
+
+      const offset = randomOffset();
+      await program.methods
+        .createConfidentialProblem(
+          offset,
+          problemId,
+          "Circuit vector: confidential bounty",
+          "Differential test against the reference model",
+          new anchor.BN(deadline),
+          new anchor.BN(bounty.bounty_cap),
+          ciphertexts[0],
+          publicKey,
+          nonce,
+          new anchor.BN(deserializeLE(randomBytes(16)).toString())
+        )
+        .accountsPartial({
+          creator: harness.owner.publicKey,
+          ...harness.arciumAccounts(offset, "initialize_confidential_bounty"),
+          problem: problemPDA,
+          registry: harness.pda("submissions", problemId),
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(offset);
+
+      const [winner] = await harness.funded(1);
+      await harness.submitSolution(problemId, winner, true);
+      await harness.waitUntil(deadline);
+      await harness.closeProblem(problemId, winner.publicKey);
+
+      const paid = harness.awaitEvent("confidentialBountyPaidEvent");
+      const refunded = harness.awaitEvent("bountyRefundedEvent");
+      const payOffset = randomOffset();
+      await program.methods
+        .payConfidentialBounty(payOffset, problemId)
+        .accountsPartial({
+          payer: harness.owner.publicKey,
+          ...harness.arciumAccounts(payOffset, "pay_confidential_bounty"),
+          problem: problemPDA,
+          winner: winner.publicKey,
+          creator: harness.owner.publicKey,
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(payOffset);
+
+      const event = await paid;
+      expect(event.bountyAmount.toString()).to.equal(bounty.payout_amount);
+      expect(event.capped).to.equal(bounty.capped);
+      expect((await refunded).amount.toString()).to.equal(bounty.refund_amount);
+    });
+  }
+
+  for (const sequence of VECTORS.submission_hashes as SubmissionHashes[]) {
+    it(`replays the content hashes [${sequence.hashes}]`, async () => {
+      const { problemId } = await harness.createProblem({ deadline: now() + 120 });
+
+      const solvers = await harness.funded(sequence.hashes.length);
+      for (const [i, solver] of solvers.entries()) {
+        await harness.submitSolution(
+          problemId,
+          solver,
+          true,
+          new anchor.BN(0),
+          {},
+          [],
+          BigInt(sequence.hashes[i])
+        );
+      }
+
+      for (const [i, solver] of solvers.entries()) {
+        const solution = await program.account.solution.fetch(
+          harness.solutionAddress(problemId, solver.publicKey)
+        );
+        expect(solution.duplicate).to.equal(sequence.duplicates[i]);
+      }
+    });
+  }
+
+  for (const panel of VECTORS.judge_picks as JudgePicks[]) {
+    it(`replays the judge picks "${panel.name}"`, async () => {
+      const judges = await harness.funded(panel.picks.length);
+      const deadline = now() + 30 + 15 * panel.num_candidates;
+      const { problemId, problemPDA } = await harness.createProblem({
+        deadline,
+        judges: judges.map((judge) => judge.publicKey),
+        judgeThreshold: panel.threshold,
+      });
+      const judgingPanel = harness.pda("judges", problemId);
+
+      for (const solver of await harness.funded(panel.num_candidates)) {
+        await harness.submitSolution(problemId, solver, true);
+      }
+      await harness.waitUntil(deadline);
+
+      const offset = randomOffset();
+      await program.methods
+        .openJudgingPanel(
+          offset,
+          problemId,
+          new anchor.BN(deserializeLE(randomBytes(16)).toString())
+        )
+        .accountsPartial({
+          authority: harness.owner.publicKey,
+          ...harness.arciumAccounts(offset, "initialize_judge_tally"),
+          problem: problemPDA,
+          judgingPanel,
+        })
+        .rpc({ commitment: "confirmed" });
+      await harness.finalize(offset);
+
+      for (const [i, judge] of judges.entries()) {
+        const { ciphertexts, publicKey, nonce } = await harness.encrypt([
+          BigInt(panel.picks[i]),
+        ]);
+        const pickOffset = randomOffset();
+        await program.methods
+          .submitJudgePick(pickOffset, problemId, ciphertexts[0], publicKey, nonce)
+          .accountsPartial({
+            judge: judge.publicKey,
+            ...harness.arciumAccounts(pickOffset, "submit_judge_pick"),
+            problem: problemPDA,
+            judgingPanel,
+          })
+          .signers([judge])
+          .rpc({ commitment: "confirmed" });
+        await harness.finalize(pickOffset);
+
+        // A decision closes the problem on the pick that reached the threshold.
+        const decision = panel.decisions[i];
+        const problem = await program.account.problem.fetch(problemPDA);
+        expect(problem.state).to.deep.equal(decision.decided ? { closed: {} } : { active: {} });
+        if (decision.decided) {
+          expect(problem.winningIndex.toNumber()).to.equal(decision.winning_index);
+        }
+      }
+    });
+  }
+
+  async function submitChoice(
+    problemId: anchor.BN,
+    solver: Keypair,
+    choice: number,
+    amount: string
+  ) {
+    const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(choice)]);
+    const offset = randomOffset();
+
+    await program.methods
+      .submitCategoricalSolution(
+        offset,
+        problemId,
+        new anchor.BN(amount),
+        ciphertexts[0],
+        publicKey,
+        nonce
+      )
+      .accountsPartial({
+        solver: solver.publicKey,
+        ...harness.arciumAccounts(offset, "submit_categorical_solution"),
+        problem: harness.problemAddress(problemId),
+        market: harness.pda("categorical", problemId),
+        solutionIndexPage: harness.solutionIndexPage(problemId, 0),
+      })
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function voteOutcome(
+    problemId: anchor.BN,
+    panel: PublicKey,
+    voter: Keypair,
+    outcome: number
+  ) {
+    const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(outcome)]);
+    const offset = randomOffset();
+
+    await program.methods
+      .submitCategoricalVote(offset, problemId, ciphertexts[0], publicKey, nonce)
+      .accountsPartial({
+        voter: voter.publicKey,
+        ...harness.arciumAccounts(offset, "submit_categorical_vote"),
+        problem: harness.problemAddress(problemId),
+        market: harness.pda("categorical", problemId),
+        panel,
+        voteRecord: harness.pda("vote", problemId, voter.publicKey),
+      })
+      .signers([voter])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+});
@@ -0,0 +1,133 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// MPC fee reimbursement: creators prepay a compute budget that pays solvers
+// back for the Arcium fees their `submit_solution` and `pay_winner` queues are
+// charged, and can only take what's left back once the grace period after the
+// deadline is over.
+//
+// `COMPUTE_BUDGET_GRACE_PERIOD` is 7 days, which localnet can't fast-forward
+// to, so only the rejections of `withdraw_compute_budget` are covered here.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+const COMPUTE_BUDGET = new anchor.BN(20_000_000);
+
+describe("Compute budget", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution", "pay_winner"]);
+  });
+
+  it("reimburses solvers' MPC fees from the compute budget", async () => {
+    const deadline = now() + 30;
+    const { problemId, problemPDA } = await harness.createProblem({
+      deadline,
+      computeBudget: COMPUTE_BUDGET,
+    });
+    // The creator's own queue is recorded but not reimbursed.
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.computeBudget.toString()).to.equal(COMPUTE_BUDGET.toString());
+    let mpcFees = problem.mpcFees.toNumber();
+
+    const [solver] = await harness.funded(1);
+    const submitted = harness.awaitEvent("mpcFeeReimbursedEvent");
+    await harness.submitSolution(problemId, solver, true);
+    let event = await submitted;
+
+    expect(event.payer.toBase58()).to.equal(solver.publicKey.toBase58());
+    expect(event.fee.toNumber()).to.be.greaterThan(0);
+    expect(event.reimbursed.toNumber()).to.equal(event.fee.toNumber());
+    let budget = COMPUTE_BUDGET.toNumber() - event.reimbursed.toNumber();
+    expect(event.remainingBudget.toNumber()).to.equal(budget);
+    mpcFees += event.fee.toNumber();
+
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.computeBudget.toNumber()).to.equal(budget);
+    expect(problem.mpcFees.toNumber()).to.equal(mpcFees);
+
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, solver.publicKey);
+
+    // Payouts after close are still reimbursed.
+    const paid = harness.awaitEvent("mpcFeeReimbursedEvent");
+    await harness.payWinner(problemId, solver, { isWinner: true });
+    event = await paid;
+    expect(event.reimbursed.toNumber()).to.equal(event.fee.toNumber());
+    budget -= event.reimbursed.toNumber();
+
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.computeBudget.toNumber()).to.equal(budget);
+    expect(problem.mpcFees.toNumber()).to.equal(mpcFees + event.fee.toNumber());
+  });
+
+  it("records fees the budget can't cover", async () => {
+    const { problemId, problemPDA } = await harness.createProblem({ deadline: now() + 60 });
+    const mpcFees = (await program.account.problem.fetch(problemPDA)).mpcFees.toNumber();
+
+    const [solver] = await harness.funded(1);
+    const submitted = harness.awaitEvent("mpcFeeReimbursedEvent");
+    await harness.submitSolution(problemId, solver, true);
+    const event = await submitted;
+    expect(event.reimbursed.toNumber()).to.equal(0);
+    expect(event.remainingBudget.toNumber()).to.equal(0);
+
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.mpcFees.toNumber()).to.equal(mpcFees + event.fee.toNumber());
+  });
+
+  it("tops the budget up from anyone", async () => {
+    const { problemId, problemPDA } = await harness.createProblem({
+      deadline: now() + 60,
+      computeBudget: COMPUTE_BUDGET,
+    });
+    const [sponsor] = await harness.funded(1);
+
+    await topUp(problemId, sponsor, 5_000_000);
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.computeBudget.toNumber()).to.equal(COMPUTE_BUDGET.toNumber() + 5_000_000);
+
+    await expectError(topUp(problemId, sponsor, 0), "InvalidAmount");
+  });
+
+  it("holds the budget through the grace period", async () => {
+    const deadline = now() + 20;
+    const { problemId } = await harness.createProblem({
+      deadline,
+      computeBudget: COMPUTE_BUDGET,
+    });
+    const [solver] = await harness.funded(1);
+    await harness.submitSolution(problemId, solver, true);
+
+    await expectError(withdraw(problemId), "ProblemNotClosed");
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, solver.publicKey);
+    await expectError(withdraw(problemId), "DeadlineNotReached");
+    await expectError(withdraw(problemId, solver), "UnauthorizedAccess");
+  });
+
+  function topUp(problemId: anchor.BN, funder: Keypair, amount: number) {
+    return program.methods
+      .topUpComputeBudget(problemId, new anchor.BN(amount))
+      .accountsPartial({ funder: funder.publicKey, problem: harness.problemAddress(problemId) })
+      .signers([funder])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function withdraw(problemId: anchor.BN, creator: Keypair = harness.owner) {
+    return program.methods
+      .withdrawComputeBudget(problemId)
+      .accountsPartial({ creator: creator.publicKey, problem: harness.problemAddress(problemId) })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+  }
+});
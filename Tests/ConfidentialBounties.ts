@@ -0,0 +1,159 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Confidential bounties: `create_confidential_problem` escrows a public cap
+// and keeps the real bounty encrypted until `pay_confidential_bounty` reveals
+// it at payout, refunding whatever of the cap the winner doesn't take.
+//
+// `refund_confidential_bounty` only succeeds once the 24 hour review window
+// has run out, which localnet can't fast-forward to, so only its rejections
+// are covered here.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { deserializeLE } from "@arcium-hq/client";
+import { Harness, expectError, now, randomOffset } from "./Harness";
+
+describe("Confidential bounties", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "submit_solution",
+      "initialize_confidential_bounty",
+      "pay_confidential_bounty",
+    ]);
+  });
+
+  it("reveals the bounty at payout and refunds the rest of the cap", async () => {
+    const deadline = now() + 30;
+    const { problemId, problemPDA } = await confidentialProblem(300_000, 500_000, deadline);
+
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.escrowAmount.toNumber()).to.equal(500_000);
+
+    const [winner] = await harness.funded(1);
+    await harness.submitSolution(problemId, winner, true);
+    await expectError(pay(problemId, winner), "ProblemNotClosed");
+
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, winner.publicKey);
+
+    const before = await harness.balance(winner.publicKey);
+    const paid = harness.awaitEvent("confidentialBountyPaidEvent");
+    const refunded = harness.awaitEvent("bountyRefundedEvent");
+    await pay(problemId, winner);
+
+    const event = await paid;
+    expect(event.bountyAmount.toNumber()).to.equal(300_000);
+    expect(event.capped).to.equal(false);
+    expect((await refunded).amount.toNumber()).to.equal(200_000);
+    expect((await harness.balance(winner.publicKey)) - before).to.equal(300_000);
+
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.bountyAmount.toNumber()).to.equal(300_000);
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+
+    await expectError(pay(problemId, winner), "BountyAlreadyReleased");
+    await expectError(refund(problemId), "ProblemNotActive");
+  });
+
+  it("pays no more than the cap", async () => {
+    const deadline = now() + 30;
+    const { problemId } = await confidentialProblem(900_000, 500_000, deadline);
+
+    const [winner] = await harness.funded(1);
+    await harness.submitSolution(problemId, winner, true);
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, winner.publicKey);
+
+    const paid = harness.awaitEvent("confidentialBountyPaidEvent");
+    const refunded = harness.awaitEvent("bountyRefundedEvent");
+    await pay(problemId, winner);
+
+    const event = await paid;
+    expect(event.bountyAmount.toNumber()).to.equal(500_000);
+    expect(event.capped).to.equal(true);
+    expect((await refunded).amount.toNumber()).to.equal(0);
+  });
+
+  it("holds the cap through the review window", async () => {
+    const deadline = now() + 20;
+    const { problemId } = await confidentialProblem(300_000, 500_000, deadline);
+
+    await expectError(refund(problemId), "ReviewWindowOpen");
+    await harness.waitUntil(deadline);
+    await expectError(refund(problemId), "ReviewWindowOpen");
+
+    const [stranger] = await harness.funded(1);
+    await expectError(refund(problemId, stranger), "UnauthorizedAccess");
+
+    const standard = await harness.createProblem({ deadline: now() + 20 });
+    await expectError(refund(standard.problemId), "InvalidBountyMode");
+  });
+
+  async function confidentialProblem(bounty: number, cap: number, deadline: number) {
+    const problemId = new anchor.BN(randomBytes(6));
+    const problemPDA = harness.problemAddress(problemId);
+    const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(bounty)]);
+    const offset = randomOffset();
+
+    await program.methods
+      .createConfidentialProblem(
+        offset,
+        problemId,
+        "Confidential bounty",
+        "The bounty is revealed when the winner is paid",
+        new anchor.BN(deadline),
+        new anchor.BN(cap),
+        ciphertexts[0],
+        publicKey,
+        nonce,
+        new anchor.BN(deserializeLE(randomBytes(16)).toString())
+      )
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "initialize_confidential_bounty"),
+        problem: problemPDA,
+        registry: harness.pda("submissions", problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+
+    return { problemId, problemPDA };
+  }
+
+  async function pay(problemId: anchor.BN, winner: Keypair) {
+    const offset = randomOffset();
+    await program.methods
+      .payConfidentialBounty(offset, problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "pay_confidential_bounty"),
+        problem: harness.problemAddress(problemId),
+        winner: winner.publicKey,
+        creator: harness.owner.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  function refund(problemId: anchor.BN, creator: Keypair = harness.owner) {
+    return program.methods
+      .refundConfidentialBounty(problemId)
+      .accountsPartial({
+        creator: creator.publicKey,
+        problem: harness.problemAddress(problemId),
+      })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+  }
+});
@@ -0,0 +1,211 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Creator bonds: the bond stays in the problem account until the winner can no
+// longer be disputed, then `return_creator_bond` hands it back. A jury that
+// overturns the winner slashes it, and the solvers split the slashed share
+// with `claim_bond_share`.
+//
+// `slash_missed_review` only succeeds once the 24 hour review window has run
+// out, which localnet can't fast-forward to, so only its rejection is covered.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+const BOND = new anchor.BN(90_000_000);
+const SLASH_BPS = 5_000;
+const DISPUTE_WINDOW = 10;
+// Mirrors `DISPUTE_PANEL_SIZE` in the program.
+const DISPUTE_PANEL_SIZE = 5;
+
+describe("Creator bonds", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "submit_solution",
+      "draw_jury",
+      "submit_quorum_vote",
+      "resolve_market",
+      "check_juror_vote",
+    ]);
+    await harness.registerJurors(DISPUTE_PANEL_SIZE);
+  });
+
+  // Every dispute panel is settled and closed by its test, so the jurors are free to leave.
+  after(async () => {
+    await harness.leaveJurorPool();
+  });
+
+  it("returns the bond once the dispute window has passed", async () => {
+    const [creator, solver] = await harness.funded(2);
+    const deadline = now() + 20;
+    const { problemId, problemPDA } = await bondedProblem(creator, deadline, DISPUTE_WINDOW);
+    await harness.submitSolution(problemId, solver, true);
+
+    await expectError(returnBond(problemId, creator), "ProblemNotClosed");
+
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, solver.publicKey, creator);
+    await expectError(returnBond(problemId, creator), "DisputeWindowOpen");
+
+    const { closedAt } = await program.account.problem.fetch(problemPDA);
+    await harness.waitUntil(closedAt.toNumber() + DISPUTE_WINDOW);
+    const before = await harness.balance(creator.publicKey);
+    await returnBond(problemId, creator);
+
+    expect((await harness.balance(creator.publicKey)) - before).to.equal(BOND.toNumber());
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.bondStatus).to.deep.equal({ returned: {} });
+    await expectError(returnBond(problemId, creator), "CreatorBondNotHeld");
+  });
+
+  it("slashes the bond when a jury overturns the winner", async () => {
+    const { problemId, problemPDA, creator, solvers } = await closedBondedProblem(3);
+    // Judging on time keeps the bond safe from `slash_missed_review`.
+    await expectError(slashMissedReview(problemId, creator), "ReviewWindowOpen");
+
+    const before = await harness.balance(creator.publicKey);
+    await overturn(problemId, solvers[1], solvers[0]);
+
+    // The slashed share stays behind for the solvers; the rest goes back.
+    const slashed = (BOND.toNumber() * SLASH_BPS) / 10_000;
+    expect((await harness.balance(creator.publicKey)) - before).to.equal(
+      BOND.toNumber() - slashed
+    );
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.bondStatus).to.deep.equal({ slashed: {} });
+    expect(problem.bondSlashed.toNumber()).to.equal(slashed);
+
+    await expectError(returnBond(problemId, creator), "CreatorBondNotHeld");
+    await expectError(slashMissedReview(problemId, creator), "CreatorBondNotHeld");
+  });
+
+  it("shares a slashed bond equally between the solvers", async () => {
+    const { problemId, solvers } = await closedBondedProblem(3);
+    const [winner, challenger] = solvers;
+    // Solvers only share a bond that was slashed.
+    await expectError(claimShare(problemId, winner), "CreatorBondNotSlashed");
+
+    await overturn(problemId, challenger, winner);
+
+    const share = (BOND.toNumber() * SLASH_BPS) / 10_000 / solvers.length;
+    for (const solver of solvers) {
+      const before = await harness.balance(solver.publicKey);
+      await claimShare(problemId, solver);
+      expect((await harness.balance(solver.publicKey)) - before).to.equal(share);
+    }
+    await expectError(claimShare(problemId, winner), "AlreadyPaid");
+  });
+
+  function bondedProblem(creator: Keypair, deadline: number, disputeWindow: number) {
+    return harness.createProblem({
+      deadline,
+      creator,
+      creatorBond: {
+        amount: BOND,
+        slashBps: SLASH_BPS,
+        reviewWindow: new anchor.BN(24 * 60 * 60),
+      },
+      disputeWindow,
+    });
+  }
+
+  /** A bonded problem closed on its first solver, still open to disputes. */
+  async function closedBondedProblem(solutions: number) {
+    const [creator] = await harness.funded(1);
+    const deadline = now() + 20;
+    const { problemId, problemPDA } = await bondedProblem(creator, deadline, 600);
+
+    const solvers = await harness.funded(solutions);
+    for (const solver of solvers) {
+      await harness.submitSolution(problemId, solver, true);
+    }
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, solvers[0].publicKey, creator);
+
+    return { problemId, problemPDA, creator, solvers };
+  }
+
+  /** Has `challenger` dispute `winner` and a unanimous jury overturn them. */
+  async function overturn(problemId: anchor.BN, challenger: Keypair, winner: Keypair) {
+    const { disputeCount, juryCount, creator } = await program.account.problem.fetch(
+      harness.problemAddress(problemId)
+    );
+    const dispute = harness.pda("dispute", problemId, disputeCount);
+    const panel = harness.juryPanel(problemId, juryCount.toNumber());
+
+    await program.methods
+      .disputeWinner(problemId)
+      .accountsPartial({
+        challenger: challenger.publicKey,
+        problem: harness.problemAddress(problemId),
+        solution: harness.solutionAddress(problemId, challenger.publicKey),
+        dispute,
+        panel,
+      })
+      .signers([challenger])
+      .rpc({ commitment: "confirmed" });
+
+    const jurors = await harness.seatJury(problemId, panel);
+    for (const juror of jurors) {
+      await harness.castJurorVote(problemId, panel, harness.juror(juror), true);
+    }
+    await harness.resolveJury(problemId, panel);
+
+    await program.methods
+      .resolveDispute(problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        dispute,
+        panel,
+        challenger: challenger.publicKey,
+        winner: winner.publicKey,
+        creator,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    await harness.settleJurorVotes(problemId, panel, jurors);
+    await harness.closeJury(problemId, panel);
+  }
+
+  function returnBond(problemId: anchor.BN, creator: Keypair) {
+    return program.methods
+      .returnCreatorBond(problemId)
+      .accountsPartial({ creator: creator.publicKey, problem: harness.problemAddress(problemId) })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function slashMissedReview(problemId: anchor.BN, creator: Keypair) {
+    return program.methods
+      .slashMissedReview(problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        creator: creator.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function claimShare(problemId: anchor.BN, solver: Keypair) {
+    return program.methods
+      .claimBondShare(problemId)
+      .accountsPartial({
+        solver: solver.publicKey,
+        problem: harness.problemAddress(problemId),
+        solution: harness.solutionAddress(problemId, solver.publicKey),
+      })
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+});
@@ -0,0 +1,240 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Disputes: a solver challenges the creator's winner, which opens a jury panel
+// on fixed terms. The jury drawn from the pool votes through
+// `submit_juror_vote`/`resolve_jury`, and `resolve_dispute` either confirms the
+// winner or reopens judging, closing the dispute account, and `close_jury`
+// splits the juror fee and any slashed juror stake between the majority.
+// What an overturn does to a creator bond is covered in CreatorBonds.ts.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+const DISPUTE_BOND = 50_000_000;
+const DISPUTE_JUROR_FEE = 10_000_000;
+const DISPUTE_PANEL_SIZE = 5;
+const DISPUTE_VOTE_PERIOD = 2 * 24 * 60 * 60;
+
+describe("Disputes", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "submit_solution",
+      "draw_jury",
+      "submit_quorum_vote",
+      "resolve_market",
+      "check_juror_vote",
+    ]);
+    await harness.registerJurors(DISPUTE_PANEL_SIZE);
+  });
+
+  // Every panel is settled and closed by now, so the jurors are free to leave.
+  after(async () => {
+    await harness.leaveJurorPool();
+  });
+
+  it("confirms a winner the jury backs and pays them the bond", async () => {
+    const { problemId, problemPDA, solvers } = await closedProblem(2);
+    const [winner, challenger] = solvers;
+
+    await dispute(problemId, challenger, 0);
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ disputed: {} });
+    expect(problem.disputeCount.toNumber()).to.equal(1);
+
+    // The panel's terms are fixed; the challenger only pays for them.
+    const { openedAt } = await program.account.dispute.fetch(disputeAddress(problemId, 0));
+    const panel = await program.account.juryPanel.fetch(panelAddress(problemId, 0));
+    expect(panel.funder.toBase58()).to.equal(challenger.publicKey.toBase58());
+    expect(panel.panelSize).to.equal(DISPUTE_PANEL_SIZE);
+    expect(panel.jurorFee.toNumber()).to.equal(DISPUTE_JUROR_FEE);
+    expect(panel.voteDeadline.toNumber()).to.equal(openedAt.toNumber() + DISPUTE_VOTE_PERIOD);
+    await expectError(
+      harness.assignJurors(problemId, {
+        panelSize: DISPUTE_PANEL_SIZE,
+        voteDeadline: now() + DISPUTE_VOTE_PERIOD,
+        jurorFee: DISPUTE_JUROR_FEE,
+      }),
+      "DisputePanelFixed"
+    );
+
+    const drawn = await decide(problemId, 0, Array(DISPUTE_PANEL_SIZE).fill(false));
+    const before = await harness.balance(winner.publicKey);
+    await resolve(problemId, 0, challenger.publicKey, winner.publicKey);
+
+    expect((await harness.balance(winner.publicKey)) - before).to.equal(DISPUTE_BOND);
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ closed: {} });
+    expect(problem.winner.toBase58()).to.equal(winner.publicKey.toBase58());
+    expect(await program.account.dispute.fetchNullable(disputeAddress(problemId, 0))).to.be
+      .null;
+
+    // A unanimous jury splits the fee and nobody is slashed.
+    const stakesBefore = await stakes(drawn);
+    const rewarded = await settle(problemId, 0);
+    expect(rewarded).to.have.length(DISPUTE_PANEL_SIZE);
+    const stakesAfter = await stakes(drawn);
+    for (const juror of drawn) {
+      expect(stakesAfter.get(juror.toBase58()) - stakesBefore.get(juror.toBase58())).to.equal(
+        DISPUTE_JUROR_FEE / DISPUTE_PANEL_SIZE
+      );
+    }
+  });
+
+  it("reopens judging on an overturn and lets the next winner be disputed", async () => {
+    const { problemId, problemPDA, solvers } = await closedProblem(3);
+    const [winner, challenger, runnerUp] = solvers;
+
+    await dispute(problemId, challenger, 0);
+    const drawn = await decide(problemId, 0, [true, true, true, false, false]);
+    const before = await harness.balance(challenger.publicKey);
+    await resolve(problemId, 0, challenger.publicKey, winner.publicKey);
+
+    // The bond comes back along with the rent of the closed dispute account.
+    expect((await harness.balance(challenger.publicKey)) - before).to.be.greaterThan(
+      DISPUTE_BOND
+    );
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ active: {} });
+    expect(problem.winner).to.be.null;
+    await expectError(dispute(problemId, challenger, 1), "ProblemNotClosed");
+
+    // The minority's slashed stake joins the fee, and the majority splits both.
+    const stakesBefore = await stakes(drawn);
+    const rewarded = await settle(problemId, 0);
+    expect(rewarded).to.have.length(3);
+    const minority = drawn.filter((juror) => !rewarded.some((other) => other.equals(juror)));
+    const slashed = minority.reduce(
+      (sum, juror) => sum + Math.floor(stakesBefore.get(juror.toBase58()) / 10),
+      0
+    );
+    const reward = Math.floor((DISPUTE_JUROR_FEE + slashed) / rewarded.length);
+    const stakesAfter = await stakes(drawn);
+    for (const juror of drawn) {
+      const stakeBefore = stakesBefore.get(juror.toBase58());
+      const expected = minority.includes(juror)
+        ? stakeBefore - Math.floor(stakeBefore / 10)
+        : stakeBefore + reward;
+      expect(stakesAfter.get(juror.toBase58())).to.equal(expected);
+    }
+
+    await harness.closeProblem(problemId, runnerUp.publicKey);
+    await dispute(problemId, challenger, 1);
+
+    // The second dispute gets a panel of its own.
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ disputed: {} });
+    expect(problem.disputeCount.toNumber()).to.equal(2);
+    expect(problem.juryCount.toNumber()).to.equal(2);
+    const second = await program.account.dispute.fetch(disputeAddress(problemId, 1));
+    expect(second.disputedWinner.toBase58()).to.equal(runnerUp.publicKey.toBase58());
+    expect(second.panelIndex.toNumber()).to.equal(1);
+    const panel = await program.account.juryPanel.fetch(panelAddress(problemId, 1));
+    expect(panel.index.toNumber()).to.equal(1);
+
+    await decide(problemId, 1, Array(DISPUTE_PANEL_SIZE).fill(false));
+    await resolve(problemId, 1, challenger.publicKey, runnerUp.publicKey);
+    await settle(problemId, 1);
+  });
+
+  async function closedProblem(solutions: number) {
+    const deadline = now() + 20;
+    const { problemId, problemPDA } = await harness.createProblem({
+      deadline,
+      disputeWindow: 600,
+    });
+
+    const solvers = await harness.funded(solutions);
+    for (const solver of solvers) {
+      await harness.submitSolution(problemId, solver, true);
+    }
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, solvers[0].publicKey);
+
+    return { problemId, problemPDA, solvers };
+  }
+
+  function disputeAddress(problemId: anchor.BN, index: number): PublicKey {
+    return harness.pda("dispute", problemId, new anchor.BN(index));
+  }
+
+  function panelAddress(problemId: anchor.BN, index: number): PublicKey {
+    return harness.juryPanel(problemId, index);
+  }
+
+  function dispute(problemId: anchor.BN, challenger: Keypair, index: number) {
+    return program.methods
+      .disputeWinner(problemId)
+      .accountsPartial({
+        challenger: challenger.publicKey,
+        problem: harness.problemAddress(problemId),
+        solution: harness.solutionAddress(problemId, challenger.publicKey),
+        dispute: disputeAddress(problemId, index),
+        panel: panelAddress(problemId, index),
+      })
+      .signers([challenger])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /**
+   * Draws and seats the dispute's panel, has the juror in seat `i` vote
+   * `overturn[i]` and reveals the tally, which needs no wait once everyone
+   * has voted. Returns the jurors in seat order.
+   */
+  async function decide(problemId: anchor.BN, index: number, overturn: boolean[]) {
+    const panel = panelAddress(problemId, index);
+    const drawn = await harness.seatJury(problemId, panel);
+    for (const [seat, juror] of drawn.entries()) {
+      await harness.castJurorVote(problemId, panel, harness.juror(juror), overturn[seat]);
+    }
+    await harness.resolveJury(problemId, panel);
+    return drawn;
+  }
+
+  /** Settles every juror's vote and closes the panel, returning the rewarded majority. */
+  async function settle(problemId: anchor.BN, index: number): Promise<PublicKey[]> {
+    const panel = panelAddress(problemId, index);
+    const { jurors } = await program.account.juryPanel.fetch(panel);
+    await harness.settleJurorVotes(problemId, panel, jurors);
+    return harness.closeJury(problemId, panel);
+  }
+
+  async function stakes(drawn: PublicKey[]): Promise<Map<string, number>> {
+    const stakes = new Map<string, number>();
+    for (const juror of drawn) {
+      const { stake } = await program.account.juror.fetch(harness.pda("juror", juror));
+      stakes.set(juror.toBase58(), stake.toNumber());
+    }
+    return stakes;
+  }
+
+  function resolve(
+    problemId: anchor.BN,
+    index: number,
+    challenger: PublicKey,
+    winner: PublicKey
+  ) {
+    return program.methods
+      .resolveDispute(problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        dispute: disputeAddress(problemId, index),
+        panel: panelAddress(problemId, index),
+        challenger,
+        winner,
+        creator: harness.owner.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
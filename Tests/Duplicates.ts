@@ -0,0 +1,73 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Duplicate detection: `submit_solution` checks each solution's encrypted
+// content hash against the problem's earlier ones in MPC and flags copies on
+// `Solution.duplicate`, without revealing any hash.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { Harness, now } from "./Harness";
+
+describe("Duplicate detection", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution"]);
+  });
+
+  it("flags a solution that copies an earlier one's content", async () => {
+    const { problemId } = await harness.createProblem({ deadline: now() + 60 });
+    const [original, other, copy] = await harness.funded(3);
+
+    await submit(problemId, original, BigInt(0xc0ffee));
+    await submit(problemId, other, BigInt(0xbeef));
+    const flagged = harness.awaitEvent("duplicateSolutionEvent");
+    await submit(problemId, copy, BigInt(0xc0ffee));
+
+    const event = await flagged;
+    expect(event.solver.toBase58()).to.equal(copy.publicKey.toBase58());
+    expect(event.index.toNumber()).to.equal(2);
+
+    expect(await isDuplicate(problemId, original)).to.equal(false);
+    expect(await isDuplicate(problemId, other)).to.equal(false);
+    expect(await isDuplicate(problemId, copy)).to.equal(true);
+  });
+
+  it("only compares solutions to the same problem", async () => {
+    const first = await harness.createProblem({ deadline: now() + 60 });
+    const second = await harness.createProblem({ deadline: now() + 60 });
+    const [solver, other] = await harness.funded(2);
+
+    await submit(first.problemId, solver, BigInt(0xfeed));
+    await submit(second.problemId, other, BigInt(0xfeed));
+
+    expect(await isDuplicate(first.problemId, solver)).to.equal(false);
+    expect(await isDuplicate(second.problemId, other)).to.equal(false);
+  });
+
+  function submit(problemId: anchor.BN, solver: Keypair, contentHash: bigint) {
+    return harness.submitSolution(
+      problemId,
+      solver,
+      true,
+      new anchor.BN(0),
+      {},
+      [],
+      contentHash
+    );
+  }
+
+  async function isDuplicate(problemId: anchor.BN, solver: Keypair) {
+    const solution = await program.account.solution.fetch(
+      harness.solutionAddress(problemId, solver.publicKey)
+    );
+    return solution.duplicate;
+  }
+});
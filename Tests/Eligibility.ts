@@ -0,0 +1,325 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Eligibility rules: `submit_solution` only admits solvers on a Merkle
+// allowlist, holding enough of a legacy or Token-2022 mint, or holding an NFT
+// from a verified Metaplex collection.
+
+import * as anchor from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SYSVAR_RENT_PUBKEY,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { createHash } from "crypto";
+import {
+  Harness,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  expectError,
+  now,
+} from "./Harness";
+
+const METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+describe("Eligibility rules", () => {
+  const harness = new Harness();
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution"]);
+  });
+
+  it("admits allowlisted solvers with a valid proof", async () => {
+    const [listed, alsoListed, outsider] = await harness.funded(3);
+    const leaves = [listed, alsoListed, Keypair.generate(), Keypair.generate()].map(
+      (keypair) => sha256(keypair.publicKey.toBuffer())
+    );
+    const left = hashPair(leaves[0], leaves[1]);
+    const right = hashPair(leaves[2], leaves[3]);
+    const merkleRoot = hashPair(left, right);
+
+    const { problemId } = await harness.createProblem({
+      deadline: now() + 60,
+      eligibility: { allowlist: { merkleRoot: Array.from(merkleRoot) } },
+    });
+
+    await harness.submitSolution(problemId, listed, true, undefined, {}, [leaves[1], right]);
+    await harness.submitSolution(problemId, alsoListed, true, undefined, {}, [leaves[0], right]);
+
+    // A proof only works for the leaf it was built for.
+    await expectError(
+      harness.submitSolution(problemId, outsider, true, undefined, {}, [leaves[1], right]),
+      "NotEligible"
+    );
+    await expectError(harness.submitSolution(problemId, outsider, true), "NotEligible");
+  });
+
+  for (const [name, tokenProgram] of [
+    ["legacy", TOKEN_PROGRAM_ID],
+    ["Token-2022", TOKEN_2022_PROGRAM_ID],
+  ] as const) {
+    it(`admits holders of a ${name} mint`, async () => {
+      const [holder, shortHolder, outsider] = await harness.funded(3);
+      const mint = await createMint(tokenProgram);
+      const holderAccount = await createTokenAccount(mint, holder.publicKey, tokenProgram);
+      const shortAccount = await createTokenAccount(mint, shortHolder.publicKey, tokenProgram);
+      await mintTo(mint, holderAccount, 5, tokenProgram);
+      await mintTo(mint, shortAccount, 4, tokenProgram);
+
+      const { problemId } = await harness.createProblem({
+        deadline: now() + 60,
+        eligibility: { tokenHolder: { mint, minAmount: new anchor.BN(5) } },
+      });
+
+      await harness.submitSolution(problemId, holder, true, undefined, {
+        eligibilityToken: holderAccount,
+      });
+      await expectError(
+        harness.submitSolution(problemId, shortHolder, true, undefined, {
+          eligibilityToken: shortAccount,
+        }),
+        "NotEligible"
+      );
+      // Someone else's holding doesn't count.
+      await expectError(
+        harness.submitSolution(problemId, outsider, true, undefined, {
+          eligibilityToken: holderAccount,
+        }),
+        "NotEligible"
+      );
+      await expectError(harness.submitSolution(problemId, outsider, true), "NotEligible");
+    });
+  }
+
+  it("admits holders of an NFT from a verified collection", async () => {
+    const [holder] = await harness.funded(1);
+    const collection = await createNft(harness.owner.publicKey, null);
+    await createMasterEdition(collection.mint);
+    const nft = await createNft(holder.publicKey, collection.mint);
+
+    const { problemId } = await harness.createProblem({
+      deadline: now() + 60,
+      eligibility: { nftCollection: { collection: collection.mint } },
+    });
+    const accounts = {
+      eligibilityToken: nft.account,
+      eligibilityMetadata: metadataAddress(nft.mint),
+    };
+
+    // Anyone can name a collection; only its authority can verify membership.
+    await expectError(
+      harness.submitSolution(problemId, holder, true, undefined, accounts),
+      "NotEligible"
+    );
+
+    await verifyCollection(nft.mint, collection.mint);
+    await harness.submitSolution(problemId, holder, true, undefined, accounts);
+  });
+
+  function sha256(...parts: Buffer[]): Buffer {
+    const hash = createHash("sha256");
+    parts.forEach((part) => hash.update(part));
+    return hash.digest();
+  }
+
+  // Mirrors `check_eligibility`: each step hashes the sorted pair.
+  function hashPair(a: Buffer, b: Buffer): Buffer {
+    return Buffer.compare(a, b) <= 0 ? sha256(a, b) : sha256(b, a);
+  }
+
+  async function send(instructions: TransactionInstruction[], signers: Keypair[] = []) {
+    await harness.provider.sendAndConfirm(new Transaction().add(...instructions), signers, {
+      commitment: "confirmed",
+    });
+  }
+
+  async function createAccount(space: number, owner: PublicKey): Promise<Keypair> {
+    const account = Keypair.generate();
+    const lamports = await harness.provider.connection.getMinimumBalanceForRentExemption(space);
+    await send(
+      [
+        SystemProgram.createAccount({
+          fromPubkey: harness.owner.publicKey,
+          newAccountPubkey: account.publicKey,
+          lamports,
+          space,
+          programId: owner,
+        }),
+      ],
+      [account]
+    );
+    return account;
+  }
+
+  async function createMint(tokenProgram: PublicKey): Promise<PublicKey> {
+    const mint = await createAccount(82, tokenProgram);
+    // InitializeMint2: decimals 0, the wallet as mint authority, no freeze authority.
+    await send([
+      new TransactionInstruction({
+        programId: tokenProgram,
+        keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+        data: Buffer.concat([
+          Buffer.from([20, 0]),
+          harness.owner.publicKey.toBuffer(),
+          Buffer.from([0]),
+        ]),
+      }),
+    ]);
+    return mint.publicKey;
+  }
+
+  async function createTokenAccount(
+    mint: PublicKey,
+    owner: PublicKey,
+    tokenProgram: PublicKey
+  ): Promise<PublicKey> {
+    const account = await createAccount(165, tokenProgram);
+    // InitializeAccount3
+    await send([
+      new TransactionInstruction({
+        programId: tokenProgram,
+        keys: [
+          { pubkey: account.publicKey, isSigner: false, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([Buffer.from([18]), owner.toBuffer()]),
+      }),
+    ]);
+    return account.publicKey;
+  }
+
+  async function mintTo(
+    mint: PublicKey,
+    account: PublicKey,
+    amount: number,
+    tokenProgram: PublicKey
+  ) {
+    await send([
+      new TransactionInstruction({
+        programId: tokenProgram,
+        keys: [
+          { pubkey: mint, isSigner: false, isWritable: true },
+          { pubkey: account, isSigner: false, isWritable: true },
+          { pubkey: harness.owner.publicKey, isSigner: true, isWritable: false },
+        ],
+        data: Buffer.concat([
+          Buffer.from([7]),
+          new anchor.BN(amount).toArrayLike(Buffer, "le", 8),
+        ]),
+      }),
+    ]);
+  }
+
+  function metadataAddress(mint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      METADATA_PROGRAM_ID
+    )[0];
+  }
+
+  function editionAddress(mint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("metadata"),
+        METADATA_PROGRAM_ID.toBuffer(),
+        mint.toBuffer(),
+        Buffer.from("edition"),
+      ],
+      METADATA_PROGRAM_ID
+    )[0];
+  }
+
+  function borshString(value: string): Buffer {
+    const bytes = Buffer.from(value);
+    const length = Buffer.alloc(4);
+    length.writeUInt32LE(bytes.length);
+    return Buffer.concat([length, bytes]);
+  }
+
+  /** Mints a single legacy-token NFT to `owner`, naming `collection` unverified. */
+  async function createNft(owner: PublicKey, collection: PublicKey | null) {
+    const mint = await createMint(TOKEN_PROGRAM_ID);
+    const account = await createTokenAccount(mint, owner, TOKEN_PROGRAM_ID);
+    await mintTo(mint, account, 1, TOKEN_PROGRAM_ID);
+
+    // CreateMetadataAccountV3 with no creators or uses, mutable, no collection details.
+    const data = Buffer.concat([
+      Buffer.from([33]),
+      borshString("Eligibility"),
+      borshString("ELIG"),
+      borshString(""),
+      Buffer.from([0, 0]),
+      Buffer.from([0]),
+      collection ? Buffer.concat([Buffer.from([1, 0]), collection.toBuffer()]) : Buffer.from([0]),
+      Buffer.from([0]),
+      Buffer.from([1]),
+      Buffer.from([0]),
+    ]);
+    const wallet = harness.owner.publicKey;
+    await send([
+      new TransactionInstruction({
+        programId: METADATA_PROGRAM_ID,
+        keys: [
+          { pubkey: metadataAddress(mint), isSigner: false, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: wallet, isSigner: true, isWritable: false },
+          { pubkey: wallet, isSigner: true, isWritable: true },
+          { pubkey: wallet, isSigner: true, isWritable: false },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        ],
+        data,
+      }),
+    ]);
+
+    return { mint, account };
+  }
+
+  async function createMasterEdition(mint: PublicKey) {
+    const wallet = harness.owner.publicKey;
+    // CreateMasterEditionV3 with a max supply of 0.
+    await send([
+      new TransactionInstruction({
+        programId: METADATA_PROGRAM_ID,
+        keys: [
+          { pubkey: editionAddress(mint), isSigner: false, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: true },
+          { pubkey: wallet, isSigner: true, isWritable: false },
+          { pubkey: wallet, isSigner: true, isWritable: false },
+          { pubkey: wallet, isSigner: true, isWritable: true },
+          { pubkey: metadataAddress(mint), isSigner: false, isWritable: true },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([Buffer.from([17, 1]), Buffer.alloc(8)]),
+      }),
+    ]);
+  }
+
+  async function verifyCollection(mint: PublicKey, collectionMint: PublicKey) {
+    const wallet = harness.owner.publicKey;
+    // VerifyCollection, signed by the collection's update authority.
+    await send([
+      new TransactionInstruction({
+        programId: METADATA_PROGRAM_ID,
+        keys: [
+          { pubkey: metadataAddress(mint), isSigner: false, isWritable: true },
+          { pubkey: wallet, isSigner: true, isWritable: true },
+          { pubkey: wallet, isSigner: true, isWritable: true },
+          { pubkey: collectionMint, isSigner: false, isWritable: false },
+          { pubkey: metadataAddress(collectionMint), isSigner: false, isWritable: false },
+          { pubkey: editionAddress(collectionMint), isSigner: false, isWritable: false },
+        ],
+        data: Buffer.from([18]),
+      }),
+    ]);
+  }
+});
@@ -0,0 +1,617 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Shared localnet plumbing for the feature suites: provider and program setup,
+// Arcium account derivation, computation definition bootstrapping, the
+// create/submit/close/pay round trip most suites start from and the
+// draw/seat/vote/settle round trip of a jury panel.
+
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import { BountySolver } from "../target/types/bounty_solver";
+import { randomBytes } from "crypto";
+import {
+  awaitComputationFinalization,
+  getCompDefAccOffset,
+  getArciumProgAddress,
+  buildFinalizeCompDefTx,
+  RescueCipher,
+  deserializeLE,
+  getMXEAccAddress,
+  getMempoolAccAddress,
+  getCompDefAccAddress,
+  getExecutingPoolAccAddress,
+  x25519,
+  getComputationAccAddress,
+  getArciumAccountBaseSeed,
+  getClusterAccAddress,
+  getMXEPublicKey,
+} from "@arcium-hq/client";
+import * as fs from "fs";
+import * as os from "os";
+import { expect } from "chai";
+
+export const TOKEN_2022_PROGRAM_ID = new PublicKey(
+  "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+);
+export const TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+export const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
+  "ATokenGPvbdGVxr1b2hzZbsiqKZkbQfQHUXqRwnLbEA"
+);
+
+export type ProblemOptions = {
+  problemId: anchor.BN;
+  title: string;
+  description: string;
+  deadline: number;
+  bountyAmount: anchor.BN;
+  bountyMode: object;
+  computeBudget: anchor.BN;
+  judges: PublicKey[];
+  judgeThreshold: number;
+  eligibility: object;
+  creatorBond: object | null;
+  vesting: object | null;
+  puzzleHash: number[] | null;
+  verifier: PublicKey | null;
+  contentHash: number[] | null;
+  // `ProblemContent` chunks pinned by `contentHash`, in index order.
+  contentChunks: PublicKey[];
+  creator: Keypair;
+  // Suites pay out straight after closing, so problems default to no window.
+  disputeWindow: number;
+};
+
+export type PayOptions = {
+  isWinner: boolean;
+  vests?: boolean;
+  seasonId?: anchor.BN;
+};
+
+export type JuryOptions = {
+  panelSize: number;
+  voteDeadline: number;
+  jurorFee: number;
+};
+
+export class Harness {
+  readonly owner: Keypair;
+  readonly provider: anchor.AnchorProvider;
+  readonly program: anchor.Program<BountySolver>;
+  // Keypairs of the jurors this suite registered, by public key.
+  readonly jurors = new Map<string, Keypair>();
+  clusterAccount: PublicKey;
+
+  constructor() {
+    this.owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+
+    const connection = new anchor.web3.Connection("http://127.0.0.1:8899", "confirmed");
+    this.provider = new anchor.AnchorProvider(connection, new anchor.Wallet(this.owner), {
+      commitment: "confirmed",
+    });
+    anchor.setProvider(this.provider);
+
+    const idl = JSON.parse(fs.readFileSync("target/idl/bounty_solver.json", "utf8"));
+    this.program = new anchor.Program<BountySolver>(idl as anchor.Idl, this.provider);
+  }
+
+  /** Resolves the cluster and makes sure every circuit the suite queues is live. */
+  async setup(circuits: string[]) {
+    try {
+      const clusterData = JSON.parse(fs.readFileSync("artifacts/cluster_acc_0.json", "utf8"));
+      this.clusterAccount = new PublicKey(clusterData.pubkey);
+    } catch (error) {
+      this.clusterAccount = getClusterAccAddress(0);
+    }
+
+    for (const circuit of circuits) {
+      await this.ensureCompDef(circuit);
+    }
+  }
+
+  arciumAccounts(computationOffset: anchor.BN, circuit: string) {
+    const programId = this.program.programId;
+    return {
+      computationAccount: getComputationAccAddress(programId, computationOffset),
+      clusterAccount: this.clusterAccount,
+      mxeAccount: getMXEAccAddress(programId),
+      mempoolAccount: getMempoolAccAddress(programId),
+      executingPool: getExecutingPoolAccAddress(programId),
+      compDefAccount: getCompDefAccAddress(
+        programId,
+        Buffer.from(getCompDefAccOffset(circuit)).readUInt32LE()
+      ),
+    };
+  }
+
+  pda(...seeds: (Buffer | PublicKey | anchor.BN | string)[]): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      seeds.map((seed) => {
+        if (typeof seed === "string") return Buffer.from(seed);
+        if (seed instanceof PublicKey) return seed.toBuffer();
+        if (anchor.BN.isBN(seed)) return (seed as anchor.BN).toArrayLike(Buffer, "le", 8);
+        return seed as Buffer;
+      }),
+      this.program.programId
+    )[0];
+  }
+
+  problemAddress(problemId: anchor.BN): PublicKey {
+    return this.pda("problem", problemId);
+  }
+
+  solutionAddress(problemId: anchor.BN, solver: PublicKey): PublicKey {
+    return this.pda("solution", problemId, solver);
+  }
+
+  solutionIndexPage(problemId: anchor.BN, page: number): PublicKey {
+    return this.pda("solution_index", problemId, new anchor.BN(page));
+  }
+
+  badgeMint(problemId: anchor.BN): PublicKey {
+    return this.pda("badge", problemId);
+  }
+
+  async createProblem(options: Partial<ProblemOptions> = {}) {
+    const problemId = options.problemId ?? new anchor.BN(randomBytes(6));
+    const problemPDA = this.problemAddress(problemId);
+    const creator = options.creator ?? this.owner;
+    const judges = options.judges ?? [];
+    const offset = randomOffset();
+
+    await this.program.methods
+      .createProblem(
+        offset,
+        problemId,
+        options.title ?? "Test problem",
+        options.description ?? "A problem posted by the test suite",
+        new anchor.BN(options.deadline ?? now() + 60),
+        options.bountyAmount ?? new anchor.BN(0),
+        new anchor.BN(deserializeLE(randomBytes(16)).toString()),
+        (options.bountyMode ?? { standard: {} }) as any,
+        options.computeBudget ?? new anchor.BN(0),
+        judges,
+        options.judgeThreshold ?? 0,
+        (options.eligibility ?? { open: {} }) as any,
+        (options.creatorBond ?? null) as any,
+        (options.vesting ?? null) as any,
+        options.puzzleHash ?? null,
+        options.verifier ?? null,
+        options.contentHash ?? null
+      )
+      .accountsPartial({
+        creator: creator.publicKey,
+        ...this.arciumAccounts(offset, "initialize_problem"),
+        problem: problemPDA,
+        judgingPanel: judges.length > 0 ? this.pda("judges", problemId) : null,
+      })
+      .remainingAccounts(
+        (options.contentChunks ?? []).map((pubkey) => ({
+          pubkey,
+          isWritable: false,
+          isSigner: false,
+        }))
+      )
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+    await this.finalize(offset);
+
+    await this.program.methods
+      .setDisputeWindow(problemId, new anchor.BN(options.disputeWindow ?? 0))
+      .accountsPartial({ creator: creator.publicKey, problem: problemPDA })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+
+    return { problemId, problemPDA };
+  }
+
+  /** Encrypts `values` under a fresh key shared with the MXE. */
+  async encrypt(values: bigint[]) {
+    const mxePublicKey = await getMXEPublicKey(this.provider, this.program.programId);
+    const privateKey = x25519.utils.randomSecretKey();
+    const publicKey = x25519.getPublicKey(privateKey);
+    const cipher = new RescueCipher(x25519.getSharedSecret(privateKey, mxePublicKey));
+    const nonce = randomBytes(16);
+
+    return {
+      cipher,
+      ciphertexts: cipher.encrypt(values, nonce).map((ciphertext) => Array.from(ciphertext)),
+      publicKey: Array.from(publicKey),
+      nonce: new anchor.BN(deserializeLE(nonce).toString()),
+    };
+  }
+
+  async submitSolution(
+    problemId: anchor.BN,
+    solver: Keypair,
+    prediction: boolean,
+    amount: anchor.BN = new anchor.BN(0),
+    accounts: Record<string, PublicKey | null> = {},
+    allowlistProof: Buffer[] = [],
+    contentHash: bigint = deserializeLE(randomBytes(16))
+  ) {
+    const problem = await this.program.account.problem.fetch(this.problemAddress(problemId));
+    const { cipher, ciphertexts, publicKey, nonce } = await this.encrypt([
+      BigInt(prediction ? 1 : 0),
+    ]);
+    // The content hash is encrypted under the same key as the prediction.
+    const hashNonce = randomBytes(16);
+    const encryptedHash = cipher.encrypt([contentHash], hashNonce);
+    const offset = randomOffset();
+
+    await this.program.methods
+      .submitSolution(
+        offset,
+        problemId,
+        amount,
+        ciphertexts[0],
+        publicKey,
+        nonce,
+        Array.from(encryptedHash[0]),
+        new anchor.BN(deserializeLE(hashNonce).toString()),
+        allowlistProof.map((node) => Array.from(node))
+      )
+      .accountsPartial({
+        solver: solver.publicKey,
+        ...this.arciumAccounts(offset, "submit_solution"),
+        problem: this.problemAddress(problemId),
+        solutionIndexPage: this.solutionIndexPage(
+          problemId,
+          problem.totalSolutions.divn(SOLUTIONS_PER_PAGE).toNumber()
+        ),
+        eligibilityToken: null,
+        eligibilityMetadata: null,
+        ...accounts,
+      })
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+    await this.finalize(offset);
+  }
+
+  closeProblem(problemId: anchor.BN, winner: PublicKey, creator: Keypair = this.owner) {
+    return this.program.methods
+      .closeProblem(problemId, winner)
+      .accountsPartial({
+        authority: creator.publicKey,
+        problem: this.problemAddress(problemId),
+        judgingPanel: null,
+      })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async payWinner(problemId: anchor.BN, solver: Keypair, options: PayOptions) {
+    const offset = randomOffset();
+    const badgeMint = this.badgeMint(problemId);
+    const seasonId = options.seasonId;
+
+    await this.program.methods
+      .payWinner(offset, problemId)
+      .accountsPartial({
+        solver: solver.publicKey,
+        ...this.arciumAccounts(offset, "pay_winner"),
+        problem: this.problemAddress(problemId),
+        vesting: options.vests ? this.pda("vesting", problemId, solver.publicKey) : null,
+        badgeMint: options.isWinner ? badgeMint : null,
+        badgeAccount: options.isWinner ? badgeAccount(solver.publicKey, badgeMint) : null,
+        season: seasonId ? this.pda("season", seasonId) : null,
+        seasonEntry: seasonId ? this.pda("season_entry", seasonId, solver.publicKey) : null,
+      })
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+    await this.finalize(offset);
+  }
+
+  /** Stakes `count` fresh jurors in the global pool; suites must leave it again. */
+  async registerJurors(count: number, stake = MIN_JUROR_STAKE): Promise<Keypair[]> {
+    const jurors = await this.funded(count);
+    for (const juror of jurors) {
+      await this.program.methods
+        .registerJuror(new anchor.BN(stake))
+        .accountsPartial({ authority: juror.publicKey })
+        .signers([juror])
+        .rpc({ commitment: "confirmed" });
+      this.jurors.set(juror.publicKey.toBase58(), juror);
+    }
+    return jurors;
+  }
+
+  async leaveJurorPool() {
+    for (const juror of this.jurors.values()) {
+      await this.program.methods
+        .leaveJurorPool()
+        .accountsPartial({ authority: juror.publicKey })
+        .signers([juror])
+        .rpc({ commitment: "confirmed" });
+    }
+    this.jurors.clear();
+  }
+
+  juror(authority: PublicKey): Keypair {
+    return this.jurors.get(authority.toBase58());
+  }
+
+  juryPanel(problemId: anchor.BN, index: number): PublicKey {
+    return this.pda("jury", problemId, new anchor.BN(index));
+  }
+
+  /** Opens the problem's next jury panel through `assign_jurors`. */
+  async assignJurors(problemId: anchor.BN, options: JuryOptions, creator: Keypair = this.owner) {
+    const problem = await this.program.account.problem.fetch(this.problemAddress(problemId));
+    const panel = this.juryPanel(problemId, problem.juryCount.toNumber());
+
+    await this.program.methods
+      .assignJurors(
+        problemId,
+        options.panelSize,
+        new anchor.BN(options.voteDeadline),
+        new anchor.BN(options.jurorFee)
+      )
+      .accountsPartial({
+        authority: creator.publicKey,
+        problem: this.problemAddress(problemId),
+        panel,
+      })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+
+    return panel;
+  }
+
+  /** Draws the panel's jurors and seats them, returning them in seat order. */
+  async seatJury(problemId: anchor.BN, panel: PublicKey): Promise<PublicKey[]> {
+    const offset = randomOffset();
+    await this.program.methods
+      .drawJury(offset, problemId, new anchor.BN(deserializeLE(randomBytes(16)).toString()))
+      .accountsPartial({
+        payer: this.owner.publicKey,
+        ...this.arciumAccounts(offset, "draw_jury"),
+        problem: this.problemAddress(problemId),
+        panel,
+      })
+      .rpc({ commitment: "confirmed" });
+    await this.finalize(offset);
+
+    const drawn = (await this.program.account.juryPanel.fetch(panel)).jurors;
+    await this.program.methods
+      .seatJurors(problemId)
+      .accountsPartial({ payer: this.owner.publicKey, panel })
+      .remainingAccounts(this.jurorAccounts(drawn))
+      .rpc({ commitment: "confirmed" });
+
+    return drawn;
+  }
+
+  async castJurorVote(
+    problemId: anchor.BN,
+    panel: PublicKey,
+    juror: Keypair,
+    overturn: boolean
+  ) {
+    const { ciphertexts, publicKey, nonce } = await this.encrypt([BigInt(overturn ? 1 : 0)]);
+    const offset = randomOffset();
+
+    await this.program.methods
+      .submitJurorVote(offset, problemId, ciphertexts[0], publicKey, nonce)
+      .accountsPartial({
+        juror: juror.publicKey,
+        ...this.arciumAccounts(offset, "submit_quorum_vote"),
+        problem: this.problemAddress(problemId),
+        panel,
+        jurorVote: this.pda("juror_vote", problemId, juror.publicKey),
+      })
+      .signers([juror])
+      .rpc({ commitment: "confirmed" });
+    await this.finalize(offset);
+  }
+
+  async resolveJury(problemId: anchor.BN, panel: PublicKey) {
+    const offset = randomOffset();
+    await this.program.methods
+      .resolveJury(offset, problemId)
+      .accountsPartial({
+        payer: this.owner.publicKey,
+        ...this.arciumAccounts(offset, "resolve_market"),
+        problem: this.problemAddress(problemId),
+        panel,
+      })
+      .rpc({ commitment: "confirmed" });
+    await this.finalize(offset);
+  }
+
+  /** Checks each listed juror's vote against the panel's outcome. */
+  async settleJurorVotes(problemId: anchor.BN, panel: PublicKey, jurors: PublicKey[]) {
+    for (const juror of jurors) {
+      const offset = randomOffset();
+      await this.program.methods
+        .settleJurorVote(offset, problemId)
+        .accountsPartial({
+          payer: this.owner.publicKey,
+          ...this.arciumAccounts(offset, "check_juror_vote"),
+          problem: this.problemAddress(problemId),
+          panel,
+          juror: this.pda("juror", juror),
+          jurorVote: this.pda("juror_vote", problemId, juror),
+        })
+        .rpc({ commitment: "confirmed" });
+      await this.finalize(offset);
+    }
+  }
+
+  /** Pays the settled panel's majority and closes it, returning who was paid. */
+  async closeJury(problemId: anchor.BN, panel: PublicKey): Promise<PublicKey[]> {
+    const { jurors, majority, funder } = await this.program.account.juryPanel.fetch(panel);
+    const rewarded = jurors.filter((_, seat) => (majority & (1 << seat)) !== 0);
+
+    await this.program.methods
+      .closeJury(problemId)
+      .accountsPartial({ payer: this.owner.publicKey, panel, funder })
+      .remainingAccounts(this.jurorAccounts(rewarded))
+      .rpc({ commitment: "confirmed" });
+
+    return rewarded;
+  }
+
+  jurorAccounts(jurors: PublicKey[]) {
+    return jurors.map((juror) => ({
+      pubkey: this.pda("juror", juror),
+      isWritable: true,
+      isSigner: false,
+    }));
+  }
+
+  finalize(offset: anchor.BN) {
+    return awaitComputationFinalization(
+      this.provider,
+      offset,
+      this.program.programId,
+      "confirmed"
+    );
+  }
+
+  async fund(pubkey: PublicKey, sol = 2) {
+    const sig = await this.provider.connection.requestAirdrop(
+      pubkey,
+      sol * anchor.web3.LAMPORTS_PER_SOL
+    );
+    await this.provider.connection.confirmTransaction(sig, "confirmed");
+  }
+
+  async funded(count: number): Promise<Keypair[]> {
+    const keypairs = Array.from({ length: count }, () => Keypair.generate());
+    for (const keypair of keypairs) {
+      await this.fund(keypair.publicKey);
+    }
+    return keypairs;
+  }
+
+  balance(pubkey: PublicKey): Promise<number> {
+    return this.provider.connection.getBalance(pubkey);
+  }
+
+  /** Waits for the validator clock, not just the wall clock, to pass `timestamp`. */
+  async waitUntil(timestamp: number) {
+    for (;;) {
+      const slot = await this.provider.connection.getSlot("confirmed");
+      const blockTime = await this.provider.connection.getBlockTime(slot);
+      if (blockTime !== null && blockTime > timestamp) {
+        return;
+      }
+      await sleep(1000);
+    }
+  }
+
+  awaitEvent<E extends keyof anchor.IdlEvents<BountySolver>>(
+    eventName: E,
+    timeoutMs = 60000
+  ): Promise<anchor.IdlEvents<BountySolver>[E]> {
+    return new Promise((resolve, reject) => {
+      let listenerId: number;
+      const timeoutId = setTimeout(() => {
+        this.program.removeEventListener(listenerId);
+        reject(new Error(`Event ${String(eventName)} timed out after ${timeoutMs}ms`));
+      }, timeoutMs);
+      listenerId = this.program.addEventListener(eventName as any, (event) => {
+        clearTimeout(timeoutId);
+        this.program.removeEventListener(listenerId);
+        resolve(event as any);
+      });
+    });
+  }
+
+  async ensureCompDef(circuit: string) {
+    const offset = getCompDefAccOffset(circuit);
+    const compDefPDA = PublicKey.findProgramAddressSync(
+      [
+        getArciumAccountBaseSeed("ComputationDefinitionAccount"),
+        this.program.programId.toBuffer(),
+        offset,
+      ],
+      getArciumProgAddress()
+    )[0];
+
+    if (await this.provider.connection.getAccountInfo(compDefPDA)) {
+      return;
+    }
+
+    const method = `init${circuit
+      .split("_")
+      .map((part) => part.charAt(0).toUpperCase() + part.slice(1))
+      .join("")}CompDef`;
+
+    await (this.program.methods as any)
+      [method](null)
+      .accounts({
+        compDefAccount: compDefPDA,
+        payer: this.owner.publicKey,
+        mxeAccount: getMXEAccAddress(this.program.programId),
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const finalizeTx = await buildFinalizeCompDefTx(
+      this.provider,
+      Buffer.from(offset).readUInt32LE(),
+      this.program.programId
+    );
+    const latestBlockhash = await this.provider.connection.getLatestBlockhash();
+    finalizeTx.recentBlockhash = latestBlockhash.blockhash;
+    finalizeTx.lastValidBlockHeight = latestBlockhash.lastValidBlockHeight;
+    finalizeTx.sign(this.owner);
+    await this.provider.sendAndConfirm(finalizeTx, [this.owner], { commitment: "confirmed" });
+  }
+}
+
+/** Mirrors `SOLUTIONS_PER_PAGE` in the program. */
+export const SOLUTIONS_PER_PAGE = 32;
+
+/** Mirrors `MIN_JUROR_STAKE` in the program. */
+export const MIN_JUROR_STAKE = 100_000_000;
+
+export function badgeAccount(owner: PublicKey, mint: PublicKey): PublicKey {
+  return associatedTokenAccount(owner, mint, TOKEN_2022_PROGRAM_ID);
+}
+
+export function associatedTokenAccount(
+  owner: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [owner.toBuffer(), tokenProgram.toBuffer(), mint.toBuffer()],
+    ASSOCIATED_TOKEN_PROGRAM_ID
+  )[0];
+}
+
+export async function expectError(promise: Promise<unknown>, code: string) {
+  try {
+    await promise;
+  } catch (error) {
+    const message = error.error?.errorCode?.code ?? `${error.toString()} ${error.logs ?? ""}`;
+    expect(message).to.contain(code);
+    return;
+  }
+  expect.fail(`expected ${code}`);
+}
+
+export function randomOffset(): anchor.BN {
+  return new anchor.BN(randomBytes(8), "hex");
+}
+
+export function now(): number {
+  return Math.floor(Date.now() / 1000);
+}
+
+export function sleep(ms: number) {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+export function readKpJson(path: string): Keypair {
+  const file = fs.readFileSync(path);
+  return Keypair.fromSecretKey(new Uint8Array(JSON.parse(file.toString())));
+}
@@ -0,0 +1,162 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Judging panels: judges pick a solution in the MXE, the pick that takes a
+// solution to the threshold closes the problem, and a panel where everyone
+// picked without agreeing hands the decision back to the creator.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import {
+  RescueCipher,
+  deserializeLE,
+  getMXEPublicKey,
+  x25519,
+} from "@arcium-hq/client";
+import { Harness, expectError, now, randomOffset } from "./Harness";
+
+describe("Judging panels", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "submit_solution",
+      "initialize_judge_tally",
+      "submit_judge_pick",
+    ]);
+  });
+
+  it("closes the problem on the pick that reaches the threshold", async () => {
+    const judges = await harness.funded(3);
+    const { problemId, problemPDA } = await panelProblem(judges, 2, 2);
+
+    await expectError(pick(problemId, judges[0], 0), "JudgingPanelNotOpened");
+
+    // Picks wait for the MXE to write the empty tally.
+    const offset = await open(problemId, judges[0]);
+    await expectError(pick(problemId, judges[0], 0), "JudgeTallyNotInitialized");
+    await harness.finalize(offset);
+
+    await pick(problemId, judges[0], 1);
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ active: {} });
+    await expectError(pick(problemId, judges[0], 1), "AlreadyVoted");
+
+    await pick(problemId, judges[1], 1);
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ closed: {} });
+    expect(problem.winningIndex.toNumber()).to.equal(1);
+
+    const panel = await program.account.judgingPanel.fetch(panelAddress(problemId));
+    expect(panel.decided).to.equal(true);
+    expect(panel.picksCast).to.equal(2);
+
+    await expectError(pick(problemId, judges[2], 0), "ProblemNotActive");
+  });
+
+  it("hands a deadlocked panel back to the creator", async () => {
+    const judges = await harness.funded(3);
+    const { problemId, problemPDA, solvers } = await panelProblem(judges, 2, 3);
+    await harness.finalize(await open(problemId, judges[0]));
+
+    await pick(problemId, judges[0], 0);
+    await pick(problemId, judges[1], 1);
+
+    // Until the last judge has picked, the panel can still decide.
+    await expectError(close(problemId, solvers[2].publicKey), "PanelStillJudging");
+
+    await pick(problemId, judges[2], 2);
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ active: {} });
+
+    await close(problemId, solvers[2].publicKey);
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ closed: {} });
+    expect(problem.winner.toBase58()).to.equal(solvers[2].publicKey.toBase58());
+  });
+
+  async function panelProblem(judges: Keypair[], threshold: number, solutions: number) {
+    const deadline = now() + 30;
+    const { problemId, problemPDA } = await harness.createProblem({
+      deadline,
+      judges: judges.map((judge) => judge.publicKey),
+      judgeThreshold: threshold,
+    });
+
+    const solvers = await harness.funded(solutions);
+    for (const solver of solvers) {
+      await harness.submitSolution(problemId, solver, true);
+    }
+    await harness.waitUntil(deadline);
+
+    return { problemId, problemPDA, solvers };
+  }
+
+  function panelAddress(problemId: anchor.BN): PublicKey {
+    return harness.pda("judges", problemId);
+  }
+
+  async function open(problemId: anchor.BN, judge: Keypair) {
+    const offset = randomOffset();
+
+    await program.methods
+      .openJudgingPanel(offset, problemId, new anchor.BN(deserializeLE(randomBytes(16)).toString()))
+      .accountsPartial({
+        authority: judge.publicKey,
+        ...harness.arciumAccounts(offset, "initialize_judge_tally"),
+        problem: harness.problemAddress(problemId),
+        judgingPanel: panelAddress(problemId),
+      })
+      .signers([judge])
+      .rpc({ commitment: "confirmed" });
+
+    return offset;
+  }
+
+  async function pick(problemId: anchor.BN, judge: Keypair, index: number) {
+    const mxePublicKey = await getMXEPublicKey(harness.provider, program.programId);
+    const privateKey = x25519.utils.randomSecretKey();
+    const publicKey = x25519.getPublicKey(privateKey);
+    const cipher = new RescueCipher(x25519.getSharedSecret(privateKey, mxePublicKey));
+    const nonce = randomBytes(16);
+    const ciphertext = cipher.encrypt([BigInt(index)], nonce);
+    const offset = randomOffset();
+
+    await program.methods
+      .submitJudgePick(
+        offset,
+        problemId,
+        Array.from(ciphertext[0]),
+        Array.from(publicKey),
+        new anchor.BN(deserializeLE(nonce).toString())
+      )
+      .accountsPartial({
+        judge: judge.publicKey,
+        ...harness.arciumAccounts(offset, "submit_judge_pick"),
+        problem: harness.problemAddress(problemId),
+        judgingPanel: panelAddress(problemId),
+      })
+      .signers([judge])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  function close(problemId: anchor.BN, winner: PublicKey) {
+    return program.methods
+      .closeProblem(problemId, winner)
+      .accountsPartial({
+        authority: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        judgingPanel: panelAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
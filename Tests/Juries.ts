@@ -0,0 +1,157 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Juries: jurors stake into the pool with `register_juror`, are drawn and
+// locked onto a panel by `draw_jury` and `seat_jurors`, and once the panel is
+// resolved lose part of their stake for voting against the outcome or not
+// voting at all. `close_jury` splits the fee and the slashed stake among the
+// majority.
+
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { Harness, MIN_JUROR_STAKE, expectError, now } from "./Harness";
+
+// Mirrors `JUROR_SLASH_BPS` in the program.
+const JUROR_SLASH_BPS = 1_000;
+
+const PANEL_SIZE = 3;
+const JUROR_FEE = 30_000_000;
+
+describe("Juries", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "draw_jury",
+      "submit_quorum_vote",
+      "resolve_market",
+      "check_juror_vote",
+    ]);
+    await harness.registerJurors(PANEL_SIZE);
+  });
+
+  // Every panel is settled and closed by its test, so the jurors are free to leave.
+  after(async () => {
+    await harness.leaveJurorPool();
+  });
+
+  it("only takes jurors who stake at least the minimum", async () => {
+    await expectError(harness.registerJurors(1, MIN_JUROR_STAKE - 1), "InsufficientStake");
+  });
+
+  it("slashes the minority and pays the majority the fee and the slashed stake", async () => {
+    const { problemId } = await harness.createProblem();
+    await expectError(assign(problemId, 0, now() + 600), "InvalidPanelSize");
+    const panel = await assign(problemId, PANEL_SIZE, now() + 600);
+
+    const drawn = await harness.seatJury(problemId, panel);
+    expect(drawn).to.have.lengthOf(PANEL_SIZE);
+    for (const juror of drawn) {
+      expect((await jurorAccount(juror)).activePanels).to.equal(1);
+    }
+    // Seated jurors are locked until the panel settles them.
+    await expectError(leave(drawn[0]), "JurorBusy");
+
+    const votes = [true, true, false];
+    for (const [seat, juror] of drawn.entries()) {
+      await harness.castJurorVote(problemId, panel, harness.juror(juror), votes[seat]);
+    }
+    await harness.resolveJury(problemId, panel);
+    expect((await program.account.juryPanel.fetch(panel)).outcome).to.equal(true);
+
+    const stakes = await Promise.all(drawn.map(async (juror) => (await jurorAccount(juror)).stake));
+    await expectError(harness.closeJury(problemId, panel), "PanelNotSettled");
+    await harness.settleJurorVotes(problemId, panel, drawn);
+    await expectError(
+      harness.settleJurorVotes(problemId, panel, [drawn[0]]),
+      "JurorAlreadySettled"
+    );
+
+    const minority = await jurorAccount(drawn[2]);
+    const slashed = slash(stakes[2]);
+    expect(minority.stake.toNumber()).to.equal(stakes[2].toNumber() - slashed);
+    expect(minority.activePanels).to.equal(0);
+    const { rewardPool } = await program.account.juryPanel.fetch(panel);
+    expect(rewardPool.toNumber()).to.equal(JUROR_FEE + slashed);
+
+    const rewarded = await harness.closeJury(problemId, panel);
+    expect(rewarded.map((juror) => juror.toBase58())).to.deep.equal(
+      drawn.slice(0, 2).map((juror) => juror.toBase58())
+    );
+    const reward = Math.floor((JUROR_FEE + slashed) / 2);
+    for (const [seat, juror] of drawn.slice(0, 2).entries()) {
+      const account = await jurorAccount(juror);
+      expect(account.stake.toNumber()).to.equal(stakes[seat].toNumber() + reward);
+      expect(account.activePanels).to.equal(0);
+    }
+    expect(await program.account.juryPanel.fetchNullable(panel)).to.equal(null);
+  });
+
+  it("slashes a seated juror who doesn't vote", async () => {
+    const { problemId } = await harness.createProblem();
+    const voteDeadline = now() + 45;
+    const panel = await assign(problemId, PANEL_SIZE, voteDeadline);
+    const drawn = await harness.seatJury(problemId, panel);
+
+    const [absent, ...voters] = drawn;
+    for (const juror of voters) {
+      await harness.castJurorVote(problemId, panel, harness.juror(juror), false);
+    }
+    await expectError(settleAbsent(problemId, panel, absent), "DeadlineNotReached");
+    await harness.waitUntil(voteDeadline);
+    await harness.resolveJury(problemId, panel);
+    await harness.settleJurorVotes(problemId, panel, voters);
+
+    // Voters settle through `settle_juror_vote` only.
+    await expectError(settleAbsent(problemId, panel, voters[0]), "AlreadyVoted");
+
+    const stake = (await jurorAccount(absent)).stake;
+    await settleAbsent(problemId, panel, absent);
+    const account = await jurorAccount(absent);
+    expect(account.stake.toNumber()).to.equal(stake.toNumber() - slash(stake));
+    expect(account.amountSlashed.toNumber()).to.be.greaterThan(0);
+    expect(account.activePanels).to.equal(0);
+
+    await harness.closeJury(problemId, panel);
+  });
+
+  function slash(stake: anchor.BN) {
+    return Math.floor((stake.toNumber() * JUROR_SLASH_BPS) / 10_000);
+  }
+
+  function jurorAccount(authority: PublicKey) {
+    return program.account.juror.fetch(harness.pda("juror", authority));
+  }
+
+  function assign(problemId: anchor.BN, panelSize: number, voteDeadline: number) {
+    return harness.assignJurors(problemId, { panelSize, voteDeadline, jurorFee: JUROR_FEE });
+  }
+
+  function leave(authority: PublicKey) {
+    const juror = harness.juror(authority);
+    return program.methods
+      .leaveJurorPool()
+      .accountsPartial({ authority: juror.publicKey })
+      .signers([juror])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function settleAbsent(problemId: anchor.BN, panel: PublicKey, juror: PublicKey) {
+    return program.methods
+      .settleJuror(problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        panel,
+        juror: harness.pda("juror", juror),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
@@ -0,0 +1,167 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Milestones: `create_milestone_plan` escrows an ordered list of milestone
+// amounts, the creator selects one solver to deliver them, each approval
+// releases that milestone's amount, and whatever wasn't delivered by the
+// delivery deadline goes back to the creator.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+describe("Milestones", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution"]);
+  });
+
+  it("releases each milestone's escrow as the creator approves it in order", async () => {
+    const { problemId, problemPDA } = await harness.createProblem({ deadline: now() + 60 });
+    await createPlan(problemId, [1_000_000, 2_000_000], now() + 600);
+
+    const [solver, other] = await harness.funded(2);
+    await harness.submitSolution(problemId, solver, true);
+    await harness.submitSolution(problemId, other, true);
+    await select(problemId, solver.publicKey);
+    await expectError(select(problemId, other.publicKey), "MilestoneSolverSelected");
+
+    await expectError(submit(problemId, other, 0), "UnauthorizedAccess");
+    await expectError(submit(problemId, solver, 1), "MilestoneOutOfOrder");
+    await expectError(approve(problemId, solver.publicKey, 0), "MilestoneNotSubmitted");
+
+    for (const [index, amount] of [1_000_000, 2_000_000].entries()) {
+      await submit(problemId, solver, index);
+      const before = await harness.balance(solver.publicKey);
+      await approve(problemId, solver.publicKey, index);
+      expect((await harness.balance(solver.publicKey)) - before).to.equal(amount);
+    }
+
+    const plan = await program.account.milestonePlan.fetch(planAddress(problemId));
+    expect(plan.milestones.map((milestone) => milestone.status)).to.deep.equal([
+      { approved: {} },
+      { approved: {} },
+    ]);
+    expect(plan.released.toNumber()).to.equal(3_000_000);
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+
+    // An approved milestone is paid out once.
+    await expectError(approve(problemId, solver.publicKey, 0), "MilestoneNotSubmitted");
+  });
+
+  it("refunds the milestones not delivered by the delivery deadline", async () => {
+    const deadline = now() + 20;
+    const { problemId, problemPDA } = await harness.createProblem({ deadline });
+    const deliveryDeadline = deadline + 40;
+    await createPlan(problemId, [1_000_000, 2_000_000, 3_000_000], deliveryDeadline);
+
+    const [solver] = await harness.funded(1);
+    await harness.submitSolution(problemId, solver, true);
+    await select(problemId, solver.publicKey);
+    await submit(problemId, solver, 0);
+    await approve(problemId, solver.publicKey, 0);
+    // Submitted but never approved, so it is refunded with the pending one.
+    await submit(problemId, solver, 1);
+
+    await expectError(refund(problemId), "DeadlineNotReached");
+    await harness.waitUntil(deliveryDeadline);
+    await expectError(submit(problemId, solver, 1), "DeadlinePassed");
+
+    const refunded = harness.awaitEvent("milestonesRefundedEvent");
+    await refund(problemId);
+    expect((await refunded).amount.toNumber()).to.equal(5_000_000);
+
+    const plan = await program.account.milestonePlan.fetch(planAddress(problemId));
+    expect(plan.milestones.map((milestone) => milestone.status)).to.deep.equal([
+      { approved: {} },
+      { refunded: {} },
+      { refunded: {} },
+    ]);
+    expect(plan.released.toNumber()).to.equal(1_000_000);
+    expect(plan.refunded.toNumber()).to.equal(5_000_000);
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+
+    await expectError(refund(problemId), "NothingToRefund");
+  });
+
+  it("only accepts plans delivered after the problem's deadline", async () => {
+    const deadline = now() + 60;
+    const { problemId } = await harness.createProblem({ deadline });
+
+    await expectError(createPlan(problemId, [], deadline + 60), "InvalidMilestonePlan");
+    await expectError(createPlan(problemId, [1_000_000, 0], deadline + 60), "InvalidAmount");
+    await expectError(createPlan(problemId, [1_000_000], deadline), "InvalidDeadline");
+  });
+
+  function planAddress(problemId: anchor.BN) {
+    return harness.pda("milestones", problemId);
+  }
+
+  function createPlan(problemId: anchor.BN, amounts: number[], deliveryDeadline: number) {
+    return program.methods
+      .createMilestonePlan(
+        problemId,
+        amounts.map((amount) => new anchor.BN(amount)),
+        new anchor.BN(deliveryDeadline)
+      )
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        milestonePlan: planAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function select(problemId: anchor.BN, solver: PublicKey) {
+    return program.methods
+      .selectMilestoneSolver(problemId, solver)
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        milestonePlan: planAddress(problemId),
+        solution: harness.solutionAddress(problemId, solver),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function submit(problemId: anchor.BN, solver: Keypair, index: number) {
+    return program.methods
+      .submitMilestone(problemId, index, Array.from(randomBytes(32)))
+      .accountsPartial({ solver: solver.publicKey, milestonePlan: planAddress(problemId) })
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function approve(problemId: anchor.BN, solver: PublicKey, index: number) {
+    return program.methods
+      .approveMilestone(problemId, index)
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        milestonePlan: planAddress(problemId),
+        solver,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function refund(problemId: anchor.BN) {
+    return program.methods
+      .refundMilestones(problemId)
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        milestonePlan: planAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
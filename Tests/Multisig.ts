@@ -0,0 +1,232 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Multisig creators: once a problem is assigned to a multisig, updating,
+// cancelling and every creator refund only happen through proposals that
+// reach the threshold, and refunds go to the recipient the members approved.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+const ESCROW = new anchor.BN(300_000_000);
+const COMPUTE_BUDGET = new anchor.BN(20_000_000);
+const CREATOR_BOND = new anchor.BN(100_000_000);
+
+describe("Multisig creators", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution"]);
+  });
+
+  it("only lets the creator push the deadline back once solutions are in", async () => {
+    const [creator, solver] = await harness.funded(2);
+    const deadline = now() + 60;
+    const { problemId, problemPDA } = await harness.createProblem({ creator, deadline });
+
+    await update(problemId, creator, { title: "Renamed", description: null, deadline: null });
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.title).to.equal("Renamed");
+
+    // Once someone has submitted, only a later deadline is allowed.
+    await harness.submitSolution(problemId, solver, true);
+    await expectError(
+      update(problemId, creator, { title: "Bait", description: null, deadline: null }),
+      "ProblemHasSolutions"
+    );
+    await expectError(
+      update(problemId, creator, { title: null, description: null, deadline: new anchor.BN(deadline - 10) }),
+      "InvalidDeadline"
+    );
+    await update(problemId, creator, {
+      title: null,
+      description: null,
+      deadline: new anchor.BN(deadline + 60),
+    });
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.deadline.toNumber()).to.equal(deadline + 60);
+
+    await expectError(cancel(problemId, creator), "ProblemHasSolutions");
+  });
+
+  it("refunds escrow, compute budget and bond on cancel", async () => {
+    const [creator] = await harness.funded(1);
+    const { problemId, problemPDA } = await escrowedProblem(creator);
+
+    const before = await harness.balance(creator.publicKey);
+    await cancel(problemId, creator);
+    const after = await harness.balance(creator.publicKey);
+
+    expect(after - before).to.equal(ESCROW.add(COMPUTE_BUDGET).add(CREATOR_BOND).toNumber());
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ cancelled: {} });
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+    expect(problem.computeBudget.toNumber()).to.equal(0);
+    expect(problem.bondStatus).to.deep.equal({ returned: {} });
+  });
+
+  it("routes updates and cancels through threshold-approved proposals", async () => {
+    const [creator, alice, bob, carol, treasury] = await harness.funded(5);
+    const { problemId, problemPDA } = await escrowedProblem(creator);
+    const multisig = await createMultisig([alice, bob, carol], 2);
+    await assign(problemId, creator, multisig);
+
+    // The original creator no longer acts for the problem.
+    await expectError(cancel(problemId, creator), "UnauthorizedAccess");
+
+    const extended = now() + 120;
+    const update = await propose(multisig, problemId, alice, {
+      updateProblem: {
+        update: { title: null, description: null, deadline: new anchor.BN(extended) },
+      },
+    });
+    await expectError(execute(multisig, problemId, update), "ApprovalThresholdNotReached");
+    await approve(multisig, update, bob);
+    await execute(multisig, problemId, update);
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.deadline.toNumber()).to.equal(extended);
+
+    const cancelProposal = await propose(multisig, problemId, bob, {
+      cancelProblem: { recipient: treasury.publicKey },
+    });
+    await approve(multisig, cancelProposal, carol);
+
+    // Refunds only go where the members agreed.
+    await expectError(
+      execute(multisig, problemId, cancelProposal, creator.publicKey),
+      "InvalidRecipient"
+    );
+    await expectError(execute(multisig, problemId, cancelProposal), "InvalidRecipient");
+
+    const before = await harness.balance(treasury.publicKey);
+    await execute(multisig, problemId, cancelProposal, treasury.publicKey);
+    const after = await harness.balance(treasury.publicKey);
+
+    expect(after - before).to.equal(ESCROW.add(COMPUTE_BUDGET).add(CREATOR_BOND).toNumber());
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ cancelled: {} });
+
+    await expectError(
+      execute(multisig, problemId, cancelProposal, treasury.publicKey),
+      "ProposalExecuted"
+    );
+  });
+
+  function escrowedProblem(creator: Keypair) {
+    // A vested bounty is escrowed up front, so the problem holds all three.
+    return harness.createProblem({
+      creator,
+      deadline: now() + 60,
+      bountyAmount: ESCROW,
+      computeBudget: COMPUTE_BUDGET,
+      vesting: { cliff: new anchor.BN(0), duration: new anchor.BN(60) },
+      creatorBond: {
+        amount: CREATOR_BOND,
+        slashBps: 5_000,
+        reviewWindow: new anchor.BN(24 * 60 * 60),
+      },
+    });
+  }
+
+  function update(problemId: anchor.BN, creator: Keypair, fields: object) {
+    return program.methods
+      .updateProblem(problemId, fields as any)
+      .accountsPartial({ creator: creator.publicKey, problem: harness.problemAddress(problemId) })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function cancel(problemId: anchor.BN, creator: Keypair) {
+    return program.methods
+      .cancelProblem(problemId)
+      .accountsPartial({ creator: creator.publicKey, problem: harness.problemAddress(problemId) })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async function createMultisig(members: Keypair[], threshold: number) {
+    const multisigId = new anchor.BN(randomBytes(6));
+    const multisig = harness.pda("multisig", multisigId);
+
+    await program.methods
+      .createMultisig(multisigId, members.map((member) => member.publicKey), threshold)
+      .accountsPartial({ payer: harness.owner.publicKey, multisig })
+      .rpc({ commitment: "confirmed" });
+
+    return multisig;
+  }
+
+  function assign(problemId: anchor.BN, creator: Keypair, multisig: PublicKey) {
+    return program.methods
+      .assignProblemToMultisig(problemId)
+      .accountsPartial({
+        creator: creator.publicKey,
+        problem: harness.problemAddress(problemId),
+        multisig,
+      })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async function propose(
+    multisig: PublicKey,
+    problemId: anchor.BN,
+    member: Keypair,
+    action: object
+  ) {
+    const { proposalCount } = await program.account.multisig.fetch(multisig);
+
+    await program.methods
+      .createProposal(problemId, action as any)
+      .accountsPartial({
+        member: member.publicKey,
+        multisig,
+        problem: harness.problemAddress(problemId),
+        proposal: harness.pda("proposal", multisig, proposalCount),
+      })
+      .signers([member])
+      .rpc({ commitment: "confirmed" });
+
+    return proposalCount;
+  }
+
+  function approve(multisig: PublicKey, index: anchor.BN, member: Keypair) {
+    return program.methods
+      .approveProposal(index)
+      .accountsPartial({
+        member: member.publicKey,
+        multisig,
+        proposal: harness.pda("proposal", multisig, index),
+      })
+      .signers([member])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function execute(
+    multisig: PublicKey,
+    problemId: anchor.BN,
+    index: anchor.BN,
+    recipient: PublicKey | null = null
+  ) {
+    return program.methods
+      .executeProposal(problemId, index)
+      .accountsPartial({
+        executor: harness.owner.publicKey,
+        multisig,
+        proposal: harness.pda("proposal", multisig, index),
+        problem: harness.problemAddress(problemId),
+        judgingPanel: null,
+        milestonePlan: null,
+        recipient,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
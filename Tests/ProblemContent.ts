@@ -0,0 +1,106 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Problem content: long descriptions are written to `ProblemContent` chunks
+// with `write_problem_content` before the problem exists, then pinned by
+// `create_problem` through the hash of the chunks' concatenated bytes.
+
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { createHash, randomBytes } from "crypto";
+import { expect } from "chai";
+import { Harness, expectError } from "./Harness";
+
+describe("Problem content", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem"]);
+  });
+
+  it("only accepts a hash of every chunk, in index order", async () => {
+    const problemId = new anchor.BN(randomBytes(6));
+    const { first, second, chunks } = await writeStatement(problemId);
+
+    await expectError(
+      harness.createProblem({
+        problemId,
+        contentHash: Array.from(sha256(first)),
+        contentChunks: chunks,
+      }),
+      "ContentHashMismatch"
+    );
+    await expectError(
+      harness.createProblem({
+        problemId,
+        contentHash: Array.from(sha256(first, second)),
+        contentChunks: [...chunks].reverse(),
+      }),
+      "InvalidProblemContent"
+    );
+  });
+
+  it("pins the written chunks and seals them once the problem exists", async () => {
+    const problemId = new anchor.BN(randomBytes(6));
+    const { first, second, chunks } = await writeStatement(problemId);
+
+    const chunk = await program.account.problemContent.fetch(chunks[1]);
+    expect(Buffer.from(chunk.data).equals(second)).to.equal(true);
+
+    const { problemPDA } = await harness.createProblem({
+      problemId,
+      contentHash: Array.from(sha256(first, second)),
+      contentChunks: chunks,
+    });
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.contentHash).to.deep.equal(Array.from(sha256(first, second)));
+    expect(problem.contentChunks).to.equal(2);
+
+    // Neither a pinned chunk nor a new one can be written afterwards.
+    await expectError(write(problemId, 1, 0, Buffer.from("swapped")), "ProblemContentSealed");
+    await expectError(write(problemId, 2, 0, Buffer.from("appended")), "ProblemContentSealed");
+  });
+
+  /** Writes a two-chunk statement for `problemId`, the second chunk in two parts. */
+  async function writeStatement(problemId: anchor.BN) {
+    const first = Buffer.alloc(600, "a");
+    const second = Buffer.from("The rest of the statement, written in two parts.");
+
+    await write(problemId, 0, 0, first);
+    await write(problemId, 1, 0, second.subarray(0, 20));
+    await write(problemId, 1, 20, second.subarray(20));
+
+    return { first, second, chunks: [chunkAddress(problemId, 0), chunkAddress(problemId, 1)] };
+  }
+
+  function sha256(...parts: Buffer[]): Buffer {
+    const hash = createHash("sha256");
+    parts.forEach((part) => hash.update(part));
+    return hash.digest();
+  }
+
+  function chunkAddress(problemId: anchor.BN, index: number): PublicKey {
+    return harness.pda(
+      "problem_content",
+      problemId,
+      harness.owner.publicKey,
+      Buffer.from([index])
+    );
+  }
+
+  function write(problemId: anchor.BN, index: number, offset: number, data: Buffer) {
+    return program.methods
+      .writeProblemContent(problemId, index, offset, data)
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        problemContent: chunkAddress(problemId, index),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
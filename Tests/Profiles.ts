@@ -0,0 +1,88 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Reputation profiles: submissions, wins and earnings on the solver side,
+// posted and on-time closes on the creator side, each counted exactly once.
+
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+describe("Reputation profiles", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution", "pay_winner"]);
+  });
+
+  it("counts each submission, close and payout once", async () => {
+    const [creator, winner, loser] = await harness.funded(3);
+    const deadline = now() + 30;
+    const { problemId } = await harness.createProblem({ creator, deadline });
+
+    await harness.submitSolution(problemId, winner, true, new anchor.BN(1_000));
+    await harness.submitSolution(problemId, loser, false, new anchor.BN(3_000));
+
+    let winnerProfile = await solverProfile(winner.publicKey);
+    expect(winnerProfile.submissions.toNumber()).to.equal(1);
+    expect(winnerProfile.wins.toNumber()).to.equal(0);
+
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, winner.publicKey, creator);
+
+    const creatorProfile = await program.account.creatorProfile.fetch(
+      harness.pda("creator_profile", creator.publicKey)
+    );
+    expect(creatorProfile.bountiesPosted.toNumber()).to.equal(1);
+    expect(creatorProfile.bountiesClosed.toNumber()).to.equal(1);
+    expect(creatorProfile.bountiesPaidOnTime.toNumber()).to.equal(1);
+
+    await harness.payWinner(problemId, winner, { isWinner: true });
+    await harness.payWinner(problemId, loser, { isWinner: false });
+    await expectError(harness.payWinner(problemId, winner, { isWinner: true }), "AlreadyPaid");
+
+    // The winner takes their stake back plus the whole opposite pool.
+    winnerProfile = await solverProfile(winner.publicKey);
+    expect(winnerProfile.wins.toNumber()).to.equal(1);
+    expect(winnerProfile.totalEarned.toNumber()).to.equal(4_000);
+
+    const loserProfile = await solverProfile(loser.publicKey);
+    expect(loserProfile.submissions.toNumber()).to.equal(1);
+    expect(loserProfile.wins.toNumber()).to.equal(0);
+    expect(loserProfile.totalEarned.toNumber()).to.equal(0);
+  });
+
+  it("accumulates a solver's record across problems", async () => {
+    const [solver, other] = await harness.funded(2);
+    const deadline = now() + 40;
+    const first = await harness.createProblem({ deadline });
+    const second = await harness.createProblem({ deadline });
+
+    await harness.submitSolution(first.problemId, solver, true, new anchor.BN(500));
+    await harness.submitSolution(first.problemId, other, false, new anchor.BN(500));
+    await harness.submitSolution(second.problemId, solver, true, new anchor.BN(500));
+    await harness.submitSolution(second.problemId, other, false, new anchor.BN(500));
+
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(first.problemId, solver.publicKey);
+    await harness.closeProblem(second.problemId, other.publicKey);
+
+    await harness.payWinner(first.problemId, solver, { isWinner: true });
+    await harness.payWinner(second.problemId, solver, { isWinner: false });
+    await harness.payWinner(second.problemId, other, { isWinner: true });
+
+    const profile = await solverProfile(solver.publicKey);
+    expect(profile.submissions.toNumber()).to.equal(2);
+    expect(profile.wins.toNumber()).to.equal(1);
+    expect(profile.totalEarned.toNumber()).to.equal(1_000);
+  });
+
+  function solverProfile(solver: anchor.web3.PublicKey) {
+    return program.account.solverProfile.fetch(harness.pda("solver_profile", solver));
+  }
+});
@@ -0,0 +1,142 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Hash puzzles: solvers commit to `sha256(preimage || solver)` and reveal the
+// preimage in a later slot; the first reveal matching the puzzle hash takes
+// the escrowed bounty, and an unsolved puzzle is refunded after the deadline.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+const BOUNTY = new anchor.BN(200_000_000);
+const PREIMAGE = Buffer.from("correct horse battery staple");
+
+describe("Hash puzzles", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem"]);
+  });
+
+  it("pays the first reveal that matches the puzzle hash", async () => {
+    const { problemId, problemPDA } = await puzzle(now() + 120);
+    const [solver, copier] = await harness.funded(2);
+
+    // A commitment made in the same slot as its reveal is refused, so seeing
+    // a reveal in flight is no use to a copier.
+    await expectError(commitAndReveal(problemId, copier, PREIMAGE), "CommitmentTooRecent");
+
+    const wrong = Buffer.from("incorrect horse");
+    await commit(problemId, solver, wrong);
+    await expectError(reveal(problemId, solver, PREIMAGE), "CommitmentMismatch");
+    await expectError(reveal(problemId, solver, wrong), "WrongPuzzleSolution");
+
+    await commit(problemId, solver, PREIMAGE);
+    const before = await harness.balance(solver.publicKey);
+    await reveal(problemId, solver, PREIMAGE);
+
+    // The bounty comes with the rent of the closed commitment.
+    expect((await harness.balance(solver.publicKey)) - before).to.be.greaterThan(
+      BOUNTY.toNumber()
+    );
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ settled: {} });
+    expect(problem.winner.toBase58()).to.equal(solver.publicKey.toBase58());
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+
+    await commit(problemId, copier, PREIMAGE);
+    await expectError(reveal(problemId, copier, PREIMAGE), "ProblemNotActive");
+    await expectError(refund(problemId), "ProblemNotActive");
+  });
+
+  it("refunds a puzzle nobody solved once the deadline passes", async () => {
+    const deadline = now() + 20;
+    const { problemId, problemPDA } = await puzzle(deadline);
+    const [solver] = await harness.funded(1);
+    await commit(problemId, solver, PREIMAGE);
+
+    await expectError(refund(problemId), "DeadlineNotReached");
+
+    await harness.waitUntil(deadline);
+    await expectError(reveal(problemId, solver, PREIMAGE), "DeadlinePassed");
+
+    const before = await harness.balance(problemPDA);
+    await refund(problemId);
+
+    expect(before - (await harness.balance(problemPDA))).to.equal(BOUNTY.toNumber());
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ settled: {} });
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+  });
+
+  function sha256(...parts: Buffer[]): Buffer {
+    const hash = createHash("sha256");
+    parts.forEach((part) => hash.update(part));
+    return hash.digest();
+  }
+
+  function puzzle(deadline: number) {
+    return harness.createProblem({
+      deadline,
+      bountyAmount: BOUNTY,
+      puzzleHash: Array.from(sha256(PREIMAGE)),
+    });
+  }
+
+  function commitIx(problemId: anchor.BN, solver: Keypair, preimage: Buffer) {
+    return program.methods
+      .commitPuzzleSolution(problemId, Array.from(sha256(preimage, solver.publicKey.toBuffer())))
+      .accountsPartial({
+        solver: solver.publicKey,
+        problem: harness.problemAddress(problemId),
+        puzzleCommit: harness.pda("puzzle_commit", problemId, solver.publicKey),
+      });
+  }
+
+  function revealIx(problemId: anchor.BN, solver: Keypair, preimage: Buffer) {
+    return program.methods
+      .revealPuzzleSolution(problemId, preimage)
+      .accountsPartial({
+        solver: solver.publicKey,
+        problem: harness.problemAddress(problemId),
+        puzzleCommit: harness.pda("puzzle_commit", problemId, solver.publicKey),
+      });
+  }
+
+  function commit(problemId: anchor.BN, solver: Keypair, preimage: Buffer) {
+    return commitIx(problemId, solver, preimage)
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function reveal(problemId: anchor.BN, solver: Keypair, preimage: Buffer) {
+    return revealIx(problemId, solver, preimage)
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async function commitAndReveal(problemId: anchor.BN, solver: Keypair, preimage: Buffer) {
+    return revealIx(problemId, solver, preimage)
+      .preInstructions([await commitIx(problemId, solver, preimage).instruction()])
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function refund(problemId: anchor.BN) {
+    return program.methods
+      .refundUnsolvedPuzzle(problemId)
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
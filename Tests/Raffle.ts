@@ -0,0 +1,119 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Raffles: once `enable_raffle` switches a problem to raffle judging, the
+// creator marks valid solutions with `mark_solution_valid` and `draw_winner`
+// picks one of those entrants in MPC after the deadline.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { Harness, expectError, now, randomOffset } from "./Harness";
+
+describe("Raffles", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution", "draw_raffle_winner"]);
+  });
+
+  it("draws the winner from the solutions marked valid", async () => {
+    const deadline = now() + 30;
+    const { problemId, problemPDA } = await raffle(deadline);
+    const solvers = await harness.funded(4);
+    for (const solver of solvers) {
+      await harness.submitSolution(problemId, solver, true);
+    }
+
+    // The last solver never makes it into the raffle.
+    const entrants = solvers.slice(0, 3);
+    for (const solver of entrants) {
+      await markValid(problemId, problemId, solver);
+    }
+    await expectError(markValid(problemId, problemId, entrants[0]), "AlreadyMarkedValid");
+    const { entrants: listed } = await program.account.raffle.fetch(raffleAddress(problemId));
+    expect(listed.map((entrant) => entrant.toBase58())).to.deep.equal(
+      entrants.map((solver) => solver.publicKey.toBase58())
+    );
+
+    await expectError(draw(problemId), "DeadlineNotReached");
+    await harness.waitUntil(deadline);
+    await draw(problemId);
+
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ closed: {} });
+    expect(entrants.map((solver) => solver.publicKey.toBase58())).to.include(
+      problem.winner.toBase58()
+    );
+
+    // The drawn winner stands; the raffle can't be run again.
+    await expectError(draw(problemId), "ProblemNotActive");
+  });
+
+  it("refuses to draw when no solution was marked valid", async () => {
+    const deadline = now() + 20;
+    const { problemId } = await raffle(deadline);
+    const [solver] = await harness.funded(1);
+    await harness.submitSolution(problemId, solver, true);
+
+    await harness.waitUntil(deadline);
+    await expectError(draw(problemId), "NoRaffleEntrants");
+  });
+
+  it("only marks solutions submitted to the raffle's problem", async () => {
+    const { problemId } = await raffle(now() + 60);
+    const { problemId: otherId } = await harness.createProblem({ deadline: now() + 60 });
+    const [solver] = await harness.funded(1);
+    await harness.submitSolution(otherId, solver, true);
+
+    await expectError(markValid(problemId, otherId, solver), "InvalidSolution");
+  });
+
+  async function raffle(deadline: number) {
+    const created = await harness.createProblem({ deadline });
+    await program.methods
+      .enableRaffle(created.problemId)
+      .accountsPartial({
+        authority: harness.owner.publicKey,
+        problem: created.problemPDA,
+        raffle: raffleAddress(created.problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+    return created;
+  }
+
+  function raffleAddress(problemId: anchor.BN) {
+    return harness.pda("raffle", problemId);
+  }
+
+  function markValid(problemId: anchor.BN, solutionProblemId: anchor.BN, solver: Keypair) {
+    return program.methods
+      .markSolutionValid(problemId)
+      .accountsPartial({
+        authority: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        raffle: raffleAddress(problemId),
+        solution: harness.solutionAddress(solutionProblemId, solver.publicKey),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async function draw(problemId: anchor.BN) {
+    const offset = randomOffset();
+    await program.methods
+      .drawWinner(offset, problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "draw_raffle_winner"),
+        problem: harness.problemAddress(problemId),
+        raffle: raffleAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+});
@@ -0,0 +1,143 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Confidential scoring: after `enable_scoring` the creator scores solutions
+// under encryption and `close_scored_problem` reveals only the best one's
+// index, closing the problem if the creator named that solution.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { deserializeLE } from "@arcium-hq/client";
+import { Harness, expectError, now, randomOffset, sleep } from "./Harness";
+
+describe("Confidential scoring", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "submit_solution",
+      "initialize_scoring",
+      "score_solution",
+      "reveal_best_solution",
+    ]);
+  });
+
+  it("closes on the best scored solution", async () => {
+    const deadline = now() + 60;
+    const { problemId, problemPDA } = await harness.createProblem({ deadline });
+    await enableScoring(problemId);
+
+    const solvers = await harness.funded(3);
+    for (const solver of solvers) {
+      await harness.submitSolution(problemId, solver, true);
+    }
+    for (const [i, value] of [10, 40, 25].entries()) {
+      await score(problemId, solvers[i], value);
+    }
+    await expectError(score(problemId, solvers[0], 50), "AlreadyScored");
+
+    const board = await program.account.scoreBoard.fetch(scoreBoardAddress(problemId));
+    expect(board.scoredCount.toNumber()).to.equal(3);
+
+    const best = solvers[1];
+    await expectError(queueClose(problemId, best), "DeadlineNotReached");
+    await harness.waitUntil(deadline);
+    // The creator's pick is replaced by the revealed best score.
+    await expectError(harness.closeProblem(problemId, best.publicKey), "InvalidJudgingMode");
+
+    // The callback rejects anything but the best solution with
+    // `NotBestSolution`, so that computation never finalizes and the problem
+    // stays open.
+    const rejected = await queueClose(problemId, solvers[2]);
+    await Promise.race([harness.finalize(rejected), sleep(20_000)]);
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ active: {} });
+    expect(problem.winner).to.equal(null);
+
+    const closed = harness.awaitEvent("scoredProblemClosedEvent");
+    await close(problemId, best);
+    const event = await closed;
+    expect(event.winner.toBase58()).to.equal(best.publicKey.toBase58());
+    expect(event.winningIndex.toNumber()).to.equal(1);
+
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ closed: {} });
+    expect(problem.winner.toBase58()).to.equal(best.publicKey.toBase58());
+    expect(problem.winningIndex.toNumber()).to.equal(1);
+  });
+
+  it("refuses to close before any solution is scored", async () => {
+    const deadline = now() + 20;
+    const { problemId } = await harness.createProblem({ deadline });
+    await enableScoring(problemId);
+
+    const [solver] = await harness.funded(1);
+    await harness.submitSolution(problemId, solver, true);
+    await harness.waitUntil(deadline);
+
+    await expectError(queueClose(problemId, solver), "NoScoredSolutions");
+  });
+
+  function scoreBoardAddress(problemId: anchor.BN) {
+    return harness.pda("score_board", problemId);
+  }
+
+  async function enableScoring(problemId: anchor.BN) {
+    const offset = randomOffset();
+    await program.methods
+      .enableScoring(offset, problemId, new anchor.BN(deserializeLE(randomBytes(16)).toString()))
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "initialize_scoring"),
+        problem: harness.problemAddress(problemId),
+        scoreBoard: scoreBoardAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function score(problemId: anchor.BN, solver: Keypair, value: number) {
+    const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(value)]);
+    const offset = randomOffset();
+
+    await program.methods
+      .scoreSolution(offset, problemId, ciphertexts[0], publicKey, nonce)
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "score_solution"),
+        problem: harness.problemAddress(problemId),
+        scoreBoard: scoreBoardAddress(problemId),
+        solution: harness.solutionAddress(problemId, solver.publicKey),
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function close(problemId: anchor.BN, solver: Keypair) {
+    await harness.finalize(await queueClose(problemId, solver));
+  }
+
+  async function queueClose(problemId: anchor.BN, solver: Keypair) {
+    const offset = randomOffset();
+    await program.methods
+      .closeScoredProblem(offset, problemId)
+      .accountsPartial({
+        authority: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "reveal_best_solution"),
+        problem: harness.problemAddress(problemId),
+        scoreBoard: scoreBoardAddress(problemId),
+        solution: harness.solutionAddress(problemId, solver.publicKey),
+      })
+      .rpc({ commitment: "confirmed" });
+
+    return offset;
+  }
+});
@@ -0,0 +1,195 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Sealed-bid bounties: solvers quote an encrypted price with `submit_bid`,
+// `close_sealed_bid` reveals only the lowest quote under the bounty, and
+// `pay_sealed_bid_winner` pays that price and refunds the rest to the creator.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { deserializeLE } from "@arcium-hq/client";
+import { Harness, badgeAccount, expectError, now, randomOffset } from "./Harness";
+
+describe("Sealed bids", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup([
+      "initialize_problem",
+      "initialize_bid_board",
+      "submit_bid",
+      "reveal_lowest_bid",
+    ]);
+  });
+
+  it("pays the lowest bid and refunds the rest of the bounty", async () => {
+    const deadline = now() + 60;
+    const { problemId, problemPDA } = await sealedBidProblem(1_000_000, deadline);
+
+    const bidders = await harness.funded(3);
+    for (const [i, price] of [800_000, 300_000, 500_000].entries()) {
+      await bid(problemId, bidders[i], price);
+    }
+    await expectError(pay(problemId, bidders[1]), "ProblemNotClosed");
+
+    await harness.waitUntil(deadline);
+    const [late] = await harness.funded(1);
+    await expectError(bid(problemId, late, 100_000), "DeadlinePassed");
+
+    const closed = harness.awaitEvent("sealedBidClosedEvent");
+    await close(problemId);
+    const event = await closed;
+    expect(event.hasWinner).to.equal(true);
+    expect(event.winningIndex.toNumber()).to.equal(1);
+    expect(event.winningBid.toNumber()).to.equal(300_000);
+
+    let problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ closed: {} });
+    expect(problem.winningIndex.toNumber()).to.equal(1);
+    expect(problem.winningBid.toNumber()).to.equal(300_000);
+
+    // Only the lowest bidder's solution is paid.
+    await expectError(pay(problemId, bidders[0]), "InvalidSolution");
+
+    const winner = bidders[1];
+    const before = await harness.balance(winner.publicKey);
+    const refunded = harness.awaitEvent("bountyRefundedEvent");
+    await pay(problemId, winner);
+    expect((await refunded).amount.toNumber()).to.equal(700_000);
+    expect((await harness.balance(winner.publicKey)) - before).to.equal(300_000);
+
+    problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ settled: {} });
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+    const solution = await program.account.solution.fetch(
+      harness.solutionAddress(problemId, winner.publicKey)
+    );
+    expect(solution.paid).to.equal(true);
+    expect(solution.payoutAmount.toNumber()).to.equal(300_000);
+
+    await expectError(pay(problemId, winner), "ProblemNotClosed");
+  });
+
+  it("refunds the whole bounty when nobody bids under it", async () => {
+    const deadline = now() + 30;
+    const { problemId, problemPDA } = await sealedBidProblem(100_000, deadline);
+
+    const [bidder] = await harness.funded(1);
+    await bid(problemId, bidder, 150_000);
+    await harness.waitUntil(deadline);
+
+    const closed = harness.awaitEvent("sealedBidClosedEvent");
+    const refunded = harness.awaitEvent("bountyRefundedEvent");
+    await close(problemId);
+    expect((await closed).hasWinner).to.equal(false);
+    expect((await refunded).amount.toNumber()).to.equal(100_000);
+
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ settled: {} });
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+    expect(problem.winningIndex).to.equal(null);
+  });
+
+  it("keeps sealed bidding and creator picks apart", async () => {
+    const deadline = now() + 20;
+    const standard = await harness.createProblem({ deadline });
+    await expectError(openBidding(standard.problemId), "InvalidBountyMode");
+
+    const { problemId } = await sealedBidProblem(100_000, deadline);
+    const [bidder] = await harness.funded(1);
+    await bid(problemId, bidder, 50_000);
+    await harness.waitUntil(deadline);
+
+    await expectError(harness.closeProblem(problemId, bidder.publicKey), "InvalidBountyMode");
+  });
+
+  function bidBoardAddress(problemId: anchor.BN) {
+    return harness.pda("bid_board", problemId);
+  }
+
+  async function sealedBidProblem(bounty: number, deadline: number) {
+    const created = await harness.createProblem({
+      deadline,
+      bountyAmount: new anchor.BN(bounty),
+      bountyMode: { sealedBid: {} },
+    });
+    await openBidding(created.problemId);
+    return created;
+  }
+
+  async function openBidding(problemId: anchor.BN) {
+    const offset = randomOffset();
+    await program.methods
+      .openSealedBidding(
+        offset,
+        problemId,
+        new anchor.BN(deserializeLE(randomBytes(16)).toString())
+      )
+      .accountsPartial({
+        creator: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "initialize_bid_board"),
+        problem: harness.problemAddress(problemId),
+        bidBoard: bidBoardAddress(problemId),
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function bid(problemId: anchor.BN, bidder: Keypair, price: number) {
+    const { ciphertexts, publicKey, nonce } = await harness.encrypt([BigInt(price)]);
+    const offset = randomOffset();
+
+    await program.methods
+      .submitBid(offset, problemId, ciphertexts[0], publicKey, nonce)
+      .accountsPartial({
+        solver: bidder.publicKey,
+        ...harness.arciumAccounts(offset, "submit_bid"),
+        problem: harness.problemAddress(problemId),
+        bidBoard: bidBoardAddress(problemId),
+        solutionIndexPage: harness.solutionIndexPage(problemId, 0),
+      })
+      .signers([bidder])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  async function close(problemId: anchor.BN) {
+    const offset = randomOffset();
+    await program.methods
+      .closeSealedBid(offset, problemId)
+      .accountsPartial({
+        authority: harness.owner.publicKey,
+        ...harness.arciumAccounts(offset, "reveal_lowest_bid"),
+        problem: harness.problemAddress(problemId),
+        bidBoard: bidBoardAddress(problemId),
+        creator: harness.owner.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+
+  function pay(problemId: anchor.BN, solver: Keypair) {
+    const badgeMint = harness.badgeMint(problemId);
+    return program.methods
+      .paySealedBidWinner(problemId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        problem: harness.problemAddress(problemId),
+        creator: harness.owner.publicKey,
+        solution: harness.solutionAddress(problemId, solver.publicKey),
+        solver: solver.publicKey,
+        badgeMint,
+        badgeAccount: badgeAccount(solver.publicKey, badgeMint),
+        season: null,
+        seasonEntry: null,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+});
@@ -0,0 +1,173 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Seasons: payouts on a season's problems score points once each, and
+// `finalize_season` splits the prize pool over the leaderboard, refunding
+// shares nobody placed for to the season authority.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+const PRIZE_POOL = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+
+describe("Seasons", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution", "pay_winner"]);
+  });
+
+  it("scores each payout once and pays the prize pool by rank", async () => {
+    const [authority] = await harness.funded(1);
+    const seasonEnd = now() + 90;
+    const seasonId = await createSeason(authority, seasonEnd, [6_000, 3_000, 1_000]);
+
+    const deadline = now() + 30;
+    const { problemId } = await harness.createProblem({ deadline });
+    await addToSeason(seasonId, problemId, authority);
+
+    const [winner, runnerUp] = await harness.funded(2);
+    await harness.submitSolution(problemId, winner, true, new anchor.BN(1_000));
+    await harness.submitSolution(problemId, runnerUp, false, new anchor.BN(1_000));
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, winner.publicKey);
+
+    await harness.payWinner(problemId, winner, { isWinner: true, seasonId });
+    await harness.payWinner(problemId, runnerUp, { isWinner: false, seasonId });
+
+    // A payout that can't be queued twice can't score twice either.
+    await expectError(
+      harness.payWinner(problemId, winner, { isWinner: true, seasonId }),
+      "AlreadyPaid"
+    );
+
+    let season = await program.account.season.fetch(seasonPDA(seasonId));
+    expect(
+      season.leaderboard.map((standing) => [
+        standing.solver.toBase58(),
+        standing.points.toNumber(),
+      ])
+    ).to.deep.equal([
+      [winner.publicKey.toBase58(), 10],
+      [runnerUp.publicKey.toBase58(), 1],
+    ]);
+
+    await harness.waitUntil(seasonEnd);
+    const before = await balances([winner.publicKey, runnerUp.publicKey, authority.publicKey]);
+    await finalize(seasonId, authority.publicKey, [winner.publicKey, runnerUp.publicKey]);
+    const after = await balances([winner.publicKey, runnerUp.publicKey, authority.publicKey]);
+
+    // Nobody placed third, so its share goes back to the authority.
+    const pool = PRIZE_POOL.toNumber();
+    expect(after[0] - before[0]).to.equal((pool * 6_000) / 10_000);
+    expect(after[1] - before[1]).to.equal((pool * 3_000) / 10_000);
+    expect(after[2] - before[2]).to.equal((pool * 1_000) / 10_000);
+
+    season = await program.account.season.fetch(seasonPDA(seasonId));
+    expect(season.finalized).to.equal(true);
+    expect(season.prizePool.toNumber()).to.equal(0);
+
+    await expectError(
+      finalize(seasonId, authority.publicKey, [winner.publicKey, runnerUp.publicKey]),
+      "SeasonFinalized"
+    );
+  });
+
+  it("refunds the whole pool when nobody scored", async () => {
+    const [authority] = await harness.funded(1);
+    const seasonEnd = now() + 10;
+    const seasonId = await createSeason(authority, seasonEnd, [10_000]);
+
+    await expectError(finalize(seasonId, authority.publicKey, []), "SeasonNotEnded");
+
+    await harness.waitUntil(seasonEnd);
+    const [before] = await balances([authority.publicKey]);
+    await finalize(seasonId, authority.publicKey, []);
+    const [after] = await balances([authority.publicKey]);
+
+    expect(after - before).to.equal(PRIZE_POOL.toNumber());
+  });
+
+  it("rejects a leaderboard passed out of order", async () => {
+    const [authority] = await harness.funded(1);
+    const seasonEnd = now() + 60;
+    const seasonId = await createSeason(authority, seasonEnd, [5_000, 5_000]);
+
+    const deadline = now() + 30;
+    const { problemId } = await harness.createProblem({ deadline });
+    await addToSeason(seasonId, problemId, authority);
+
+    const [winner, runnerUp] = await harness.funded(2);
+    await harness.submitSolution(problemId, winner, true);
+    await harness.submitSolution(problemId, runnerUp, true);
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, winner.publicKey);
+    await harness.payWinner(problemId, winner, { isWinner: true, seasonId });
+    await harness.payWinner(problemId, runnerUp, { isWinner: false, seasonId });
+
+    await harness.waitUntil(seasonEnd);
+    await expectError(
+      finalize(seasonId, authority.publicKey, [runnerUp.publicKey, winner.publicKey]),
+      "InvalidSeasonAccounts"
+    );
+  });
+
+  function seasonPDA(seasonId: anchor.BN): PublicKey {
+    return harness.pda("season", seasonId);
+  }
+
+  async function createSeason(authority: Keypair, endTime: number, shares: number[]) {
+    const seasonId = new anchor.BN(randomBytes(6));
+
+    await program.methods
+      .createSeason(seasonId, new anchor.BN(endTime), shares)
+      .accountsPartial({ authority: authority.publicKey, season: seasonPDA(seasonId) })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    await program.methods
+      .fundSeason(seasonId, PRIZE_POOL)
+      .accountsPartial({ funder: harness.owner.publicKey, season: seasonPDA(seasonId) })
+      .rpc({ commitment: "confirmed" });
+
+    return seasonId;
+  }
+
+  function addToSeason(seasonId: anchor.BN, problemId: anchor.BN, authority: Keypair) {
+    return program.methods
+      .addProblemToSeason(seasonId, problemId)
+      .accountsPartial({
+        authority: authority.publicKey,
+        creator: harness.owner.publicKey,
+        season: seasonPDA(seasonId),
+        problem: harness.problemAddress(problemId),
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function finalize(seasonId: anchor.BN, authority: PublicKey, leaderboard: PublicKey[]) {
+    return program.methods
+      .finalizeSeason(seasonId)
+      .accountsPartial({
+        payer: harness.owner.publicKey,
+        season: seasonPDA(seasonId),
+        authority,
+      })
+      .remainingAccounts(
+        leaderboard.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+      )
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function balances(pubkeys: PublicKey[]): Promise<number[]> {
+    return Promise.all(pubkeys.map((pubkey) => harness.balance(pubkey)));
+  }
+});
@@ -0,0 +1,52 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Solution index: `submit_solution` lists every solution in a page of
+// `SOLUTIONS_PER_PAGE` entries, opening the next page once one fills up.
+
+import { expect } from "chai";
+import { Harness, SOLUTIONS_PER_PAGE, now } from "./Harness";
+
+describe("Solution index", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution"]);
+  });
+
+  it("rolls over to a new page after SOLUTIONS_PER_PAGE solutions", async () => {
+    const { problemId } = await harness.createProblem({ deadline: now() + 900 });
+    const solvers = await harness.funded(SOLUTIONS_PER_PAGE + 1);
+    for (const solver of solvers) {
+      await harness.submitSolution(problemId, solver, true);
+    }
+
+    const first = await program.account.solutionIndexPage.fetch(
+      harness.solutionIndexPage(problemId, 0)
+    );
+    expect(first.page.toNumber()).to.equal(0);
+    expect(first.entries.map((entry) => entry.solver.toBase58())).to.deep.equal(
+      solvers.slice(0, SOLUTIONS_PER_PAGE).map((solver) => solver.publicKey.toBase58())
+    );
+
+    // Entry 0 of page 1 is solution index `SOLUTIONS_PER_PAGE`.
+    const second = await program.account.solutionIndexPage.fetch(
+      harness.solutionIndexPage(problemId, 1)
+    );
+    expect(second.page.toNumber()).to.equal(1);
+    expect(second.problemId.toString()).to.equal(problemId.toString());
+    expect(second.entries).to.have.length(1);
+    expect(second.entries[0].solver.toBase58()).to.equal(
+      solvers[SOLUTIONS_PER_PAGE].publicKey.toBase58()
+    );
+    const last = await program.account.solution.fetch(
+      harness.solutionAddress(problemId, solvers[SOLUTIONS_PER_PAGE].publicKey)
+    );
+    expect(last.index.toNumber()).to.equal(SOLUTIONS_PER_PAGE);
+  });
+});
@@ -0,0 +1,142 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Bounty templates: `spawn_round` posts a template's next round and moves its
+// bounty and compute budget out of the template vault into the new problem.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { deserializeLE } from "@arcium-hq/client";
+import { Harness, expectError, now, randomOffset } from "./Harness";
+
+const BOUNTY = new anchor.BN(300_000_000);
+const COMPUTE_BUDGET = new anchor.BN(20_000_000);
+const CADENCE = 60 * 60;
+
+describe("Bounty templates", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem"]);
+  });
+
+  it("funds a standard round's bounty from the vault", async () => {
+    const [creator, cranker] = await harness.funded(2);
+    const templateId = await createTemplate(creator, { standard: {} });
+    await fund(templateId, BOUNTY.add(COMPUTE_BUDGET));
+
+    const problemId = new anchor.BN(randomBytes(6));
+    const before = await harness.balance(templateAddress(templateId));
+    await spawn(templateId, problemId, cranker);
+
+    const problem = await program.account.problem.fetch(harness.problemAddress(problemId));
+    expect(problem.creator.toBase58()).to.equal(creator.publicKey.toBase58());
+    expect(problem.state).to.deep.equal({ active: {} });
+    expect(problem.judgingMode).to.deep.equal({ creator: {} });
+    expect(problem.escrowAmount.toString()).to.equal(BOUNTY.toString());
+    expect(problem.escrowedBounty.toString()).to.equal(BOUNTY.toString());
+    expect(problem.bountyAmount.toString()).to.equal(BOUNTY.toString());
+    expect(problem.deadline.toNumber()).to.be.closeTo(now() + CADENCE, 30);
+
+    const template = await program.account.bountyTemplate.fetch(templateAddress(templateId));
+    expect(template.vaultBalance.toNumber()).to.equal(0);
+    expect(template.rounds.toNumber()).to.equal(1);
+    expect(before - (await harness.balance(templateAddress(templateId)))).to.equal(
+      BOUNTY.add(COMPUTE_BUDGET).toNumber()
+    );
+
+    // The next round waits for this one's deadline.
+    await expectError(
+      spawn(templateId, new anchor.BN(randomBytes(6)), cranker),
+      "RoundStillOpen"
+    );
+  });
+
+  it("won't spawn a standard round the vault can't pay the bounty for", async () => {
+    const [creator, cranker] = await harness.funded(2);
+    const templateId = await createTemplate(creator, { standard: {} });
+    await fund(templateId, COMPUTE_BUDGET);
+
+    await expectError(
+      spawn(templateId, new anchor.BN(randomBytes(6)), cranker),
+      "InsufficientEscrow"
+    );
+  });
+
+  it("escrows a sealed-bid round's bounty as its price ceiling", async () => {
+    const [creator, cranker] = await harness.funded(2);
+    const templateId = await createTemplate(creator, { sealedBid: {} });
+    await fund(templateId, BOUNTY.add(COMPUTE_BUDGET));
+
+    const problemId = new anchor.BN(randomBytes(6));
+    await spawn(templateId, problemId, cranker);
+
+    // The winning bid is paid by `pay_sealed_bid_winner`, not `pay_winner`.
+    const problem = await program.account.problem.fetch(harness.problemAddress(problemId));
+    expect(problem.bountyMode).to.deep.equal({ sealedBid: {} });
+    expect(problem.escrowAmount.toString()).to.equal(BOUNTY.toString());
+    expect(problem.escrowedBounty.toNumber()).to.equal(0);
+  });
+
+  function templateAddress(templateId: anchor.BN): PublicKey {
+    return harness.pda("template", templateId);
+  }
+
+  async function createTemplate(creator: Keypair, bountyMode: object) {
+    const templateId = new anchor.BN(randomBytes(6));
+
+    await program.methods
+      .createBountyTemplate(
+        templateId,
+        "Weekly bounty",
+        "A round posted from a template",
+        BOUNTY,
+        bountyMode as any,
+        COMPUTE_BUDGET,
+        new anchor.BN(CADENCE)
+      )
+      .accountsPartial({ creator: creator.publicKey, template: templateAddress(templateId) })
+      .signers([creator])
+      .rpc({ commitment: "confirmed" });
+
+    return templateId;
+  }
+
+  function fund(templateId: anchor.BN, amount: anchor.BN) {
+    return program.methods
+      .fundBountyTemplate(templateId, amount)
+      .accountsPartial({
+        funder: harness.owner.publicKey,
+        template: templateAddress(templateId),
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async function spawn(templateId: anchor.BN, problemId: anchor.BN, cranker: Keypair) {
+    const offset = randomOffset();
+
+    await program.methods
+      .spawnRound(
+        offset,
+        templateId,
+        problemId,
+        new anchor.BN(deserializeLE(randomBytes(16)).toString())
+      )
+      .accountsPartial({
+        payer: cranker.publicKey,
+        ...harness.arciumAccounts(offset, "initialize_problem"),
+        template: templateAddress(templateId),
+        problem: harness.problemAddress(problemId),
+      })
+      .signers([cranker])
+      .rpc({ commitment: "confirmed" });
+    await harness.finalize(offset);
+  }
+});
@@ -0,0 +1,467 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Verifier-judged bounties: `verify_solution` hands the solution to the
+// problem's verifier program over CPI and pays the first one it accepts. These
+// tests run against the example `factor_verifier` program, where solvers
+// commit to their factors a slot before submitting them.
+
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import { BountySolver } from "../target/types/bounty_solver";
+import { FactorVerifier } from "../target/types/factor_verifier";
+import { createHash, randomBytes } from "crypto";
+import {
+  awaitComputationFinalization,
+  getCompDefAccOffset,
+  getArciumProgAddress,
+  buildFinalizeCompDefTx,
+  deserializeLE,
+  getMXEAccAddress,
+  getMempoolAccAddress,
+  getCompDefAccAddress,
+  getExecutingPoolAccAddress,
+  getComputationAccAddress,
+  getArciumAccountBaseSeed,
+  getClusterAccAddress,
+} from "@arcium-hq/client";
+import * as fs from "fs";
+import * as os from "os";
+import { expect } from "chai";
+
+const IDL = JSON.parse(
+  fs.readFileSync("target/idl/bounty_solver.json", "utf8")
+);
+
+const VERIFIER_IDL = JSON.parse(
+  fs.readFileSync("target/idl/factor_verifier.json", "utf8")
+);
+
+// 4294967291 and 4294967279 are the two largest primes below 2^32.
+const P = new anchor.BN("4294967291");
+const Q = new anchor.BN("4294967279");
+const SEMIPRIME = P.mul(Q);
+
+describe("Verifier-judged bounties", () => {
+  const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+
+  const connection = new anchor.web3.Connection(
+    "http://127.0.0.1:8899",
+    "confirmed"
+  );
+  const wallet = new anchor.Wallet(owner);
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: "confirmed",
+  });
+  anchor.setProvider(provider);
+
+  const program = new anchor.Program<BountySolver>(IDL as anchor.Idl, provider);
+  const verifier = new anchor.Program<FactorVerifier>(
+    VERIFIER_IDL as anchor.Idl,
+    provider
+  );
+
+  let clusterAccount: PublicKey;
+
+  const arciumAccounts = (computationOffset: anchor.BN, circuit: string) => ({
+    computationAccount: getComputationAccAddress(
+      program.programId,
+      computationOffset
+    ),
+    clusterAccount,
+    mxeAccount: getMXEAccAddress(program.programId),
+    mempoolAccount: getMempoolAccAddress(program.programId),
+    executingPool: getExecutingPoolAccAddress(program.programId),
+    compDefAccount: getCompDefAccAddress(
+      program.programId,
+      Buffer.from(getCompDefAccOffset(circuit)).readUInt32LE()
+    ),
+  });
+
+  before(async () => {
+    try {
+      const clusterData = JSON.parse(
+        fs.readFileSync("artifacts/cluster_acc_0.json", "utf8")
+      );
+      clusterAccount = new PublicKey(clusterData.pubkey);
+    } catch (error) {
+      clusterAccount = getClusterAccAddress(0);
+    }
+
+    await ensureCompDef("initialize_problem");
+  });
+
+  it("pays the first solution the verifier accepts", async () => {
+    const { problemId, problemPDA, semiprimePDA } = await postFactoringBounty();
+    const solver = Keypair.generate();
+    await fund(solver.publicKey);
+
+    await commit(problemId, solver, factors(P, Q));
+    const before = await provider.connection.getBalance(solver.publicKey);
+    await verify(problemId, problemPDA, semiprimePDA, solver, factors(P, Q));
+    const after = await provider.connection.getBalance(solver.publicKey);
+
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ settled: {} });
+    expect(problem.winner.toBase58()).to.equal(solver.publicKey.toBase58());
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+    expect(after - before).to.be.greaterThan(0.9 * anchor.web3.LAMPORTS_PER_SOL);
+
+    // Once paid, the problem accepts no further solutions.
+    const late = Keypair.generate();
+    await fund(late.publicKey);
+    await commit(problemId, late, factors(Q, P));
+    await expectError(
+      verify(problemId, problemPDA, semiprimePDA, late, factors(Q, P)),
+      "ProblemNotActive"
+    );
+  });
+
+  it("rejects solutions the verifier does not accept", async () => {
+    const { problemId, problemPDA, semiprimePDA } = await postFactoringBounty();
+    const solver = Keypair.generate();
+    await fund(solver.publicKey);
+
+    // The trivial factorisation and malformed solutions are both turned down.
+    await commit(problemId, solver, factors(new anchor.BN(1), SEMIPRIME));
+    await expectError(
+      verify(problemId, problemPDA, semiprimePDA, solver, factors(new anchor.BN(1), SEMIPRIME)),
+      "SolutionRejected"
+    );
+    await commit(problemId, solver, Buffer.from("not factors"));
+    await expectError(
+      verify(problemId, problemPDA, semiprimePDA, solver, Buffer.from("not factors")),
+      "SolutionRejected"
+    );
+
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.state).to.deep.equal({ active: {} });
+    expect(problem.winner).to.equal(null);
+    expect(problem.escrowAmount.toNumber()).to.equal(anchor.web3.LAMPORTS_PER_SOL);
+  });
+
+  it("only invokes the verifier the problem names", async () => {
+    const { problemId, problemPDA, semiprimePDA } = await postFactoringBounty();
+    const solver = Keypair.generate();
+    await fund(solver.publicKey);
+
+    await expectError(
+      program.methods
+        .verifySolution(problemId, factors(P, Q))
+        .accountsPartial({
+          solver: solver.publicKey,
+          problem: problemPDA,
+          verifierProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: semiprimePDA, isWritable: false, isSigner: false },
+          {
+            pubkey: commitmentPDA(problemId, solver.publicKey),
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([solver])
+        .rpc({ commitment: "confirmed" }),
+      "InvalidVerifier"
+    );
+  });
+
+  it("only lets the problem's creator set its semiprime", async () => {
+    const { problemId, problemPDA, semiprimePDA } = await createFactoringProblem();
+    const squatter = Keypair.generate();
+    await fund(squatter.publicKey);
+
+    // A squatter can't claim the semiprime slot with a target of their own...
+    await expectError(
+      setSemiprime(problemId, problemPDA, semiprimePDA, squatter, new anchor.BN(15)),
+      "NotProblemCreator"
+    );
+
+    // ...nor get in ahead of a problem that doesn't exist yet.
+    const futureId = new anchor.BN(randomBytes(6));
+    await expectError(
+      setSemiprime(
+        futureId,
+        problemAddress(futureId),
+        semiprimeAddress(futureId),
+        squatter,
+        new anchor.BN(15)
+      ),
+      "ConstraintOwner"
+    );
+
+    await setSemiprime(problemId, problemPDA, semiprimePDA, owner, SEMIPRIME);
+    const semiprime = await verifier.account.semiprime.fetch(semiprimePDA);
+    expect(semiprime.authority.toBase58()).to.equal(owner.publicKey.toBase58());
+    expect(semiprime.target.toString()).to.equal(SEMIPRIME.toString());
+  });
+
+  it("rejects a solution copied from another solver", async () => {
+    const { problemId, problemPDA, semiprimePDA } = await postFactoringBounty();
+    const solver = Keypair.generate();
+    const copier = Keypair.generate();
+    await fund(solver.publicKey);
+    await fund(copier.publicKey);
+
+    await commit(problemId, solver, factors(P, Q));
+
+    // Replaying the solver's factors, or even their commitment, doesn't help:
+    // the commitment binds the factors to the solver who made it.
+    await commitRaw(problemId, copier, commitmentFor(solver.publicKey, factors(P, Q)));
+    await expectError(
+      verify(problemId, problemPDA, semiprimePDA, copier, factors(P, Q)),
+      "SolutionRejected"
+    );
+
+    // Committing and submitting in the same slot is rejected too, so a copier
+    // can't commit after seeing the solution and still land first.
+    await expectError(
+      commitAndVerify(problemId, problemPDA, semiprimePDA, copier, factors(P, Q)),
+      "SolutionRejected"
+    );
+
+    await verify(problemId, problemPDA, semiprimePDA, solver, factors(P, Q));
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.winner.toBase58()).to.equal(solver.publicKey.toBase58());
+  });
+
+  async function postFactoringBounty() {
+    const bounty = await createFactoringProblem();
+    await setSemiprime(
+      bounty.problemId,
+      bounty.problemPDA,
+      bounty.semiprimePDA,
+      owner,
+      SEMIPRIME
+    );
+
+    return bounty;
+  }
+
+  async function createFactoringProblem() {
+    const problemId = new anchor.BN(randomBytes(6));
+    const problemPDA = problemAddress(problemId);
+    const semiprimePDA = semiprimeAddress(problemId);
+
+    const offset = new anchor.BN(randomBytes(8), "hex");
+    await program.methods
+      .createProblem(
+        offset,
+        problemId,
+        "Factor the semiprime",
+        "Post two factors above one, little-endian u64s",
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+        new anchor.BN(anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(deserializeLE(randomBytes(16)).toString()),
+        { standard: {} },
+        new anchor.BN(0),
+        [],
+        0,
+        { open: {} },
+        null,
+        null,
+        null,
+        verifier.programId,
+        null
+      )
+      .accountsPartial({
+        creator: owner.publicKey,
+        ...arciumAccounts(offset, "initialize_problem"),
+        problem: problemPDA,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+    await awaitComputationFinalization(provider, offset, program.programId, "confirmed");
+
+    return { problemId, problemPDA, semiprimePDA };
+  }
+
+  function setSemiprime(
+    problemId: anchor.BN,
+    problemPDA: PublicKey,
+    semiprimePDA: PublicKey,
+    authority: Keypair,
+    target: anchor.BN
+  ) {
+    return verifier.methods
+      .setSemiprime(problemId, target)
+      .accountsPartial({
+        authority: authority.publicKey,
+        problem: problemPDA,
+        semiprime: semiprimePDA,
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function commit(problemId: anchor.BN, solver: Keypair, solution: Buffer) {
+    return commitRaw(problemId, solver, commitmentFor(solver.publicKey, solution));
+  }
+
+  function commitRaw(problemId: anchor.BN, solver: Keypair, commitment: Buffer) {
+    return commitIx(problemId, solver, commitment)
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function commitIx(problemId: anchor.BN, solver: Keypair, commitment: Buffer) {
+    return verifier.methods
+      .commitFactors(problemId, Array.from(commitment))
+      .accountsPartial({
+        solver: solver.publicKey,
+        commitment: commitmentPDA(problemId, solver.publicKey),
+      });
+  }
+
+  function verifyIx(
+    problemId: anchor.BN,
+    problemPDA: PublicKey,
+    semiprimePDA: PublicKey,
+    solver: Keypair,
+    solution: Buffer
+  ) {
+    return program.methods
+      .verifySolution(problemId, solution)
+      .accountsPartial({
+        solver: solver.publicKey,
+        problem: problemPDA,
+        verifierProgram: verifier.programId,
+      })
+      .remainingAccounts([
+        { pubkey: semiprimePDA, isWritable: false, isSigner: false },
+        {
+          pubkey: commitmentPDA(problemId, solver.publicKey),
+          isWritable: false,
+          isSigner: false,
+        },
+      ]);
+  }
+
+  function verify(
+    problemId: anchor.BN,
+    problemPDA: PublicKey,
+    semiprimePDA: PublicKey,
+    solver: Keypair,
+    solution: Buffer
+  ) {
+    return verifyIx(problemId, problemPDA, semiprimePDA, solver, solution)
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async function commitAndVerify(
+    problemId: anchor.BN,
+    problemPDA: PublicKey,
+    semiprimePDA: PublicKey,
+    solver: Keypair,
+    solution: Buffer
+  ) {
+    const commitment = commitmentFor(solver.publicKey, solution);
+    return verifyIx(problemId, problemPDA, semiprimePDA, solver, solution)
+      .preInstructions([await commitIx(problemId, solver, commitment).instruction()])
+      .signers([solver])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function problemAddress(problemId: anchor.BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("problem"), problemId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  }
+
+  function semiprimeAddress(problemId: anchor.BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("semiprime"), problemId.toArrayLike(Buffer, "le", 8)],
+      verifier.programId
+    )[0];
+  }
+
+  function commitmentPDA(problemId: anchor.BN, solver: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("commitment"),
+        problemId.toArrayLike(Buffer, "le", 8),
+        solver.toBuffer(),
+      ],
+      verifier.programId
+    )[0];
+  }
+
+  function commitmentFor(solver: PublicKey, solution: Buffer): Buffer {
+    return createHash("sha256").update(solver.toBuffer()).update(solution).digest();
+  }
+
+  function factors(p: anchor.BN, q: anchor.BN): Buffer {
+    return Buffer.concat([p.toArrayLike(Buffer, "le", 8), q.toArrayLike(Buffer, "le", 8)]);
+  }
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+    } catch (error) {
+      expect(error.error?.errorCode?.code ?? error.toString()).to.contain(code);
+      return;
+    }
+    expect.fail(`expected ${code}`);
+  }
+
+  async function fund(pubkey: PublicKey) {
+    const sig = await provider.connection.requestAirdrop(
+      pubkey,
+      2 * anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(sig, "confirmed");
+  }
+
+  async function ensureCompDef(circuit: string) {
+    const offset = getCompDefAccOffset(circuit);
+    const compDefPDA = PublicKey.findProgramAddressSync(
+      [
+        getArciumAccountBaseSeed("ComputationDefinitionAccount"),
+        program.programId.toBuffer(),
+        offset,
+      ],
+      getArciumProgAddress()
+    )[0];
+
+    if (await provider.connection.getAccountInfo(compDefPDA)) {
+      return;
+    }
+
+    const method = `init${circuit
+      .split("_")
+      .map((part) => part.charAt(0).toUpperCase() + part.slice(1))
+      .join("")}CompDef`;
+
+    await (program.methods as any)
+      [method](null)
+      .accounts({
+        compDefAccount: compDefPDA,
+        payer: owner.publicKey,
+        mxeAccount: getMXEAccAddress(program.programId),
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const finalizeTx = await buildFinalizeCompDefTx(
+      provider,
+      Buffer.from(offset).readUInt32LE(),
+      program.programId
+    );
+    const latestBlockhash = await provider.connection.getLatestBlockhash();
+    finalizeTx.recentBlockhash = latestBlockhash.blockhash;
+    finalizeTx.lastValidBlockHeight = latestBlockhash.lastValidBlockHeight;
+    finalizeTx.sign(owner);
+    await provider.sendAndConfirm(finalizeTx, [owner], { commitment: "confirmed" });
+  }
+});
+
+function readKpJson(path: string): anchor.web3.Keypair {
+  const file = fs.readFileSync(path);
+  return anchor.web3.Keypair.fromSecretKey(new Uint8Array(JSON.parse(file.toString())));
+}
@@ -0,0 +1,92 @@
+/**
+ * Bounty Problem Solver: Decentralised Bounty Platform
+ * Copyright (c) 2025 Ayush Srivastava
+ *
+ * Licensed under the Apache 2.0
+ */
+
+// Vested bounties: `pay_winner` locks the bounty in the winner's schedule and
+// `claim_vested` releases nothing before the cliff, then linearly until the
+// whole bounty unlocks at the end of the duration.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { Harness, expectError, now } from "./Harness";
+
+const BOUNTY = new anchor.BN(100_000_000);
+const CLIFF = 20;
+const DURATION = 40;
+
+describe("Vested bounties", () => {
+  const harness = new Harness();
+  const program = harness.program;
+
+  before(async () => {
+    await harness.setup(["initialize_problem", "submit_solution", "pay_winner"]);
+  });
+
+  it("releases nothing before the cliff, then unlocks linearly", async () => {
+    const deadline = now() + 30;
+    const { problemId, problemPDA } = await harness.createProblem({
+      deadline,
+      bountyAmount: BOUNTY,
+      vesting: { cliff: new anchor.BN(CLIFF), duration: new anchor.BN(DURATION) },
+    });
+    const [winner] = await harness.funded(1);
+    await harness.submitSolution(problemId, winner, true);
+    await harness.waitUntil(deadline);
+    await harness.closeProblem(problemId, winner.publicKey);
+
+    // The winner has to take the schedule; a plain payout is refused.
+    await expectError(
+      harness.payWinner(problemId, winner, { isWinner: true }),
+      "InvalidVestingSchedule"
+    );
+    await harness.payWinner(problemId, winner, { isWinner: true, vests: true });
+
+    const vestingPDA = harness.pda("vesting", problemId, winner.publicKey);
+    let schedule = await program.account.vestingSchedule.fetch(vestingPDA);
+    const startTime = schedule.startTime.toNumber();
+    expect(startTime).to.be.greaterThan(0);
+    expect(schedule.totalAmount.toString()).to.equal(BOUNTY.toString());
+
+    await expectError(claim(problemId, winner), "NothingVested");
+
+    await harness.waitUntil(startTime + CLIFF);
+    let before = await harness.balance(winner.publicKey);
+    await claim(problemId, winner);
+
+    schedule = await program.account.vestingSchedule.fetch(vestingPDA);
+    const partial = schedule.claimedAmount.toNumber();
+    expect(partial).to.be.at.least(BOUNTY.toNumber() / 2);
+    expect(partial).to.be.below(BOUNTY.toNumber());
+    expect((await harness.balance(winner.publicKey)) - before).to.equal(partial);
+
+    await harness.waitUntil(startTime + DURATION);
+    before = await harness.balance(winner.publicKey);
+    await claim(problemId, winner);
+
+    schedule = await program.account.vestingSchedule.fetch(vestingPDA);
+    expect(schedule.claimedAmount.toString()).to.equal(BOUNTY.toString());
+    expect((await harness.balance(winner.publicKey)) - before).to.equal(
+      BOUNTY.toNumber() - partial
+    );
+    const problem = await program.account.problem.fetch(problemPDA);
+    expect(problem.escrowAmount.toNumber()).to.equal(0);
+
+    await expectError(claim(problemId, winner), "NothingVested");
+  });
+
+  function claim(problemId: anchor.BN, beneficiary: Keypair) {
+    return program.methods
+      .claimVested(problemId)
+      .accountsPartial({
+        beneficiary: beneficiary.publicKey,
+        problem: harness.problemAddress(problemId),
+        vesting: harness.pda("vesting", problemId, beneficiary.publicKey),
+      })
+      .signers([beneficiary])
+      .rpc({ commitment: "confirmed" });
+  }
+});